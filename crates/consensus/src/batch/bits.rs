@@ -0,0 +1,107 @@
+//! Span batch bitlists.
+
+use super::BatchError;
+use alloc::vec::Vec;
+
+/// A span batch bitlist, holding one bit per block or per transaction.
+///
+/// A bitlist of `n` bits is encoded as a big-endian integer of `ceil(n / 8)` bytes, in which the
+/// bit `i` is the `i`-th least significant bit.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct SpanBatchBits(Vec<bool>);
+
+impl SpanBatchBits {
+    /// Creates a new bitlist.
+    pub const fn new(bits: Vec<bool>) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the number of bits.
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the bitlist has no bits.
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the bit at `index`.
+    pub fn get(&self, index: usize) -> Option<bool> {
+        self.0.get(index).copied()
+    }
+
+    /// Appends a bit.
+    pub fn push(&mut self, bit: bool) {
+        self.0.push(bit);
+    }
+
+    /// Returns an iterator over the bits.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// Returns the number of set bits.
+    pub fn count_ones(&self) -> usize {
+        self.0.iter().filter(|bit| **bit).count()
+    }
+
+    /// Encodes the bitlist.
+    pub fn encode(&self, out: &mut Vec<u8>) {
+        let byte_len = self.0.len().div_ceil(8);
+        let start = out.len();
+        out.resize(start + byte_len, 0);
+        for (i, _) in self.0.iter().enumerate().filter(|(_, bit)| **bit) {
+            out[start + byte_len - 1 - i / 8] |= 1 << (i % 8);
+        }
+    }
+
+    /// Decodes a bitlist of `len` bits from the start of `buf`, advancing it past the bitlist.
+    pub fn decode(buf: &mut &[u8], len: usize) -> Result<Self, BatchError> {
+        let byte_len = len.div_ceil(8);
+        if buf.len() < byte_len {
+            return Err(BatchError::UnexpectedEnd);
+        }
+        let (bytes, rest) = buf.split_at(byte_len);
+        let bit = |i: usize| bytes[byte_len - 1 - i / 8] & (1 << (i % 8)) != 0;
+        if (len..byte_len * 8).any(bit) {
+            return Err(BatchError::BitlistTooLong);
+        }
+        *buf = rest;
+        Ok(Self((0..len).map(bit).collect()))
+    }
+}
+
+impl FromIterator<bool> for SpanBatchBits {
+    fn from_iter<T: IntoIterator<Item = bool>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_bits_encoding() {
+        let bits: SpanBatchBits = (0..10).map(|i| i == 0 || i == 9).collect();
+        let mut encoded = Vec::new();
+        bits.encode(&mut encoded);
+        assert_eq!(encoded, vec![0b10, 0b1]);
+        assert_eq!(bits.count_ones(), 2);
+        assert_eq!(SpanBatchBits::decode(&mut encoded.as_slice(), 10), Ok(bits));
+
+        let mut buf = [0u8; 0].as_slice();
+        assert_eq!(SpanBatchBits::decode(&mut buf, 0), Ok(SpanBatchBits::default()));
+    }
+
+    #[test]
+    fn test_decode_bits_invalid() {
+        assert_eq!(
+            SpanBatchBits::decode(&mut [0b100].as_slice(), 2),
+            Err(BatchError::BitlistTooLong)
+        );
+        assert_eq!(SpanBatchBits::decode(&mut [0].as_slice(), 9), Err(BatchError::UnexpectedEnd));
+    }
+}
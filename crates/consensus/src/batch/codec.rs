@@ -0,0 +1,70 @@
+//! Primitive encodings shared by the batch formats.
+
+use super::BatchError;
+use alloc::vec::Vec;
+
+/// The maximum length of an encoded `u64` varint.
+const MAX_UVARINT_LEN: usize = 10;
+
+/// Appends `value` encoded as an unsigned LEB128 varint.
+pub(super) fn write_uvarint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Reads an unsigned LEB128 varint from the start of `buf`, advancing it past the varint.
+pub(super) fn read_uvarint(buf: &mut &[u8]) -> Result<u64, BatchError> {
+    let mut value = 0u64;
+    for (i, byte) in buf.iter().copied().enumerate().take(MAX_UVARINT_LEN) {
+        if i == MAX_UVARINT_LEN - 1 && byte > 1 {
+            return Err(BatchError::InvalidVarint);
+        }
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte < 0x80 {
+            *buf = &buf[i + 1..];
+            return Ok(value);
+        }
+    }
+    if buf.len() < MAX_UVARINT_LEN {
+        Err(BatchError::UnexpectedEnd)
+    } else {
+        Err(BatchError::InvalidVarint)
+    }
+}
+
+/// Reads `N` bytes from the start of `buf`, advancing it past them.
+pub(super) fn read_array<const N: usize>(buf: &mut &[u8]) -> Result<[u8; N], BatchError> {
+    let (bytes, rest) = buf.split_first_chunk::<N>().ok_or(BatchError::UnexpectedEnd)?;
+    *buf = rest;
+    Ok(*bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_uvarint() {
+        for value in [0, 1, 0x7f, 0x80, 300, u32::MAX as u64, u64::MAX] {
+            let mut encoded = Vec::new();
+            write_uvarint(&mut encoded, value);
+            let mut buf = encoded.as_slice();
+            assert_eq!(read_uvarint(&mut buf), Ok(value));
+            assert!(buf.is_empty());
+        }
+
+        let mut encoded = Vec::new();
+        write_uvarint(&mut encoded, 300);
+        assert_eq!(encoded, vec![0xac, 0x02]);
+
+        assert_eq!(read_uvarint(&mut [0x80].as_slice()), Err(BatchError::UnexpectedEnd));
+        assert_eq!(read_uvarint(&mut [0xff; 10].as_slice()), Err(BatchError::InvalidVarint));
+        let mut overflow = vec![0xff; 9];
+        overflow.push(0x02);
+        assert_eq!(read_uvarint(&mut overflow.as_slice()), Err(BatchError::InvalidVarint));
+    }
+}
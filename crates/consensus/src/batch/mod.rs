@@ -0,0 +1,190 @@
+//! Batches, the inputs of L2 blocks carried by channels.
+//!
+//! A batch is either a [`SingularBatch`], holding the inputs of a single L2 block, or a span
+//! batch holding the inputs of a range of consecutive L2 blocks, available from the Delta
+//! hardfork. Span batches are encoded as a [`RawSpanBatch`], and expanded into a [`SpanBatch`]
+//! with the rollup configuration.
+//!
+//! See also: <https://specs.optimism.io/protocol/derivation.html#batch-format> and
+//! <https://specs.optimism.io/protocol/delta/span-batches.html>
+
+mod bits;
+pub use bits::SpanBatchBits;
+
+mod codec;
+
+mod single;
+pub use single::SingularBatch;
+
+mod span;
+pub use span::{RawSpanBatch, SpanBatch, SpanBatchElement};
+
+mod transactions;
+pub use transactions::{
+    SpanBatchEip1559TransactionData, SpanBatchEip2930TransactionData,
+    SpanBatchEip7702TransactionData, SpanBatchLegacyTransactionData, SpanBatchTransactionData,
+    SpanBatchTransactions,
+};
+
+use alloc::vec::Vec;
+use alloy_primitives::ChainId;
+use alloy_rlp::{Decodable, Encodable};
+
+/// The type of a [`SingularBatch`].
+pub const SINGULAR_BATCH_TYPE: u8 = 0;
+
+/// The type of a span batch.
+pub const SPAN_BATCH_TYPE: u8 = 1;
+
+/// The maximum number of blocks, and of transactions, in a span batch.
+pub const MAX_SPAN_BATCH_ELEMENTS: u64 = 10_000_000;
+
+/// A batch, encoded as `batch_type ++ batch_data`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Batch {
+    /// A singular batch, encoded as the RLP encoding of the batch.
+    Singular(SingularBatch),
+    /// A span batch, encoded in the span batch format.
+    Span(RawSpanBatch),
+}
+
+impl Batch {
+    /// Returns the type of the batch.
+    pub const fn batch_type(&self) -> u8 {
+        match self {
+            Self::Singular(_) => SINGULAR_BATCH_TYPE,
+            Self::Span(_) => SPAN_BATCH_TYPE,
+        }
+    }
+
+    /// Encodes the batch.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut encoded = alloc::vec![self.batch_type()];
+        match self {
+            Self::Singular(batch) => batch.encode(&mut encoded),
+            Self::Span(batch) => batch.encode(&mut encoded),
+        }
+        encoded
+    }
+
+    /// Decodes a batch.
+    ///
+    /// Singular batches must span the whole data, while the data of a span batch may be followed
+    /// by trailing bytes, which are ignored.
+    pub fn decode(data: &[u8]) -> Result<Self, BatchError> {
+        let (&batch_type, mut buf) = data.split_first().ok_or(BatchError::EmptyBatch)?;
+        match batch_type {
+            SINGULAR_BATCH_TYPE => {
+                let batch = SingularBatch::decode(&mut buf)?;
+                if !buf.is_empty() {
+                    return Err(alloy_rlp::Error::UnexpectedLength.into());
+                }
+                Ok(Self::Singular(batch))
+            }
+            SPAN_BATCH_TYPE => Ok(Self::Span(RawSpanBatch::decode(&mut buf)?)),
+            _ => Err(BatchError::UnknownBatchType(batch_type)),
+        }
+    }
+}
+
+/// Errors that can occur when decoding, encoding or converting batches.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum BatchError {
+    /// The RLP encoding of the batch, or of a span batch transaction, is invalid.
+    #[error(transparent)]
+    Rlp(#[from] alloy_rlp::Error),
+    /// The data ends in the middle of the batch.
+    #[error("Unexpected end of batch data")]
+    UnexpectedEnd,
+    /// A varint is longer than 10 bytes or overflows a `u64`.
+    #[error("Invalid varint")]
+    InvalidVarint,
+    /// A bitlist has bits set after its last bit.
+    #[error("Bitlist too long")]
+    BitlistTooLong,
+    /// The batch data is empty.
+    #[error("Empty batch")]
+    EmptyBatch,
+    /// The batch type is unknown.
+    #[error("Unknown batch type: {0}")]
+    UnknownBatchType(u8),
+    /// The span batch has no blocks.
+    #[error("Empty span batch")]
+    EmptySpanBatch,
+    /// The span batch has more than [`MAX_SPAN_BATCH_ELEMENTS`] blocks or transactions.
+    #[error("Too many span batch elements")]
+    TooManyElements,
+    /// The type of a span batch transaction is not supported.
+    #[error("Invalid span batch transaction type: {0}")]
+    InvalidTxType(u8),
+    /// A deposit transaction was added to a span batch.
+    #[error("Deposit transactions are not allowed in batches")]
+    DepositTransaction,
+    /// The chain ID of a transaction does not match the chain ID of the span batch.
+    #[error("Transaction chain ID {got} does not match chain ID {expected}")]
+    ChainIdMismatch {
+        /// The chain ID of the span batch.
+        expected: ChainId,
+        /// The chain ID of the transaction.
+        got: ChainId,
+    },
+    /// The span batch transaction at the given index creates a contract, which its type does not
+    /// allow.
+    #[error("Span batch transaction {0} can not create a contract")]
+    ContractCreation(usize),
+    /// The timestamps of the span batch blocks are not spaced by the block time, or precede the
+    /// genesis.
+    #[error("Invalid span batch block timestamp")]
+    InvalidTimestamp,
+    /// The L1 origins of the span batch blocks do not advance by zero or one block at a time.
+    #[error("Non-sequential span batch L1 origins")]
+    NonSequentialOrigin,
+    /// The origin bits of the span batch advance past the genesis L1 block.
+    #[error("Span batch L1 origin number underflow")]
+    L1OriginUnderflow,
+    /// No L1 origin was provided for the given L1 block number.
+    #[error("Missing L1 origin for block number {0}")]
+    MissingL1Origin(u64),
+    /// A transaction of a batch can not be decoded.
+    #[error("Invalid transaction {index} in batch {block}")]
+    InvalidTransaction {
+        /// The index of the batch, or of the block in a span batch.
+        block: usize,
+        /// The index of the transaction in the block.
+        index: usize,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+
+    #[test]
+    fn test_batch_encoding() {
+        let batch = Batch::Singular(SingularBatch {
+            parent_hash: B256::repeat_byte(1),
+            epoch_num: 2,
+            epoch_hash: B256::repeat_byte(3),
+            timestamp: 4,
+            transactions: Vec::new(),
+        });
+        let encoded = batch.encode();
+        assert_eq!(encoded[0], SINGULAR_BATCH_TYPE);
+        assert_eq!(Batch::decode(&encoded), Ok(batch));
+
+        let mut trailing = encoded;
+        trailing.push(0);
+        assert_eq!(Batch::decode(&trailing), Err(alloy_rlp::Error::UnexpectedLength.into()));
+
+        let data = include_bytes!("testdata/span_batch.bin");
+        let mut encoded = alloc::vec![SPAN_BATCH_TYPE];
+        encoded.extend_from_slice(data);
+        let batch = Batch::decode(&encoded).unwrap();
+        assert_eq!(batch.batch_type(), SPAN_BATCH_TYPE);
+        assert_eq!(batch.encode(), encoded);
+
+        assert_eq!(Batch::decode(&[]), Err(BatchError::EmptyBatch));
+        assert_eq!(Batch::decode(&[2]), Err(BatchError::UnknownBatchType(2)));
+    }
+}
@@ -0,0 +1,88 @@
+//! Singular batches, holding the inputs of a single L2 block.
+
+use crate::{OpTxEnvelope, OpTxType};
+use alloc::vec::Vec;
+use alloy_eips::{BlockNumHash, eip2718::Decodable2718};
+use alloy_primitives::{B256, Bytes};
+use alloy_rlp::{RlpDecodable, RlpEncodable};
+
+/// A singular batch, encoded as `rlp([parent_hash, epoch_num, epoch_hash, timestamp,
+/// transactions])`.
+///
+/// The transactions are opaque EIP-2718 encoded transactions: a batch may carry invalid
+/// transactions, which make the whole batch invalid during derivation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, RlpEncodable, RlpDecodable)]
+pub struct SingularBatch {
+    /// The hash of the parent L2 block.
+    pub parent_hash: B256,
+    /// The number of the L1 origin of the block.
+    pub epoch_num: u64,
+    /// The hash of the L1 origin of the block.
+    pub epoch_hash: B256,
+    /// The timestamp of the block.
+    pub timestamp: u64,
+    /// The EIP-2718 encoded transactions of the block.
+    pub transactions: Vec<Bytes>,
+}
+
+impl SingularBatch {
+    /// Returns the L1 origin of the block.
+    pub const fn epoch(&self) -> BlockNumHash {
+        BlockNumHash { number: self.epoch_num, hash: self.epoch_hash }
+    }
+
+    /// Returns `true` if the batch carries empty or deposit transactions, which are not allowed
+    /// in batches.
+    pub fn has_invalid_transactions(&self) -> bool {
+        self.transactions
+            .iter()
+            .any(|tx| tx.first().is_none_or(|ty| *ty == OpTxType::Deposit as u8))
+    }
+
+    /// Decodes the transactions of the batch, returning the index of the first transaction that
+    /// could not be decoded on failure.
+    pub fn decode_transactions(&self) -> Result<Vec<OpTxEnvelope>, usize> {
+        self.transactions
+            .iter()
+            .enumerate()
+            .map(|(index, tx)| OpTxEnvelope::decode_2718_exact(tx).map_err(|_| index))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use alloy_consensus::{Signed, TxLegacy};
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::Signature;
+    use alloy_rlp::{Decodable, Encodable};
+
+    #[test]
+    fn test_singular_batch_rlp() {
+        let tx = OpTxEnvelope::Legacy(Signed::new_unhashed(
+            TxLegacy::default(),
+            Signature::test_signature(),
+        ));
+        let batch = SingularBatch {
+            parent_hash: B256::repeat_byte(1),
+            epoch_num: 2,
+            epoch_hash: B256::repeat_byte(3),
+            timestamp: 4,
+            transactions: vec![tx.encoded_2718().into()],
+        };
+        let mut encoded = Vec::new();
+        batch.encode(&mut encoded);
+        assert_eq!(SingularBatch::decode(&mut encoded.as_slice()).unwrap(), batch);
+        assert_eq!(batch.decode_transactions(), Ok(vec![tx]));
+        assert!(!batch.has_invalid_transactions());
+
+        let batch = SingularBatch {
+            transactions: vec![Bytes::new(), Bytes::from_static(&[0x7e])],
+            ..batch
+        };
+        assert!(batch.has_invalid_transactions());
+        assert_eq!(batch.decode_transactions(), Err(0));
+    }
+}
@@ -0,0 +1,423 @@
+//! Span batches, holding the inputs of a range of consecutive L2 blocks.
+
+use super::{
+    BatchError, MAX_SPAN_BATCH_ELEMENTS, SingularBatch, SpanBatchBits, SpanBatchTransactions, codec,
+};
+use crate::OpTxEnvelope;
+use alloc::vec::Vec;
+use alloy_eips::{BlockNumHash, eip2718::Encodable2718};
+use alloy_primitives::{B256, ChainId, FixedBytes};
+
+/// A span batch as encoded in a channel.
+///
+/// A raw span batch is encoded as
+/// `rel_timestamp ++ l1_origin_num ++ parent_check ++ l1_origin_check ++ block_count ++
+/// origin_bits ++ block_tx_counts ++ txs`, where the integers are unsigned varints. It only carries
+/// the fields needed to rebuild its blocks with the rollup configuration, see
+/// [`RawSpanBatch::derive`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct RawSpanBatch {
+    /// The timestamp of the first block, relative to the L2 genesis timestamp.
+    pub rel_timestamp: u64,
+    /// The number of the L1 origin of the last block.
+    pub l1_origin_num: u64,
+    /// The first 20 bytes of the hash of the parent of the first block.
+    pub parent_check: FixedBytes<20>,
+    /// The first 20 bytes of the hash of the L1 origin of the last block.
+    pub l1_origin_check: FixedBytes<20>,
+    /// Whether the L1 origin of each block differs from the L1 origin of the previous block.
+    pub origin_bits: SpanBatchBits,
+    /// The number of transactions of each block.
+    pub block_tx_counts: Vec<u64>,
+    /// The transactions of all the blocks.
+    pub txs: SpanBatchTransactions,
+}
+
+impl RawSpanBatch {
+    /// Returns the number of blocks of the span batch.
+    pub const fn block_count(&self) -> usize {
+        self.block_tx_counts.len()
+    }
+
+    /// Encodes the span batch.
+    pub fn encode(&self, out: &mut Vec<u8>) {
+        codec::write_uvarint(out, self.rel_timestamp);
+        codec::write_uvarint(out, self.l1_origin_num);
+        out.extend_from_slice(self.parent_check.as_slice());
+        out.extend_from_slice(self.l1_origin_check.as_slice());
+        codec::write_uvarint(out, self.block_count() as u64);
+        self.origin_bits.encode(out);
+        for count in &self.block_tx_counts {
+            codec::write_uvarint(out, *count);
+        }
+        self.txs.encode(out);
+    }
+
+    /// Decodes a span batch from the start of `buf`, advancing it past the span batch.
+    pub fn decode(buf: &mut &[u8]) -> Result<Self, BatchError> {
+        let rel_timestamp = codec::read_uvarint(buf)?;
+        let l1_origin_num = codec::read_uvarint(buf)?;
+        let parent_check = codec::read_array(buf)?.into();
+        let l1_origin_check = codec::read_array(buf)?.into();
+
+        let block_count = codec::read_uvarint(buf)?;
+        if block_count == 0 {
+            return Err(BatchError::EmptySpanBatch);
+        }
+        if block_count > MAX_SPAN_BATCH_ELEMENTS {
+            return Err(BatchError::TooManyElements);
+        }
+        let origin_bits = SpanBatchBits::decode(buf, block_count as usize)?;
+        let block_tx_counts: Vec<_> =
+            (0..block_count).map(|_| codec::read_uvarint(buf)).collect::<Result<_, _>>()?;
+        let tx_count = block_tx_counts
+            .iter()
+            .try_fold(0u64, |total, count| total.checked_add(*count))
+            .filter(|total| *total <= MAX_SPAN_BATCH_ELEMENTS)
+            .ok_or(BatchError::TooManyElements)?;
+        let txs = SpanBatchTransactions::decode(buf, tx_count as usize)?;
+
+        Ok(Self {
+            rel_timestamp,
+            l1_origin_num,
+            parent_check,
+            l1_origin_check,
+            origin_bits,
+            block_tx_counts,
+            txs,
+        })
+    }
+
+    /// Rebuilds the blocks of the span batch of the chain with ID `chain_id`, whose L2 genesis
+    /// timestamp is `genesis_timestamp` and whose blocks are `block_time` seconds apart.
+    pub fn derive(
+        &self,
+        genesis_timestamp: u64,
+        block_time: u64,
+        chain_id: ChainId,
+    ) -> Result<SpanBatch, BatchError> {
+        let block_count = self.block_count();
+        if block_count == 0 {
+            return Err(BatchError::EmptySpanBatch);
+        }
+        if self.origin_bits.len() != block_count {
+            return Err(BatchError::UnexpectedEnd);
+        }
+
+        // The L1 origin numbers are rebuilt backwards from the L1 origin of the last block.
+        let mut epoch_nums = alloc::vec![0; block_count];
+        epoch_nums[block_count - 1] = self.l1_origin_num;
+        for i in (1..block_count).rev() {
+            let changed = self.origin_bits.get(i) == Some(true);
+            epoch_nums[i - 1] =
+                epoch_nums[i].checked_sub(changed as u64).ok_or(BatchError::L1OriginUnderflow)?;
+        }
+
+        let mut txs = self.txs.to_envelopes(chain_id)?.into_iter();
+        let first_timestamp = genesis_timestamp
+            .checked_add(self.rel_timestamp)
+            .ok_or(BatchError::InvalidTimestamp)?;
+        let blocks = self
+            .block_tx_counts
+            .iter()
+            .zip(epoch_nums)
+            .enumerate()
+            .map(|(i, (count, epoch_num))| {
+                let timestamp = (i as u64)
+                    .checked_mul(block_time)
+                    .and_then(|offset| first_timestamp.checked_add(offset))
+                    .ok_or(BatchError::InvalidTimestamp)?;
+                let transactions: Vec<_> = txs.by_ref().take(*count as usize).collect();
+                if transactions.len() as u64 != *count {
+                    return Err(BatchError::UnexpectedEnd);
+                }
+                Ok(SpanBatchElement { timestamp, epoch_num, transactions })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(SpanBatch {
+            parent_check: self.parent_check,
+            l1_origin_check: self.l1_origin_check,
+            first_origin_changed: self.origin_bits.get(0) == Some(true),
+            blocks,
+        })
+    }
+}
+
+/// The inputs of a single L2 block of a [`SpanBatch`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SpanBatchElement {
+    /// The timestamp of the block.
+    pub timestamp: u64,
+    /// The number of the L1 origin of the block.
+    pub epoch_num: u64,
+    /// The transactions of the block.
+    pub transactions: Vec<OpTxEnvelope>,
+}
+
+/// A span batch with its blocks rebuilt, see [`RawSpanBatch::derive`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SpanBatch {
+    /// The first 20 bytes of the hash of the parent of the first block.
+    pub parent_check: FixedBytes<20>,
+    /// The first 20 bytes of the hash of the L1 origin of the last block.
+    pub l1_origin_check: FixedBytes<20>,
+    /// Whether the L1 origin of the first block differs from the L1 origin of its parent.
+    pub first_origin_changed: bool,
+    /// The blocks of the span batch.
+    pub blocks: Vec<SpanBatchElement>,
+}
+
+impl SpanBatch {
+    /// Returns the timestamp of the first block.
+    pub fn starting_timestamp(&self) -> Option<u64> {
+        self.blocks.first().map(|block| block.timestamp)
+    }
+
+    /// Returns the timestamp of the last block.
+    pub fn final_timestamp(&self) -> Option<u64> {
+        self.blocks.last().map(|block| block.timestamp)
+    }
+
+    /// Returns `true` if the first 20 bytes of `hash` match the parent check of the span batch.
+    pub fn check_parent_hash(&self, hash: B256) -> bool {
+        hash[..20] == self.parent_check
+    }
+
+    /// Returns `true` if the first 20 bytes of `hash` match the L1 origin check of the span batch.
+    pub fn check_l1_origin_hash(&self, hash: B256) -> bool {
+        hash[..20] == self.l1_origin_check
+    }
+
+    /// Builds a span batch from consecutive singular batches.
+    ///
+    /// `first_origin_changed` tells whether the L1 origin of the first batch differs from the L1
+    /// origin of its parent block.
+    pub fn from_singular_batches(
+        batches: &[SingularBatch],
+        first_origin_changed: bool,
+    ) -> Result<Self, BatchError> {
+        let (Some(first), Some(last)) = (batches.first(), batches.last()) else {
+            return Err(BatchError::EmptySpanBatch);
+        };
+        let blocks = batches
+            .iter()
+            .enumerate()
+            .map(|(block, batch)| {
+                let transactions = batch
+                    .decode_transactions()
+                    .map_err(|index| BatchError::InvalidTransaction { block, index })?;
+                Ok::<_, BatchError>(SpanBatchElement {
+                    timestamp: batch.timestamp,
+                    epoch_num: batch.epoch_num,
+                    transactions,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            parent_check: FixedBytes::from_slice(&first.parent_hash[..20]),
+            l1_origin_check: FixedBytes::from_slice(&last.epoch_hash[..20]),
+            first_origin_changed,
+            blocks,
+        })
+    }
+
+    /// Splits the span batch into singular batches, skipping the blocks that are not newer than
+    /// the L2 safe head, whose timestamp is `safe_head_timestamp`.
+    ///
+    /// The L1 origin hashes are looked up in `l1_origins`. The parent hashes of the batches are
+    /// left empty, as only the first 20 bytes of the parent hash of the first block are known.
+    pub fn to_singular_batches(
+        &self,
+        l1_origins: &[BlockNumHash],
+        safe_head_timestamp: u64,
+    ) -> Result<Vec<SingularBatch>, BatchError> {
+        self.blocks
+            .iter()
+            .filter(|block| block.timestamp > safe_head_timestamp)
+            .map(|block| {
+                let origin = l1_origins
+                    .iter()
+                    .find(|origin| origin.number == block.epoch_num)
+                    .ok_or(BatchError::MissingL1Origin(block.epoch_num))?;
+                Ok(SingularBatch {
+                    parent_hash: B256::ZERO,
+                    epoch_num: block.epoch_num,
+                    epoch_hash: origin.hash,
+                    timestamp: block.timestamp,
+                    transactions: block
+                        .transactions
+                        .iter()
+                        .map(|tx| tx.encoded_2718().into())
+                        .collect(),
+                })
+            })
+            .collect()
+    }
+
+    /// Converts the span batch of the chain with ID `chain_id` to its raw form.
+    ///
+    /// The blocks must be `block_time` seconds apart, starting after the L2 genesis timestamp
+    /// `genesis_timestamp`, and their L1 origins must advance by at most one block at a time.
+    pub fn to_raw(
+        &self,
+        genesis_timestamp: u64,
+        block_time: u64,
+        chain_id: ChainId,
+    ) -> Result<RawSpanBatch, BatchError> {
+        let (Some(first), Some(last)) = (self.blocks.first(), self.blocks.last()) else {
+            return Err(BatchError::EmptySpanBatch);
+        };
+        let rel_timestamp =
+            first.timestamp.checked_sub(genesis_timestamp).ok_or(BatchError::InvalidTimestamp)?;
+
+        let mut origin_bits = SpanBatchBits::default();
+        origin_bits.push(self.first_origin_changed);
+        for (prev, block) in self.blocks.iter().zip(&self.blocks[1..]) {
+            if prev.timestamp.checked_add(block_time) != Some(block.timestamp) {
+                return Err(BatchError::InvalidTimestamp);
+            }
+            match block.epoch_num.checked_sub(prev.epoch_num) {
+                Some(0) => origin_bits.push(false),
+                Some(1) => origin_bits.push(true),
+                _ => return Err(BatchError::NonSequentialOrigin),
+            }
+        }
+
+        let mut txs = SpanBatchTransactions::default();
+        for block in &self.blocks {
+            for tx in &block.transactions {
+                txs.push(tx, chain_id)?;
+            }
+        }
+
+        Ok(RawSpanBatch {
+            rel_timestamp,
+            l1_origin_num: last.epoch_num,
+            parent_check: self.parent_check,
+            l1_origin_check: self.l1_origin_check,
+            origin_bits,
+            block_tx_counts: self
+                .blocks
+                .iter()
+                .map(|block| block.transactions.len() as u64)
+                .collect(),
+            txs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use alloy_consensus::{Signed, TxEip1559, TxLegacy};
+    use alloy_primitives::{Address, Signature, TxKind, U256};
+
+    const RAW: &[u8] = include_bytes!("testdata/span_batch.bin");
+
+    fn singular_batches() -> Vec<SingularBatch> {
+        let legacy = OpTxEnvelope::Legacy(Signed::new_unhashed(
+            TxLegacy { chain_id: Some(10), to: TxKind::Create, ..Default::default() },
+            Signature::test_signature(),
+        ));
+        let eip1559 = OpTxEnvelope::Eip1559(Signed::new_unhashed(
+            TxEip1559 {
+                chain_id: 10,
+                to: TxKind::Call(Address::repeat_byte(1)),
+                value: U256::from(1),
+                ..Default::default()
+            },
+            Signature::test_signature(),
+        ));
+        let batch = |timestamp, epoch_num: u64, transactions: &[&OpTxEnvelope]| SingularBatch {
+            parent_hash: B256::ZERO,
+            epoch_num,
+            epoch_hash: B256::repeat_byte(epoch_num as u8),
+            timestamp,
+            transactions: transactions.iter().map(|tx| tx.encoded_2718().into()).collect(),
+        };
+        vec![batch(102, 1, &[&legacy]), batch(104, 1, &[]), batch(106, 2, &[&eip1559, &legacy])]
+    }
+
+    #[test]
+    fn test_raw_span_batch_roundtrip() {
+        let mut buf = RAW;
+        let raw = RawSpanBatch::decode(&mut buf).unwrap();
+        assert!(buf.is_empty());
+        let mut encoded = Vec::new();
+        raw.encode(&mut encoded);
+        assert_eq!(encoded, RAW);
+
+        let span = raw.derive(0, 2, 901).unwrap();
+        assert_eq!(span.blocks.len(), raw.block_count());
+        assert_eq!(span.to_raw(0, 2, 901), Ok(raw));
+    }
+
+    #[test]
+    fn test_singular_batches_roundtrip() {
+        let batches = singular_batches();
+        let span = SpanBatch::from_singular_batches(&batches, true).unwrap();
+        assert_eq!(span.starting_timestamp(), Some(102));
+        assert_eq!(span.final_timestamp(), Some(106));
+        assert!(span.check_parent_hash(B256::ZERO));
+        assert!(span.check_l1_origin_hash(B256::repeat_byte(2)));
+
+        let raw = span.to_raw(100, 2, 10).unwrap();
+        assert_eq!(raw.rel_timestamp, 2);
+        assert_eq!(raw.l1_origin_num, 2);
+        assert_eq!(raw.origin_bits, SpanBatchBits::new(vec![true, false, true]));
+        assert_eq!(raw.block_tx_counts, vec![1, 0, 2]);
+
+        let mut encoded = Vec::new();
+        raw.encode(&mut encoded);
+        let decoded = RawSpanBatch::decode(&mut encoded.as_slice()).unwrap();
+        assert_eq!(decoded, raw);
+        assert_eq!(decoded.derive(100, 2, 10), Ok(span.clone()));
+
+        let origins =
+            [1, 2].map(|number| BlockNumHash::new(number, B256::repeat_byte(number as u8)));
+        assert_eq!(span.to_singular_batches(&origins, 0), Ok(batches.clone()));
+        assert_eq!(span.to_singular_batches(&origins, 104), Ok(batches[2..].to_vec()));
+        assert_eq!(span.to_singular_batches(&origins[1..], 0), Err(BatchError::MissingL1Origin(1)));
+    }
+
+    #[test]
+    fn test_span_batch_invalid() {
+        let span = SpanBatch::from_singular_batches(&singular_batches(), false).unwrap();
+        assert_eq!(
+            span.to_raw(100, 2, 11).unwrap_err(),
+            BatchError::ChainIdMismatch { expected: 11, got: 10 }
+        );
+        assert_eq!(span.to_raw(100, 3, 10), Err(BatchError::InvalidTimestamp));
+        assert_eq!(span.to_raw(103, 2, 10), Err(BatchError::InvalidTimestamp));
+
+        let mut invalid = span.clone();
+        invalid.blocks[2].epoch_num = 3;
+        assert_eq!(invalid.to_raw(100, 2, 10), Err(BatchError::NonSequentialOrigin));
+
+        let mut raw = span.to_raw(100, 2, 10).unwrap();
+        raw.l1_origin_num = 0;
+        assert_eq!(raw.derive(100, 2, 10), Err(BatchError::L1OriginUnderflow));
+
+        assert_eq!(SpanBatch::from_singular_batches(&[], false), Err(BatchError::EmptySpanBatch));
+        let mut batches = singular_batches();
+        batches[1].transactions.push(Default::default());
+        assert_eq!(
+            SpanBatch::from_singular_batches(&batches, false),
+            Err(BatchError::InvalidTransaction { block: 1, index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_decode_raw_span_batch_invalid() {
+        let mut encoded = Vec::new();
+        RawSpanBatch::default().encode(&mut encoded);
+        assert_eq!(RawSpanBatch::decode(&mut encoded.as_slice()), Err(BatchError::EmptySpanBatch));
+
+        assert_eq!(
+            RawSpanBatch::decode(&mut &RAW[..RAW.len() - 1]),
+            Err(BatchError::UnexpectedEnd)
+        );
+    }
+}
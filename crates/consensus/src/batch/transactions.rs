@@ -0,0 +1,373 @@
+//! The transactions of a span batch, split into columns.
+
+use super::{BatchError, SpanBatchBits, codec};
+use crate::{OpTxEnvelope, OpTxType};
+use alloc::vec::Vec;
+use alloy_consensus::{Signed, TxEip1559, TxEip2930, TxEip7702, TxLegacy};
+use alloy_eips::{eip2930::AccessList, eip7702::SignedAuthorization};
+use alloy_primitives::{Address, Bytes, ChainId, Signature, TxKind, U256};
+use alloy_rlp::{Decodable, Encodable, RlpDecodable, RlpEncodable};
+
+/// The data of a legacy transaction in a span batch, encoded as `rlp([value, gas_price, data])`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, RlpEncodable, RlpDecodable)]
+pub struct SpanBatchLegacyTransactionData {
+    /// The value of the transaction.
+    pub value: U256,
+    /// The gas price of the transaction.
+    pub gas_price: u128,
+    /// The input of the transaction.
+    pub data: Bytes,
+}
+
+/// The data of an EIP-2930 transaction in a span batch, encoded as
+/// `0x01 ++ rlp([value, gas_price, data, access_list])`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, RlpEncodable, RlpDecodable)]
+pub struct SpanBatchEip2930TransactionData {
+    /// The value of the transaction.
+    pub value: U256,
+    /// The gas price of the transaction.
+    pub gas_price: u128,
+    /// The input of the transaction.
+    pub data: Bytes,
+    /// The access list of the transaction.
+    pub access_list: AccessList,
+}
+
+/// The data of an EIP-1559 transaction in a span batch, encoded as
+/// `0x02 ++ rlp([value, max_priority_fee_per_gas, max_fee_per_gas, data, access_list])`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, RlpEncodable, RlpDecodable)]
+pub struct SpanBatchEip1559TransactionData {
+    /// The value of the transaction.
+    pub value: U256,
+    /// The maximum priority fee per gas of the transaction.
+    pub max_priority_fee_per_gas: u128,
+    /// The maximum fee per gas of the transaction.
+    pub max_fee_per_gas: u128,
+    /// The input of the transaction.
+    pub data: Bytes,
+    /// The access list of the transaction.
+    pub access_list: AccessList,
+}
+
+/// The data of an EIP-7702 transaction in a span batch, encoded as
+/// `0x04 ++ rlp([value, max_priority_fee_per_gas, max_fee_per_gas, data, access_list,
+/// authorization_list])`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, RlpEncodable, RlpDecodable)]
+pub struct SpanBatchEip7702TransactionData {
+    /// The value of the transaction.
+    pub value: U256,
+    /// The maximum priority fee per gas of the transaction.
+    pub max_priority_fee_per_gas: u128,
+    /// The maximum fee per gas of the transaction.
+    pub max_fee_per_gas: u128,
+    /// The input of the transaction.
+    pub data: Bytes,
+    /// The access list of the transaction.
+    pub access_list: AccessList,
+    /// The authorization list of the transaction.
+    pub authorization_list: Vec<SignedAuthorization>,
+}
+
+/// The data of a transaction in a span batch, i.e. the fields that are not stored in their own
+/// column of [`SpanBatchTransactions`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SpanBatchTransactionData {
+    /// A legacy transaction.
+    Legacy(SpanBatchLegacyTransactionData),
+    /// An EIP-2930 transaction.
+    Eip2930(SpanBatchEip2930TransactionData),
+    /// An EIP-1559 transaction.
+    Eip1559(SpanBatchEip1559TransactionData),
+    /// An EIP-7702 transaction.
+    Eip7702(SpanBatchEip7702TransactionData),
+}
+
+impl SpanBatchTransactionData {
+    /// Returns the type of the transaction.
+    pub const fn tx_type(&self) -> OpTxType {
+        match self {
+            Self::Legacy(_) => OpTxType::Legacy,
+            Self::Eip2930(_) => OpTxType::Eip2930,
+            Self::Eip1559(_) => OpTxType::Eip1559,
+            Self::Eip7702(_) => OpTxType::Eip7702,
+        }
+    }
+
+    /// Encodes the transaction data.
+    pub fn encode(&self, out: &mut Vec<u8>) {
+        if !matches!(self, Self::Legacy(_)) {
+            out.push(self.tx_type() as u8);
+        }
+        match self {
+            Self::Legacy(data) => data.encode(out),
+            Self::Eip2930(data) => data.encode(out),
+            Self::Eip1559(data) => data.encode(out),
+            Self::Eip7702(data) => data.encode(out),
+        }
+    }
+
+    /// Decodes transaction data from the start of `buf`, advancing it past the data.
+    pub fn decode(buf: &mut &[u8]) -> Result<Self, BatchError> {
+        let ty = *buf.first().ok_or(BatchError::UnexpectedEnd)?;
+        if ty >= alloy_rlp::EMPTY_LIST_CODE {
+            return Ok(Self::Legacy(Decodable::decode(buf)?));
+        }
+        *buf = &buf[1..];
+        match OpTxType::try_from(ty) {
+            Ok(OpTxType::Eip2930) => Ok(Self::Eip2930(Decodable::decode(buf)?)),
+            Ok(OpTxType::Eip1559) => Ok(Self::Eip1559(Decodable::decode(buf)?)),
+            Ok(OpTxType::Eip7702) => Ok(Self::Eip7702(Decodable::decode(buf)?)),
+            _ => Err(BatchError::InvalidTxType(ty)),
+        }
+    }
+}
+
+/// The transactions of a span batch.
+///
+/// The fields of the transactions are stored in columns, encoded in order as
+/// `contract_creation_bits ++ y_parity_bits ++ tx_sigs ++ tx_tos ++ tx_datas ++ tx_nonces ++
+/// tx_gases ++ protected_bits`. The chain ID of the transactions is not encoded, and is supplied
+/// by the rollup configuration when converting back to [`OpTxEnvelope`]s.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct SpanBatchTransactions {
+    /// Whether each transaction creates a contract, in which case it has no `to` address.
+    pub contract_creation_bits: SpanBatchBits,
+    /// The signatures of the transactions. The y-parities are encoded as a bitlist.
+    pub tx_sigs: Vec<Signature>,
+    /// The `to` addresses of the transactions that do not create a contract.
+    pub tx_tos: Vec<Address>,
+    /// The data of the transactions.
+    pub tx_datas: Vec<SpanBatchTransactionData>,
+    /// The nonces of the transactions.
+    pub tx_nonces: Vec<u64>,
+    /// The gas limits of the transactions.
+    pub tx_gases: Vec<u64>,
+    /// Whether each legacy transaction is replay protected, i.e. signed with EIP-155.
+    pub protected_bits: SpanBatchBits,
+}
+
+impl SpanBatchTransactions {
+    /// Returns the number of transactions.
+    pub const fn len(&self) -> usize {
+        self.tx_sigs.len()
+    }
+
+    /// Returns `true` if there are no transactions.
+    pub const fn is_empty(&self) -> bool {
+        self.tx_sigs.is_empty()
+    }
+
+    /// Appends a transaction of the chain with ID `chain_id`.
+    ///
+    /// Deposit transactions can not be part of a batch, and replay protected transactions must be
+    /// signed for `chain_id`.
+    pub fn push(&mut self, tx: &OpTxEnvelope, chain_id: ChainId) -> Result<(), BatchError> {
+        let check_chain_id = |tx_chain_id: ChainId| {
+            if tx_chain_id == chain_id {
+                Ok(())
+            } else {
+                Err(BatchError::ChainIdMismatch { expected: chain_id, got: tx_chain_id })
+            }
+        };
+        let (data, to, nonce, gas, signature) = match tx {
+            OpTxEnvelope::Legacy(tx) => {
+                let (tx, signature) = (tx.tx(), tx.signature());
+                if let Some(tx_chain_id) = tx.chain_id {
+                    check_chain_id(tx_chain_id)?;
+                }
+                self.protected_bits.push(tx.chain_id.is_some());
+                let data = SpanBatchLegacyTransactionData {
+                    value: tx.value,
+                    gas_price: tx.gas_price,
+                    data: tx.input.clone(),
+                };
+                (SpanBatchTransactionData::Legacy(data), tx.to, tx.nonce, tx.gas_limit, signature)
+            }
+            OpTxEnvelope::Eip2930(tx) => {
+                let (tx, signature) = (tx.tx(), tx.signature());
+                check_chain_id(tx.chain_id)?;
+                let data = SpanBatchEip2930TransactionData {
+                    value: tx.value,
+                    gas_price: tx.gas_price,
+                    data: tx.input.clone(),
+                    access_list: tx.access_list.clone(),
+                };
+                (SpanBatchTransactionData::Eip2930(data), tx.to, tx.nonce, tx.gas_limit, signature)
+            }
+            OpTxEnvelope::Eip1559(tx) => {
+                let (tx, signature) = (tx.tx(), tx.signature());
+                check_chain_id(tx.chain_id)?;
+                let data = SpanBatchEip1559TransactionData {
+                    value: tx.value,
+                    max_priority_fee_per_gas: tx.max_priority_fee_per_gas,
+                    max_fee_per_gas: tx.max_fee_per_gas,
+                    data: tx.input.clone(),
+                    access_list: tx.access_list.clone(),
+                };
+                (SpanBatchTransactionData::Eip1559(data), tx.to, tx.nonce, tx.gas_limit, signature)
+            }
+            OpTxEnvelope::Eip7702(tx) => {
+                let (tx, signature) = (tx.tx(), tx.signature());
+                check_chain_id(tx.chain_id)?;
+                let data = SpanBatchEip7702TransactionData {
+                    value: tx.value,
+                    max_priority_fee_per_gas: tx.max_priority_fee_per_gas,
+                    max_fee_per_gas: tx.max_fee_per_gas,
+                    data: tx.input.clone(),
+                    access_list: tx.access_list.clone(),
+                    authorization_list: tx.authorization_list.clone(),
+                };
+                let to = TxKind::Call(tx.to);
+                (SpanBatchTransactionData::Eip7702(data), to, tx.nonce, tx.gas_limit, signature)
+            }
+            OpTxEnvelope::Deposit(_) => return Err(BatchError::DepositTransaction),
+        };
+
+        self.contract_creation_bits.push(to.is_create());
+        if let TxKind::Call(to) = to {
+            self.tx_tos.push(to);
+        }
+        self.tx_sigs.push(*signature);
+        self.tx_datas.push(data);
+        self.tx_nonces.push(nonce);
+        self.tx_gases.push(gas);
+        Ok(())
+    }
+
+    /// Converts the transactions back to [`OpTxEnvelope`]s of the chain with ID `chain_id`.
+    pub fn to_envelopes(&self, chain_id: ChainId) -> Result<Vec<OpTxEnvelope>, BatchError> {
+        let mut tos = self.tx_tos.iter().copied();
+        let mut protected_bits = self.protected_bits.iter();
+        let mut txs = Vec::with_capacity(self.len());
+        for (index, data) in self.tx_datas.iter().enumerate() {
+            let to = if self.contract_creation_bits.get(index) == Some(true) {
+                TxKind::Create
+            } else {
+                TxKind::Call(tos.next().ok_or(BatchError::UnexpectedEnd)?)
+            };
+            let (nonce, gas_limit, signature) =
+                (self.tx_nonces[index], self.tx_gases[index], self.tx_sigs[index]);
+            let tx = match data.clone() {
+                SpanBatchTransactionData::Legacy(data) => {
+                    let protected = protected_bits.next().ok_or(BatchError::UnexpectedEnd)?;
+                    let tx = TxLegacy {
+                        chain_id: protected.then_some(chain_id),
+                        nonce,
+                        gas_price: data.gas_price,
+                        gas_limit,
+                        to,
+                        value: data.value,
+                        input: data.data,
+                    };
+                    OpTxEnvelope::Legacy(Signed::new_unhashed(tx, signature))
+                }
+                SpanBatchTransactionData::Eip2930(data) => {
+                    let tx = TxEip2930 {
+                        chain_id,
+                        nonce,
+                        gas_price: data.gas_price,
+                        gas_limit,
+                        to,
+                        value: data.value,
+                        access_list: data.access_list,
+                        input: data.data,
+                    };
+                    OpTxEnvelope::Eip2930(Signed::new_unhashed(tx, signature))
+                }
+                SpanBatchTransactionData::Eip1559(data) => {
+                    let tx = TxEip1559 {
+                        chain_id,
+                        nonce,
+                        gas_limit,
+                        max_fee_per_gas: data.max_fee_per_gas,
+                        max_priority_fee_per_gas: data.max_priority_fee_per_gas,
+                        to,
+                        value: data.value,
+                        access_list: data.access_list,
+                        input: data.data,
+                    };
+                    OpTxEnvelope::Eip1559(Signed::new_unhashed(tx, signature))
+                }
+                SpanBatchTransactionData::Eip7702(data) => {
+                    let TxKind::Call(to) = to else {
+                        return Err(BatchError::ContractCreation(index));
+                    };
+                    let tx = TxEip7702 {
+                        chain_id,
+                        nonce,
+                        gas_limit,
+                        max_fee_per_gas: data.max_fee_per_gas,
+                        max_priority_fee_per_gas: data.max_priority_fee_per_gas,
+                        to,
+                        value: data.value,
+                        access_list: data.access_list,
+                        authorization_list: data.authorization_list,
+                        input: data.data,
+                    };
+                    OpTxEnvelope::Eip7702(Signed::new_unhashed(tx, signature))
+                }
+            };
+            txs.push(tx);
+        }
+        Ok(txs)
+    }
+
+    /// Encodes the transactions.
+    pub fn encode(&self, out: &mut Vec<u8>) {
+        self.contract_creation_bits.encode(out);
+        let y_parity_bits: SpanBatchBits = self.tx_sigs.iter().map(Signature::v).collect();
+        y_parity_bits.encode(out);
+        for signature in &self.tx_sigs {
+            out.extend_from_slice(&signature.r().to_be_bytes::<32>());
+            out.extend_from_slice(&signature.s().to_be_bytes::<32>());
+        }
+        for to in &self.tx_tos {
+            out.extend_from_slice(to.as_slice());
+        }
+        for data in &self.tx_datas {
+            data.encode(out);
+        }
+        for nonce in &self.tx_nonces {
+            codec::write_uvarint(out, *nonce);
+        }
+        for gas in &self.tx_gases {
+            codec::write_uvarint(out, *gas);
+        }
+        self.protected_bits.encode(out);
+    }
+
+    /// Decodes `count` transactions from the start of `buf`, advancing it past the transactions.
+    pub fn decode(buf: &mut &[u8], count: usize) -> Result<Self, BatchError> {
+        let contract_creation_bits = SpanBatchBits::decode(buf, count)?;
+        let y_parity_bits = SpanBatchBits::decode(buf, count)?;
+        let tx_sigs = y_parity_bits
+            .iter()
+            .map(|y_parity| {
+                let r = U256::from_be_bytes::<32>(codec::read_array(buf)?);
+                let s = U256::from_be_bytes::<32>(codec::read_array(buf)?);
+                Ok(Signature::new(r, s, y_parity))
+            })
+            .collect::<Result<_, BatchError>>()?;
+        let tx_tos = (0..count - contract_creation_bits.count_ones())
+            .map(|_| codec::read_array(buf).map(Address::from))
+            .collect::<Result<_, _>>()?;
+        let tx_datas: Vec<_> =
+            (0..count).map(|_| SpanBatchTransactionData::decode(buf)).collect::<Result<_, _>>()?;
+        let tx_nonces = (0..count).map(|_| codec::read_uvarint(buf)).collect::<Result<_, _>>()?;
+        let tx_gases = (0..count).map(|_| codec::read_uvarint(buf)).collect::<Result<_, _>>()?;
+        let legacy_count = tx_datas
+            .iter()
+            .filter(|data| matches!(data, SpanBatchTransactionData::Legacy(_)))
+            .count();
+        let protected_bits = SpanBatchBits::decode(buf, legacy_count)?;
+        Ok(Self {
+            contract_creation_bits,
+            tx_sigs,
+            tx_tos,
+            tx_datas,
+            tx_nonces,
+            tx_gases,
+            protected_bits,
+        })
+    }
+}
@@ -0,0 +1,236 @@
+//! Selection of the batcher transactions of an L1 block.
+//!
+//! A batcher transaction is an L1 transaction sent by the batcher to the batch inbox address of
+//! the rollup. Its data is either its calldata or, from Ecotone onwards for blob transactions, the
+//! blobs it carries.
+//!
+//! See also: <https://specs.optimism.io/protocol/derivation.html#l1-retrieval>
+
+use crate::OpHardfork;
+use alloc::vec::Vec;
+use alloy_consensus::{Transaction, TxEnvelope, TxType};
+use alloy_primitives::{Address, B256, Bytes};
+
+/// The data to fetch for a batcher transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BatcherTxData {
+    /// The calldata of the transaction.
+    Calldata(Bytes),
+    /// The versioned hashes of the blobs of the transaction, to fetch from a beacon node.
+    Blobs(Vec<B256>),
+}
+
+/// The reason an L1 transaction is not a valid batcher transaction.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum BatcherTxSkipReason {
+    /// The transaction is not sent to the batch inbox address.
+    #[error("Transaction not sent to the batch inbox, recipient: {0:?}")]
+    NotBatchInbox(Option<Address>),
+    /// The transaction type can not carry batcher data.
+    #[error("Unsupported batcher transaction type: {0}")]
+    UnsupportedType(TxType),
+    /// The signer of the transaction can not be recovered.
+    #[error("Invalid batcher transaction signature")]
+    InvalidSignature,
+    /// The transaction is not sent by the batcher.
+    #[error("Transaction sender {0} is not the batcher")]
+    UnauthorizedSender(Address),
+}
+
+/// Selects the batcher transactions of an L1 block.
+///
+/// A transaction is a valid batcher transaction if it is sent to the batch inbox address, by the
+/// batcher address of the system config, and is either a calldata transaction (legacy, EIP-2930
+/// or EIP-1559) or a blob transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BatchInboxFilter {
+    batch_inbox_address: Address,
+    batcher_address: Address,
+    blobs_active: bool,
+}
+
+impl BatchInboxFilter {
+    /// Creates a new filter for the transactions of an L1 block whose L2 blocks are under
+    /// `hardfork`.
+    ///
+    /// Before Ecotone, the data of blob transactions is their calldata.
+    pub const fn new(
+        batch_inbox_address: Address,
+        batcher_address: Address,
+        hardfork: OpHardfork,
+    ) -> Self {
+        Self {
+            batch_inbox_address,
+            batcher_address,
+            blobs_active: hardfork.is_enabled_in(OpHardfork::Ecotone),
+        }
+    }
+
+    /// Returns the batch inbox address.
+    pub const fn batch_inbox_address(&self) -> Address {
+        self.batch_inbox_address
+    }
+
+    /// Returns the batcher address.
+    pub const fn batcher_address(&self) -> Address {
+        self.batcher_address
+    }
+
+    /// Checks a transaction sent by `sender`, returning the data to fetch if it is a valid batcher
+    /// transaction.
+    pub fn check_with_sender(
+        &self,
+        tx: &TxEnvelope,
+        sender: Address,
+    ) -> Result<BatcherTxData, BatcherTxSkipReason> {
+        self.check_recipient(tx)?;
+        self.check_sender(sender)?;
+        Ok(self.data(tx))
+    }
+
+    /// Checks a transaction, recovering its sender, and returns the data to fetch if it is a valid
+    /// batcher transaction.
+    #[cfg(feature = "k256")]
+    pub fn check(&self, tx: &TxEnvelope) -> Result<BatcherTxData, BatcherTxSkipReason> {
+        use alloy_consensus::transaction::SignerRecoverable;
+
+        self.check_recipient(tx)?;
+        let sender = tx.recover_signer().map_err(|_| BatcherTxSkipReason::InvalidSignature)?;
+        self.check_sender(sender)?;
+        Ok(self.data(tx))
+    }
+
+    /// Checks the transactions of an L1 block, returning the result of [`Self::check`] for each of
+    /// them, in order.
+    #[cfg(feature = "k256")]
+    pub fn filter<'a>(
+        &self,
+        txs: impl IntoIterator<Item = &'a TxEnvelope>,
+    ) -> Vec<Result<BatcherTxData, BatcherTxSkipReason>> {
+        txs.into_iter().map(|tx| self.check(tx)).collect()
+    }
+
+    fn check_recipient(&self, tx: &TxEnvelope) -> Result<(), BatcherTxSkipReason> {
+        if tx.to() != Some(self.batch_inbox_address) {
+            return Err(BatcherTxSkipReason::NotBatchInbox(tx.to()));
+        }
+        match tx.tx_type() {
+            TxType::Legacy | TxType::Eip2930 | TxType::Eip1559 | TxType::Eip4844 => Ok(()),
+            ty => Err(BatcherTxSkipReason::UnsupportedType(ty)),
+        }
+    }
+
+    fn check_sender(&self, sender: Address) -> Result<(), BatcherTxSkipReason> {
+        if sender != self.batcher_address {
+            return Err(BatcherTxSkipReason::UnauthorizedSender(sender));
+        }
+        Ok(())
+    }
+
+    fn data(&self, tx: &TxEnvelope) -> BatcherTxData {
+        match tx.blob_versioned_hashes() {
+            Some(hashes) if self.blobs_active => BatcherTxData::Blobs(hashes.to_vec()),
+            _ => BatcherTxData::Calldata(tx.input().clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{Signed, TxEip1559, TxEip4844, TxEip4844Variant, TxEip7702};
+    use alloy_primitives::{Signature, TxKind};
+
+    const INBOX: Address = Address::repeat_byte(0xff);
+    const BATCHER: Address = Address::repeat_byte(0xbb);
+
+    fn calldata_tx(to: Address) -> TxEnvelope {
+        let tx = TxEip1559 {
+            to: TxKind::Call(to),
+            input: Bytes::from_static(b"frames"),
+            ..Default::default()
+        };
+        TxEnvelope::Eip1559(Signed::new_unhashed(tx, Signature::test_signature()))
+    }
+
+    fn blob_tx() -> TxEnvelope {
+        let tx = TxEip4844 {
+            to: INBOX,
+            blob_versioned_hashes: alloc::vec![B256::repeat_byte(1)],
+            input: Bytes::from_static(b"ignored"),
+            ..Default::default()
+        };
+        TxEnvelope::Eip4844(Signed::new_unhashed(
+            TxEip4844Variant::TxEip4844(tx),
+            Signature::test_signature(),
+        ))
+    }
+
+    #[test]
+    fn test_check_with_sender() {
+        let filter = BatchInboxFilter::new(INBOX, BATCHER, OpHardfork::Ecotone);
+        assert_eq!(
+            filter.check_with_sender(&calldata_tx(INBOX), BATCHER),
+            Ok(BatcherTxData::Calldata(Bytes::from_static(b"frames")))
+        );
+        assert_eq!(
+            filter.check_with_sender(&blob_tx(), BATCHER),
+            Ok(BatcherTxData::Blobs(alloc::vec![B256::repeat_byte(1)]))
+        );
+
+        let filter = BatchInboxFilter::new(INBOX, BATCHER, OpHardfork::Delta);
+        assert_eq!(
+            filter.check_with_sender(&blob_tx(), BATCHER),
+            Ok(BatcherTxData::Calldata(Bytes::from_static(b"ignored")))
+        );
+    }
+
+    #[test]
+    fn test_check_with_sender_skipped() {
+        let filter = BatchInboxFilter::new(INBOX, BATCHER, OpHardfork::Ecotone);
+        assert_eq!(
+            filter.check_with_sender(&calldata_tx(BATCHER), BATCHER),
+            Err(BatcherTxSkipReason::NotBatchInbox(Some(BATCHER)))
+        );
+        assert_eq!(
+            filter.check_with_sender(&calldata_tx(INBOX), INBOX),
+            Err(BatcherTxSkipReason::UnauthorizedSender(INBOX))
+        );
+
+        let tx = TxEip7702 { to: INBOX, ..Default::default() };
+        let tx = TxEnvelope::Eip7702(Signed::new_unhashed(tx, Signature::test_signature()));
+        assert_eq!(
+            filter.check_with_sender(&tx, BATCHER),
+            Err(BatcherTxSkipReason::UnsupportedType(TxType::Eip7702))
+        );
+    }
+
+    #[cfg(feature = "k256")]
+    #[test]
+    fn test_filter() {
+        use alloy_consensus::{
+            SignableTransaction, crypto::secp256k1, transaction::SignerRecoverable,
+        };
+
+        let secret = B256::repeat_byte(0x42);
+        let tx = TxEip1559 {
+            to: TxKind::Call(INBOX),
+            input: Bytes::from_static(b"frames"),
+            ..Default::default()
+        };
+        let signature = secp256k1::sign_message(secret, tx.signature_hash()).unwrap();
+        let signed = TxEnvelope::Eip1559(tx.into_signed(signature));
+        let sender = signed.recover_signer().unwrap();
+        // The blob transaction is signed with the test signature, by some other account.
+        let blob_sender = blob_tx().recover_signer().unwrap();
+
+        let filter = BatchInboxFilter::new(INBOX, sender, OpHardfork::Ecotone);
+        let results = filter.filter([&signed, &calldata_tx(BATCHER), &blob_tx()]);
+        assert_eq!(results[0], Ok(BatcherTxData::Calldata(Bytes::from_static(b"frames"))));
+        assert_eq!(results[1], Err(BatcherTxSkipReason::NotBatchInbox(Some(BATCHER))));
+        assert_eq!(results[2], Err(BatcherTxSkipReason::UnauthorizedSender(blob_sender)));
+
+        let filter = BatchInboxFilter::new(INBOX, BATCHER, OpHardfork::Ecotone);
+        assert_eq!(filter.check(&signed), Err(BatcherTxSkipReason::UnauthorizedSender(sender)));
+    }
+}
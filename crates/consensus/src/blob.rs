@@ -0,0 +1,246 @@
+//! The encoding of batcher transaction data in EIP-4844 blobs.
+//!
+//! A blob is made of 4096 field elements of 32 bytes, whose two most significant bits must be
+//! zero. The data is packed in 1024 rounds of 4 field elements: each field element carries 31
+//! bytes of data in its last 31 bytes, and the 6 low bits of its first byte together carry 3 more
+//! bytes per round. The first field element starts with the encoding version and the length of the
+//! data.
+//!
+//! See also: <https://specs.optimism.io/protocol/derivation.html#blob-encoding>
+
+use crate::{Frame, FrameError};
+use alloc::{boxed::Box, vec::Vec};
+use alloy_eips::eip4844::{BYTES_PER_BLOB, Blob};
+use alloy_primitives::Bytes;
+
+/// The version of the blob encoding, the only one in use.
+pub const BLOB_ENCODING_VERSION: u8 = 0;
+
+/// The maximum length of the data encoded in a blob.
+pub const BLOB_MAX_DATA_SIZE: usize = (4 * 31 + 3) * BLOB_ENCODING_ROUNDS - 4;
+
+/// The number of rounds of 4 field elements in a blob.
+const BLOB_ENCODING_ROUNDS: usize = BYTES_PER_BLOB / (4 * 32);
+
+/// The length of the header of the first field element: its first byte, the version and the
+/// 3-byte length of the data.
+const BLOB_HEADER_LEN: usize = 5;
+
+/// The data of a batcher transaction encoded in a blob.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BlobData {
+    /// The data of the batcher transaction.
+    pub data: Bytes,
+}
+
+impl BlobData {
+    /// Creates a new blob data.
+    pub const fn new(data: Bytes) -> Self {
+        Self { data }
+    }
+
+    /// Parses the frames of the data, see [`Frame::parse_frames`].
+    pub fn frames(&self) -> Result<Vec<Frame>, FrameError> {
+        Frame::parse_frames(&self.data)
+    }
+
+    /// Encodes the data in a blob.
+    pub fn encode(&self) -> Result<Box<Blob>, BlobDataError> {
+        let data = &self.data[..];
+        if data.len() > BLOB_MAX_DATA_SIZE {
+            return Err(BlobDataError::TooLarge(data.len()));
+        }
+
+        let mut blob = Box::new(Blob::ZERO);
+        let mut offset = 0;
+        for round in 0..BLOB_ENCODING_ROUNDS {
+            if offset >= data.len() {
+                break;
+            }
+            let first = if round == 0 {
+                let mut chunk = [0; 31];
+                chunk[0] = BLOB_ENCODING_VERSION;
+                chunk[1..4].copy_from_slice(&(data.len() as u32).to_be_bytes()[1..]);
+                let len = data.len().min(31 - 4);
+                chunk[4..4 + len].copy_from_slice(&data[..len]);
+                offset += len;
+                chunk
+            } else {
+                read_chunk(data, &mut offset)
+            };
+            let [x] = read_chunk(data, &mut offset);
+            let second = read_chunk(data, &mut offset);
+            let [y] = read_chunk(data, &mut offset);
+            let third = read_chunk(data, &mut offset);
+            let [z] = read_chunk(data, &mut offset);
+            let fourth = read_chunk::<31>(data, &mut offset);
+
+            let elements = [
+                (x & 0b0011_1111, first),
+                ((y & 0b0000_1111) | ((x & 0b1100_0000) >> 2), second),
+                (z & 0b0011_1111, third),
+                (((z & 0b1100_0000) >> 2) | ((y & 0b1111_0000) >> 4), fourth),
+            ];
+            for (i, (high, chunk)) in elements.into_iter().enumerate() {
+                let start = (round * 4 + i) * 32;
+                blob[start] = high;
+                blob[start + 1..start + 32].copy_from_slice(&chunk);
+            }
+        }
+        Ok(blob)
+    }
+
+    /// Decodes the data encoded in a blob.
+    ///
+    /// The blob is rejected if its version is not [`BLOB_ENCODING_VERSION`], if any of its field
+    /// elements has one of its two most significant bits set, or if it carries non-zero bytes after
+    /// the end of the data.
+    pub fn decode(blob: &Blob) -> Result<Self, BlobDataError> {
+        if blob[1] != BLOB_ENCODING_VERSION {
+            return Err(BlobDataError::InvalidVersion(blob[1]));
+        }
+        let len = u32::from_be_bytes([0, blob[2], blob[3], blob[4]]) as usize;
+        if len > BLOB_MAX_DATA_SIZE {
+            return Err(BlobDataError::InvalidLength(len));
+        }
+
+        let mut data = Vec::with_capacity(len + 127);
+        let mut round = 0;
+        while round == 0 || (round < BLOB_ENCODING_ROUNDS && data.len() < len) {
+            let mut high = [0; 4];
+            let mut positions = [0; 3];
+            for (i, high) in high.iter_mut().enumerate() {
+                let start = (round * 4 + i) * 32;
+                if blob[start] & 0b1100_0000 != 0 {
+                    return Err(BlobDataError::InvalidFieldElement(round * 4 + i));
+                }
+                *high = blob[start];
+                let skip = if round == 0 && i == 0 { BLOB_HEADER_LEN } else { 1 };
+                data.extend_from_slice(&blob[start + skip..start + 32]);
+                if i < 3 {
+                    positions[i] = data.len();
+                    data.push(0);
+                }
+            }
+            data[positions[0]] = (high[0] & 0b0011_1111) | ((high[1] & 0b0011_0000) << 2);
+            data[positions[1]] = (high[1] & 0b0000_1111) | ((high[3] & 0b0000_1111) << 4);
+            data[positions[2]] = (high[2] & 0b0011_1111) | ((high[3] & 0b0011_0000) << 2);
+            round += 1;
+        }
+
+        if let Some(index) = data.iter().skip(len).position(|byte| *byte != 0) {
+            return Err(BlobDataError::NonZeroPadding(len + index));
+        }
+        if let Some(index) = blob[round * 4 * 32..].iter().position(|byte| *byte != 0) {
+            return Err(BlobDataError::NonZeroPadding(round * 4 * 32 + index));
+        }
+        data.truncate(len);
+        Ok(Self { data: data.into() })
+    }
+}
+
+/// Reads `N` bytes from `data` at `offset`, padded with zeros past the end of `data`, and advances
+/// `offset` past the bytes read.
+fn read_chunk<const N: usize>(data: &[u8], offset: &mut usize) -> [u8; N] {
+    let mut chunk = [0; N];
+    let rest = data.get(*offset..).unwrap_or_default();
+    let len = rest.len().min(N);
+    chunk[..len].copy_from_slice(&rest[..len]);
+    *offset += len;
+    chunk
+}
+
+/// Errors that can occur when encoding or decoding blob data.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum BlobDataError {
+    /// The data is longer than [`BLOB_MAX_DATA_SIZE`].
+    #[error("Blob data too large: {0} bytes")]
+    TooLarge(usize),
+    /// The encoding version of the blob is not [`BLOB_ENCODING_VERSION`].
+    #[error("Invalid blob encoding version: {0}")]
+    InvalidVersion(u8),
+    /// The length of the data encoded in the blob exceeds [`BLOB_MAX_DATA_SIZE`].
+    #[error("Invalid blob data length: {0}")]
+    InvalidLength(usize),
+    /// The field element at the given index has one of its two most significant bits set.
+    #[error("Invalid blob field element: {0}")]
+    InvalidFieldElement(usize),
+    /// The blob has a non-zero byte after the end of its data, at the given position of the
+    /// decoded data or of the blob.
+    #[error("Non-zero blob padding at position {0}")]
+    NonZeroPadding(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ChannelId;
+    use alloc::vec;
+
+    fn blob_data(len: usize) -> BlobData {
+        BlobData::new((0..len).map(|i| (i % 251) as u8 + 1).collect())
+    }
+
+    #[test]
+    fn test_blob_data_roundtrip() {
+        for len in [0, 1, 27, 28, 123, 124, 250, 1000, BLOB_MAX_DATA_SIZE - 1, BLOB_MAX_DATA_SIZE] {
+            let data = blob_data(len);
+            let blob = data.encode().unwrap();
+            assert!(blob.chunks(32).all(|element| element[0] & 0b1100_0000 == 0));
+            assert_eq!(BlobData::decode(&blob), Ok(data), "length {len}");
+        }
+    }
+
+    #[test]
+    fn test_blob_data_layout() {
+        let blob = blob_data(128).encode().unwrap();
+        assert_eq!(blob[1..5], [BLOB_ENCODING_VERSION, 0, 0, 128]);
+        assert_eq!(blob[5..32], blob_data(27).data[..]);
+        // The byte following the first 27 bytes is carried by the first bytes of the round.
+        assert_eq!(blob[0], 28 & 0b0011_1111);
+        assert_eq!(blob[33..64], blob_data(59).data[28..]);
+        assert!(blob[4 * 32 * 2..].iter().all(|byte| *byte == 0));
+    }
+
+    #[test]
+    fn test_blob_data_frames() {
+        let frames =
+            vec![Frame::new(ChannelId::repeat_byte(1), 0, Bytes::from(vec![2; 300]), true)];
+        let data = BlobData::new(Frame::encode_frames(&frames));
+        let decoded = BlobData::decode(&data.encode().unwrap()).unwrap();
+        assert_eq!(decoded.frames(), Ok(frames));
+    }
+
+    #[test]
+    fn test_blob_data_invalid() {
+        assert_eq!(
+            blob_data(BLOB_MAX_DATA_SIZE + 1).encode(),
+            Err(BlobDataError::TooLarge(BLOB_MAX_DATA_SIZE + 1))
+        );
+
+        let blob = blob_data(200).encode().unwrap();
+
+        let mut invalid = blob.clone();
+        invalid[1] = 1;
+        assert_eq!(BlobData::decode(&invalid), Err(BlobDataError::InvalidVersion(1)));
+
+        let mut invalid = blob.clone();
+        invalid[2..5].copy_from_slice(&[0xff; 3]);
+        assert_eq!(BlobData::decode(&invalid), Err(BlobDataError::InvalidLength(0xffffff)));
+
+        let mut invalid = blob.clone();
+        invalid[5 * 32] |= 0b1000_0000;
+        assert_eq!(BlobData::decode(&invalid), Err(BlobDataError::InvalidFieldElement(5)));
+
+        let mut invalid = blob.clone();
+        invalid[4 * 32 + 100] = 1;
+        assert_eq!(BlobData::decode(&invalid), Err(BlobDataError::NonZeroPadding(222)));
+
+        let mut invalid = blob;
+        invalid[BYTES_PER_BLOB - 1] = 1;
+        assert_eq!(
+            BlobData::decode(&invalid),
+            Err(BlobDataError::NonZeroPadding(BYTES_PER_BLOB - 1))
+        );
+    }
+}
@@ -1,6 +1,447 @@
 //! Optimism Block Type.
 
-use crate::OpTxEnvelope;
+use crate::{
+    L1BlockInfoError, L1BlockInfoTx, OpHardfork, OpHardforks, OpTxEnvelope, TxDeposit,
+    l1_block_info::L1_INFO_DEPOSITOR_ADDRESS, predeploys::L1_BLOCK_ADDRESS, upgrade_transactions,
+};
+use alloc::vec::Vec;
+use alloy_primitives::{Address, B256, TxKind};
 
 /// An Optimism block type.
 pub type OpBlock = alloy_consensus::Block<OpTxEnvelope>;
+
+/// Validates the OP Stack specific structure of the transactions of a block.
+///
+/// The validator checks that:
+/// - the first transaction is the L1 info deposit, with the source hash of its L1 origin and
+///   sequence number, see [`L1BlockInfoTx::source`];
+/// - all deposits come before the user transactions;
+/// - no deposit is flagged as a system transaction from Regolith onwards;
+/// - the upgrade deposits of a hardfork are included in its activation block, and only there, in
+///   order and after all the other deposits.
+///
+/// Every rule is checked, so that all the violations of a block are reported at once. The
+/// execution of the block is not validated, nor are the upgrade deposits of the hardforks for which
+/// [`upgrade_transactions`] returns `None`.
+///
+/// See also: <https://specs.optimism.io/protocol/derivation.html#building-individual-payload-attributes>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OpBlockValidator {
+    hardforks: OpHardforks,
+    block_time: u64,
+}
+
+impl OpBlockValidator {
+    /// Creates a new validator for the chain with the given hardfork schedule and L2 block time.
+    pub const fn new(hardforks: OpHardforks, block_time: u64) -> Self {
+        Self { hardforks, block_time }
+    }
+
+    /// Returns the hardfork schedule of the chain.
+    pub const fn hardforks(&self) -> &OpHardforks {
+        &self.hardforks
+    }
+
+    /// Returns the L2 block time of the chain.
+    pub const fn block_time(&self) -> u64 {
+        self.block_time
+    }
+
+    /// Validates the transactions of `block`, returning all the violated rules.
+    pub fn validate(&self, block: &OpBlock) -> Result<(), OpBlockError> {
+        let violations = self.violations(block);
+        OpBlockError::new(violations).map_or(Ok(()), Err)
+    }
+
+    /// Returns the rules violated by the transactions of `block`, in the order they are checked.
+    pub fn violations(&self, block: &OpBlock) -> Vec<OpBlockViolation> {
+        let mut violations = Vec::new();
+        let transactions = &block.body.transactions;
+        let timestamp = block.header.timestamp;
+
+        match transactions.first().and_then(OpTxEnvelope::as_deposit) {
+            Some(l1_info) => check_l1_info_tx(l1_info, &mut violations),
+            None => violations.push(OpBlockViolation::MissingL1InfoTx),
+        }
+
+        let is_regolith = self.hardforks.is_active_at(OpHardfork::Regolith, timestamp);
+        let mut seen_user_tx = false;
+        for (index, tx) in transactions.iter().enumerate() {
+            let Some(deposit) = tx.as_deposit() else {
+                seen_user_tx = true;
+                continue;
+            };
+            if seen_user_tx {
+                violations.push(OpBlockViolation::DepositAfterUserTx { index });
+            }
+            if is_regolith && deposit.is_system_transaction {
+                violations.push(OpBlockViolation::SystemTxAfterRegolith { index });
+            }
+        }
+
+        self.check_upgrade_deposits(block, &mut violations);
+        violations
+    }
+
+    /// Checks that the upgrade deposits of each hardfork are included in its activation block,
+    /// and only there.
+    ///
+    /// In an activation block, the upgrade deposits come in order right after the L1 info deposit
+    /// and the user deposits, and are the last deposits of the block.
+    fn check_upgrade_deposits(&self, block: &OpBlock, violations: &mut Vec<OpBlockViolation>) {
+        let timestamp = block.header.timestamp;
+        let transactions = &block.body.transactions;
+        // The L1 info deposit is checked separately.
+        let deposits: Vec<(usize, &TxDeposit)> = transactions
+            .iter()
+            .enumerate()
+            .skip(1)
+            .filter_map(|(index, tx)| tx.as_deposit().map(|tx| (index, tx.inner())))
+            .collect();
+        let find_deposit = |source_hash: B256| {
+            deposits.iter().find(|(_, tx)| tx.source_hash == source_hash).map(|(index, _)| *index)
+        };
+
+        let mut expected = Vec::new();
+        for fork in OpHardfork::VARIANTS {
+            let is_activation_block =
+                self.hardforks.is_activation_block(fork, timestamp, self.block_time);
+            if deposits.is_empty() && !is_activation_block {
+                continue;
+            }
+            match upgrade_transactions(fork) {
+                Some(upgrades) if is_activation_block => expected.extend(upgrades),
+                Some(upgrades) => {
+                    for upgrade in upgrades {
+                        if let Some(index) = find_deposit(upgrade.tx.source_hash) {
+                            violations
+                                .push(OpBlockViolation::UnexpectedUpgradeDeposit { index, fork });
+                        }
+                    }
+                }
+                // The upgrade deposits of the hardfork are not known, so they are not checked.
+                None => {}
+            }
+        }
+
+        // The upgrade deposits end the leading run of deposits.
+        let deposits_end = transactions.iter().take_while(|tx| tx.is_deposit()).count();
+        let start = deposits_end.saturating_sub(expected.len()).max(1);
+        for (index, upgrade) in (start..).zip(&expected) {
+            let included = transactions.get(index).and_then(OpTxEnvelope::as_deposit);
+            match included {
+                Some(tx) if tx.source_hash == upgrade.tx.source_hash => {
+                    if *tx.inner() != upgrade.tx {
+                        violations.push(OpBlockViolation::InvalidUpgradeDeposit {
+                            index,
+                            intent: upgrade.intent,
+                        });
+                    }
+                }
+                _ => match find_deposit(upgrade.tx.source_hash) {
+                    Some(index) => violations.push(OpBlockViolation::MisplacedUpgradeDeposit {
+                        index,
+                        intent: upgrade.intent,
+                    }),
+                    None => violations
+                        .push(OpBlockViolation::MissingUpgradeDeposit { intent: upgrade.intent }),
+                },
+            }
+        }
+    }
+}
+
+/// Checks the L1 info deposit against the L1 origin and sequence number it carries.
+fn check_l1_info_tx(tx: &TxDeposit, violations: &mut Vec<OpBlockViolation>) {
+    match L1BlockInfoTx::decode_calldata(&tx.input) {
+        Ok(info) => {
+            let expected = info.source().source_hash();
+            if tx.source_hash != expected {
+                violations
+                    .push(OpBlockViolation::InvalidL1InfoSource { expected, got: tx.source_hash });
+            }
+        }
+        Err(err) => violations.push(OpBlockViolation::InvalidL1InfoCalldata(err)),
+    }
+    if tx.from != L1_INFO_DEPOSITOR_ADDRESS {
+        violations.push(OpBlockViolation::InvalidL1InfoSender(tx.from));
+    }
+    if tx.to != TxKind::Call(L1_BLOCK_ADDRESS) {
+        violations.push(OpBlockViolation::InvalidL1InfoTarget(tx.to));
+    }
+}
+
+/// A block that violates OP Stack specific rules, with all the violated rules.
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+#[error("Block violates {} OP Stack rules, first: {}", .violations.len(), .violations[0])]
+pub struct OpBlockError {
+    /// The violated rules, never empty.
+    violations: Vec<OpBlockViolation>,
+}
+
+impl OpBlockError {
+    /// Creates a new error from the violated rules, or returns `None` if there are none.
+    pub fn new(violations: Vec<OpBlockViolation>) -> Option<Self> {
+        if violations.is_empty() { None } else { Some(Self { violations }) }
+    }
+
+    /// Returns the violated rules, in the order they are checked.
+    pub fn violations(&self) -> &[OpBlockViolation] {
+        &self.violations
+    }
+
+    /// Consumes the error and returns the violated rules.
+    pub fn into_violations(self) -> Vec<OpBlockViolation> {
+        self.violations
+    }
+}
+
+/// An OP Stack specific rule violated by a block, see [`OpBlockValidator`].
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum OpBlockViolation {
+    /// The block does not start with a deposit.
+    #[error("Block does not start with an L1 info deposit")]
+    MissingL1InfoTx,
+    /// The calldata of the L1 info deposit could not be decoded.
+    #[error("Invalid L1 info deposit calldata: {0}")]
+    InvalidL1InfoCalldata(L1BlockInfoError),
+    /// The source hash of the L1 info deposit does not match its L1 origin and sequence number.
+    #[error("Invalid L1 info deposit source hash: expected {expected}, got {got}")]
+    InvalidL1InfoSource {
+        /// The source hash derived from the calldata of the deposit.
+        expected: B256,
+        /// The source hash of the deposit.
+        got: B256,
+    },
+    /// The L1 info deposit is not sent by the depositor account.
+    #[error("Invalid L1 info deposit sender: {0}")]
+    InvalidL1InfoSender(Address),
+    /// The L1 info deposit does not call the `L1Block` predeploy.
+    #[error("Invalid L1 info deposit target: {0:?}")]
+    InvalidL1InfoTarget(TxKind),
+    /// A deposit comes after a user transaction.
+    #[error("Deposit at index {index} after a user transaction")]
+    DepositAfterUserTx {
+        /// The index of the deposit in the block.
+        index: usize,
+    },
+    /// A deposit is flagged as a system transaction from Regolith onwards.
+    #[error("System transaction at index {index} after Regolith")]
+    SystemTxAfterRegolith {
+        /// The index of the deposit in the block.
+        index: usize,
+    },
+    /// An upgrade deposit is included outside of the activation block of its hardfork.
+    #[error("Unexpected {fork} upgrade deposit at index {index}")]
+    UnexpectedUpgradeDeposit {
+        /// The index of the deposit in the block.
+        index: usize,
+        /// The hardfork of the upgrade deposit.
+        fork: OpHardfork,
+    },
+    /// An upgrade deposit does not match the expected upgrade transaction.
+    #[error("Invalid upgrade deposit at index {index}: {intent}")]
+    InvalidUpgradeDeposit {
+        /// The index of the deposit in the block.
+        index: usize,
+        /// The intent of the expected upgrade transaction.
+        intent: &'static str,
+    },
+    /// An upgrade deposit is included in the activation block of its hardfork, but not at its
+    /// position.
+    #[error("Misplaced upgrade deposit at index {index}: {intent}")]
+    MisplacedUpgradeDeposit {
+        /// The index of the deposit in the block.
+        index: usize,
+        /// The intent of the upgrade transaction.
+        intent: &'static str,
+    },
+    /// An upgrade deposit is missing from the activation block of its hardfork.
+    #[error("Missing upgrade deposit: {intent}")]
+    MissingUpgradeDeposit {
+        /// The intent of the missing upgrade transaction.
+        intent: &'static str,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{L1BlockInfoEcotone, upgrade_deposits};
+    use alloc::vec;
+    use alloy_consensus::{BlockBody, Header, Sealable, Signed, TxLegacy};
+    use alloy_primitives::Signature;
+
+    const BLOCK_TIME: u64 = 2;
+
+    fn validator() -> OpBlockValidator {
+        let hardforks = OpHardforks::default()
+            .with_activation(OpHardfork::Regolith, 0)
+            .with_activation(OpHardfork::Ecotone, 0)
+            .with_activation(OpHardfork::Fjord, 100);
+        OpBlockValidator::new(hardforks, BLOCK_TIME)
+    }
+
+    fn l1_info_tx() -> TxDeposit {
+        let info = L1BlockInfoTx::Ecotone(L1BlockInfoEcotone {
+            block_hash: B256::repeat_byte(1),
+            sequence_number: 3,
+            ..Default::default()
+        });
+        info.to_deposit_tx(true)
+    }
+
+    fn deposit(tx: TxDeposit) -> OpTxEnvelope {
+        OpTxEnvelope::Deposit(tx.seal_slow())
+    }
+
+    fn user_tx() -> OpTxEnvelope {
+        OpTxEnvelope::Legacy(Signed::new_unhashed(TxLegacy::default(), Signature::test_signature()))
+    }
+
+    fn block(timestamp: u64, transactions: Vec<OpTxEnvelope>) -> OpBlock {
+        OpBlock {
+            header: Header { timestamp, ..Default::default() },
+            body: BlockBody { transactions, ommers: vec![], withdrawals: None },
+        }
+    }
+
+    #[test]
+    fn test_validate_block() {
+        let txs = vec![deposit(l1_info_tx()), deposit(TxDeposit::default()), user_tx(), user_tx()];
+        assert_eq!(validator().validate(&block(50, txs)), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_block_violations() {
+        let system_tx = TxDeposit { is_system_transaction: true, ..Default::default() };
+        let txs = vec![user_tx(), deposit(TxDeposit::default()), deposit(system_tx)];
+        let err = validator().validate(&block(50, txs)).unwrap_err();
+        assert_eq!(
+            err.violations(),
+            [
+                OpBlockViolation::MissingL1InfoTx,
+                OpBlockViolation::DepositAfterUserTx { index: 1 },
+                OpBlockViolation::DepositAfterUserTx { index: 2 },
+                OpBlockViolation::SystemTxAfterRegolith { index: 2 },
+            ]
+        );
+        assert_eq!(
+            alloc::format!("{err}"),
+            "Block violates 4 OP Stack rules, first: Block does not start with an L1 info deposit"
+        );
+    }
+
+    #[test]
+    fn test_validate_l1_info_tx() {
+        let l1_info = l1_info_tx();
+        let expected = l1_info.source_hash;
+        let l1_info = TxDeposit {
+            source_hash: B256::ZERO,
+            from: Address::ZERO,
+            to: TxKind::Create,
+            ..l1_info
+        };
+        assert_eq!(
+            validator().violations(&block(50, vec![deposit(l1_info)])),
+            vec![
+                OpBlockViolation::InvalidL1InfoSource { expected, got: B256::ZERO },
+                OpBlockViolation::InvalidL1InfoSender(Address::ZERO),
+                OpBlockViolation::InvalidL1InfoTarget(TxKind::Create),
+            ]
+        );
+
+        let l1_info = TxDeposit { input: Default::default(), ..l1_info_tx() };
+        assert_eq!(
+            validator().violations(&block(50, vec![deposit(l1_info)])),
+            vec![OpBlockViolation::InvalidL1InfoCalldata(L1BlockInfoError::MissingSelector)]
+        );
+    }
+
+    #[test]
+    fn test_validate_upgrade_deposits() {
+        let fjord = upgrade_deposits(OpHardfork::Fjord).unwrap();
+        let txs: Vec<_> =
+            core::iter::once(l1_info_tx()).chain(fjord.clone()).map(deposit).collect();
+        assert_eq!(validator().validate(&block(100, txs.clone())), Ok(()));
+
+        // Upgrade deposits are only allowed in the activation block.
+        let violations = validator().violations(&block(102, txs));
+        assert_eq!(violations.len(), fjord.len());
+        assert_eq!(
+            violations[0],
+            OpBlockViolation::UnexpectedUpgradeDeposit { index: 1, fork: OpHardfork::Fjord }
+        );
+
+        let mut tampered = fjord[0].clone();
+        tampered.gas_limit += 1;
+        let txs = vec![deposit(l1_info_tx()), deposit(tampered)];
+        let violations = validator().violations(&block(100, txs));
+        let intents: Vec<_> =
+            upgrade_transactions(OpHardfork::Fjord).unwrap().iter().map(|tx| tx.intent).collect();
+        assert_eq!(violations.len(), fjord.len());
+        assert_eq!(
+            violations[0],
+            OpBlockViolation::InvalidUpgradeDeposit { index: 1, intent: intents[0] }
+        );
+        assert_eq!(violations[1], OpBlockViolation::MissingUpgradeDeposit { intent: intents[1] });
+    }
+
+    #[test]
+    fn test_validate_upgrade_deposits_order() {
+        let fjord = upgrade_deposits(OpHardfork::Fjord).unwrap();
+        let intents: Vec<_> =
+            upgrade_transactions(OpHardfork::Fjord).unwrap().iter().map(|tx| tx.intent).collect();
+
+        // User deposits come before the upgrade deposits.
+        let txs: Vec<_> = [l1_info_tx(), TxDeposit::default()]
+            .into_iter()
+            .chain(fjord.clone())
+            .map(deposit)
+            .chain([user_tx()])
+            .collect();
+        assert_eq!(validator().validate(&block(100, txs)), Ok(()));
+
+        let txs: Vec<_> = [l1_info_tx()]
+            .into_iter()
+            .chain(fjord.clone())
+            .chain([TxDeposit::default()])
+            .map(deposit)
+            .collect();
+        assert_eq!(
+            validator().violations(&block(100, txs))[0],
+            OpBlockViolation::MisplacedUpgradeDeposit { index: 1, intent: intents[0] }
+        );
+
+        let mut swapped = fjord;
+        swapped.swap(0, 1);
+        let txs: Vec<_> = core::iter::once(l1_info_tx()).chain(swapped).map(deposit).collect();
+        assert_eq!(
+            validator().violations(&block(100, txs)),
+            vec![
+                OpBlockViolation::MisplacedUpgradeDeposit { index: 2, intent: intents[0] },
+                OpBlockViolation::MisplacedUpgradeDeposit { index: 1, intent: intents[1] },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_unknown_upgrade_deposits() {
+        // The upgrade deposits of Jovian are not known, so its activation block is not checked
+        // for them.
+        let hardforks = validator().hardforks().with_activation(OpHardfork::Jovian, 200);
+        let validator = OpBlockValidator::new(hardforks, BLOCK_TIME);
+        for timestamp in [200, 202] {
+            let txs = vec![deposit(l1_info_tx()), deposit(TxDeposit::default()), user_tx()];
+            assert_eq!(validator.validate(&block(timestamp, txs)), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_block_error_not_empty() {
+        assert_eq!(OpBlockError::new(Vec::new()), None);
+        let err = OpBlockError::new(vec![OpBlockViolation::MissingL1InfoTx]).unwrap();
+        assert_eq!(err.violations(), [OpBlockViolation::MissingL1InfoTx]);
+        assert_eq!(err.into_violations(), vec![OpBlockViolation::MissingL1InfoTx]);
+    }
+}
@@ -0,0 +1,267 @@
+//! Channels, the compressed streams of batches that the batcher splits into frames.
+//!
+//! See also: <https://specs.optimism.io/protocol/derivation.html#channel-format>
+
+use crate::{Frame, OpHardfork};
+use alloc::{collections::BTreeMap, vec::Vec};
+use alloy_primitives::{B128, Bytes};
+
+/// The ID of a channel, chosen at random by the batcher.
+pub type ChannelId = B128;
+
+/// The maximum total size of the channels buffered by the channel bank before Fjord.
+pub const MAX_CHANNEL_BANK_SIZE: usize = 100_000_000;
+
+/// The maximum total size of the channels buffered by the channel bank from Fjord onwards.
+pub const FJORD_MAX_CHANNEL_BANK_SIZE: usize = 1_000_000_000;
+
+/// Returns the maximum total size of the channels buffered by the channel bank when `hardfork` is
+/// active. A single channel can not exceed it either.
+pub const fn max_channel_bank_size(hardfork: OpHardfork) -> usize {
+    if hardfork.is_enabled_in(OpHardfork::Fjord) {
+        FJORD_MAX_CHANNEL_BANK_SIZE
+    } else {
+        MAX_CHANNEL_BANK_SIZE
+    }
+}
+
+/// The maximum length of the decompressed data of a channel before Fjord.
+pub const MAX_RLP_BYTES_PER_CHANNEL: usize = 10_000_000;
+
+/// The maximum length of the decompressed data of a channel from Fjord onwards.
+pub const FJORD_MAX_RLP_BYTES_PER_CHANNEL: usize = 100_000_000;
+
+/// Returns the maximum length of the decompressed data of a channel when `hardfork` is active.
+pub const fn max_rlp_bytes_per_channel(hardfork: OpHardfork) -> usize {
+    if hardfork.is_enabled_in(OpHardfork::Fjord) {
+        FJORD_MAX_RLP_BYTES_PER_CHANNEL
+    } else {
+        MAX_RLP_BYTES_PER_CHANNEL
+    }
+}
+
+/// A channel being reassembled from its frames.
+///
+/// Frames can be added in any order. The channel is ready once its last frame and all the frames
+/// before it have been added, at which point its data is the concatenation of the data of its
+/// frames, see [`Channel::data`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Channel {
+    id: ChannelId,
+    max_size: usize,
+    frames: BTreeMap<u16, Frame>,
+    last_frame_number: Option<u16>,
+    size: usize,
+}
+
+impl Channel {
+    /// Creates a new empty channel, whose size can not exceed `max_size`.
+    ///
+    /// The size of a channel is the sum of the sizes of its frames, see [`Frame::size`].
+    pub const fn new(id: ChannelId, max_size: usize) -> Self {
+        Self { id, max_size, frames: BTreeMap::new(), last_frame_number: None, size: 0 }
+    }
+
+    /// Returns the ID of the channel.
+    pub const fn id(&self) -> ChannelId {
+        self.id
+    }
+
+    /// Returns the size of the channel.
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the number of frames added to the channel.
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns `true` if the last frame of the channel has been added.
+    pub const fn is_closed(&self) -> bool {
+        self.last_frame_number.is_some()
+    }
+
+    /// Adds a frame to the channel.
+    ///
+    /// Frames of another channel, duplicate frames, frames after the last frame and frames that
+    /// would make the channel exceed its maximum size are rejected, leaving the channel unchanged.
+    /// Adding the last frame drops the frames that were added after it, before checking the size.
+    pub fn add_frame(&mut self, frame: Frame) -> Result<(), ChannelError> {
+        if frame.id != self.id {
+            return Err(ChannelError::IdMismatch { expected: self.id, got: frame.id });
+        }
+        if self.frames.contains_key(&frame.number) {
+            return Err(ChannelError::DuplicateFrame(frame.number));
+        }
+        if let Some(last) = self.last_frame_number {
+            if frame.is_last {
+                return Err(ChannelError::AlreadyClosed);
+            }
+            if frame.number > last {
+                return Err(ChannelError::FrameAfterLast { number: frame.number, last });
+            }
+        }
+        // The last frame prunes the frames after it before the size is checked.
+        let pruned_size = if frame.is_last {
+            self.frames.range(frame.number..).map(|(_, frame)| frame.size()).sum()
+        } else {
+            0
+        };
+        let size = self.size - pruned_size + frame.size();
+        if size > self.max_size {
+            return Err(ChannelError::TooLarge { size, max_size: self.max_size });
+        }
+
+        if frame.is_last {
+            self.last_frame_number = Some(frame.number);
+            self.frames.split_off(&frame.number);
+        }
+        self.size = size;
+        self.frames.insert(frame.number, frame);
+        Ok(())
+    }
+
+    /// Returns `true` if all the frames of the channel have been added.
+    pub fn is_ready(&self) -> bool {
+        self.last_frame_number.is_some_and(|last| self.frames.len() == last as usize + 1)
+    }
+
+    /// Returns the data of the channel, if it is ready.
+    pub fn data(&self) -> Option<Bytes> {
+        if !self.is_ready() {
+            return None;
+        }
+        let mut data = Vec::with_capacity(self.frames.values().map(|f| f.data.len()).sum());
+        for frame in self.frames.values() {
+            data.extend_from_slice(&frame.data);
+        }
+        Some(data.into())
+    }
+}
+
+/// Errors that can occur when adding a frame to a channel.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelError {
+    /// The frame belongs to another channel.
+    #[error("Frame of channel {got} added to channel {expected}")]
+    IdMismatch {
+        /// The ID of the channel.
+        expected: ChannelId,
+        /// The channel ID of the frame.
+        got: ChannelId,
+    },
+    /// A frame with the same number was already added.
+    #[error("Duplicate frame number {0}")]
+    DuplicateFrame(u16),
+    /// The last frame of the channel was already added.
+    #[error("Channel already closed")]
+    AlreadyClosed,
+    /// The frame comes after the last frame of the channel.
+    #[error("Frame number {number} after last frame number {last}")]
+    FrameAfterLast {
+        /// The number of the frame.
+        number: u16,
+        /// The number of the last frame of the channel.
+        last: u16,
+    },
+    /// The frame would make the channel exceed its maximum size.
+    #[error("Channel size {size} exceeds maximum size {max_size}")]
+    TooLarge {
+        /// The size of the channel with the frame.
+        size: usize,
+        /// The maximum size of the channel.
+        max_size: usize,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: ChannelId = ChannelId::repeat_byte(0xaa);
+
+    fn frame(number: u16, data: &'static [u8], is_last: bool) -> Frame {
+        Frame::new(ID, number, Bytes::from_static(data), is_last)
+    }
+
+    fn channel() -> Channel {
+        Channel::new(ID, MAX_CHANNEL_BANK_SIZE)
+    }
+
+    #[test]
+    fn test_channel_out_of_order() {
+        let mut channel = channel();
+        channel.add_frame(frame(2, b"baz", true)).unwrap();
+        assert!(channel.is_closed());
+        channel.add_frame(frame(0, b"foo", false)).unwrap();
+        assert!(!channel.is_ready());
+        assert_eq!(channel.data(), None);
+
+        channel.add_frame(frame(1, b"bar", false)).unwrap();
+        assert!(channel.is_ready());
+        assert_eq!(channel.frame_count(), 3);
+        assert_eq!(channel.size(), 3 * 203);
+        assert_eq!(channel.data(), Some(Bytes::from_static(b"foobarbaz")));
+    }
+
+    #[test]
+    fn test_channel_invalid_frames() {
+        let mut channel = channel();
+        channel.add_frame(frame(0, b"foo", false)).unwrap();
+        assert_eq!(
+            channel.add_frame(frame(0, b"foo", false)),
+            Err(ChannelError::DuplicateFrame(0))
+        );
+
+        let other = Frame::new(ChannelId::ZERO, 1, Bytes::new(), false);
+        assert_eq!(
+            channel.add_frame(other),
+            Err(ChannelError::IdMismatch { expected: ID, got: ChannelId::ZERO })
+        );
+
+        channel.add_frame(frame(1, b"bar", true)).unwrap();
+        assert_eq!(channel.add_frame(frame(2, b"baz", true)), Err(ChannelError::AlreadyClosed));
+        assert_eq!(
+            channel.add_frame(frame(2, b"baz", false)),
+            Err(ChannelError::FrameAfterLast { number: 2, last: 1 })
+        );
+        assert_eq!(channel.data(), Some(Bytes::from_static(b"foobar")));
+    }
+
+    #[test]
+    fn test_channel_prunes_frames_after_last() {
+        let mut channel = channel();
+        channel.add_frame(frame(0, b"foo", false)).unwrap();
+        channel.add_frame(frame(3, b"qux", false)).unwrap();
+        channel.add_frame(frame(1, b"bar", true)).unwrap();
+        assert_eq!(channel.frame_count(), 2);
+        assert_eq!(channel.size(), 2 * 203);
+        assert_eq!(channel.data(), Some(Bytes::from_static(b"foobar")));
+    }
+
+    #[test]
+    fn test_channel_max_size() {
+        let mut channel = Channel::new(ID, 2 * 203);
+        channel.add_frame(frame(0, b"foo", false)).unwrap();
+        channel.add_frame(frame(1, b"bar", false)).unwrap();
+        assert_eq!(
+            channel.add_frame(frame(2, b"baz", true)),
+            Err(ChannelError::TooLarge { size: 3 * 203, max_size: 2 * 203 })
+        );
+        assert!(!channel.is_closed());
+
+        // Closing the channel drops the frames after the last one before checking its size.
+        let mut channel = Channel::new(ID, 2 * 203);
+        channel.add_frame(frame(0, b"foo", false)).unwrap();
+        channel.add_frame(frame(2, b"baz", false)).unwrap();
+        channel.add_frame(frame(1, b"bar", true)).unwrap();
+        assert_eq!(channel.size(), 2 * 203);
+        assert_eq!(channel.data(), Some(Bytes::from_static(b"foobar")));
+
+        assert_eq!(max_channel_bank_size(OpHardfork::Ecotone), MAX_CHANNEL_BANK_SIZE);
+        assert_eq!(max_channel_bank_size(OpHardfork::Holocene), FJORD_MAX_CHANNEL_BANK_SIZE);
+        assert_eq!(max_rlp_bytes_per_channel(OpHardfork::Delta), MAX_RLP_BYTES_PER_CHANNEL);
+        assert_eq!(max_rlp_bytes_per_channel(OpHardfork::Fjord), FJORD_MAX_RLP_BYTES_PER_CHANNEL);
+    }
+}
@@ -0,0 +1,359 @@
+//! Conversion between `OptimismPortal` `TransactionDeposited` events and user deposits.
+//!
+//! See also: <https://specs.optimism.io/protocol/deposits.html#deposit-contract>
+
+use crate::{TxDeposit, UserDepositSource};
+use alloc::vec::Vec;
+use alloy_primitives::{Address, B256, Bytes, Log, LogData, TxKind, U160, U256, address, b256};
+
+/// The signature of the deposit event emitted by the `OptimismPortal`.
+pub const DEPOSIT_EVENT_ABI: &str = "TransactionDeposited(address,address,uint256,bytes)";
+
+/// `keccak256("TransactionDeposited(address,address,uint256,bytes)")`
+pub const DEPOSIT_EVENT_ABI_HASH: B256 =
+    b256!("0xb3813568d9991fc951961fcb4c784893574240a28925604d09fc577c55bb7c32");
+
+/// The version 0 of the deposit event, the only version in use.
+pub const DEPOSIT_EVENT_VERSION_0: B256 = B256::ZERO;
+
+/// The offset added to the address of an L1 contract when it sends a message to L2.
+pub const L1_TO_L2_ALIAS_OFFSET: Address = address!("0x1111000000000000000000000000000000001111");
+
+/// The length of the fixed fields of version 0 opaque data: `mint`, `value`, `gasLimit` and
+/// `isCreation`.
+const OPAQUE_DATA_V0_FIXED_LEN: usize = 32 + 32 + 8 + 1;
+
+/// Returns the L2 alias of an L1 contract address.
+///
+/// The `OptimismPortal` applies the alias to the `from` address of deposits sent by contracts,
+/// so that they cannot impersonate an L2 contract at the same address.
+pub fn apply_l1_to_l2_alias(address: Address) -> Address {
+    let offset = U160::from_be_bytes(L1_TO_L2_ALIAS_OFFSET.0.0);
+    Address::from(U160::from_be_bytes(address.0.0).wrapping_add(offset).to_be_bytes())
+}
+
+/// Returns the L1 address that a L2 alias was derived from, see [`apply_l1_to_l2_alias`].
+pub fn undo_l1_to_l2_alias(address: Address) -> Address {
+    let offset = U160::from_be_bytes(L1_TO_L2_ALIAS_OFFSET.0.0);
+    Address::from(U160::from_be_bytes(address.0.0).wrapping_sub(offset).to_be_bytes())
+}
+
+/// Decodes a user deposit from a `TransactionDeposited` log:
+///
+/// ```solidity
+/// event TransactionDeposited(
+///     address indexed from,
+///     address indexed to,
+///     uint256 indexed version,
+///     bytes opaqueData
+/// );
+/// ```
+///
+/// The source hash is derived from the hash of the L1 block that contains the log, and the index
+/// of the log in that block. The `from` address is taken as is, as the portal already applied the
+/// [L1 to L2 alias](apply_l1_to_l2_alias) for contract senders.
+///
+/// Version 0 opaque data is `abi.encodePacked(mint, value, gasLimit, isCreation, data)`. If
+/// `isCreation` is set, the deposit creates a contract and the `to` topic is ignored.
+pub fn decode_deposit_log(
+    l1_block_hash: B256,
+    log_index: u64,
+    log: &Log,
+) -> Result<TxDeposit, DepositEventError> {
+    let topics = log.topics();
+    if topics.len() != 4 {
+        return Err(DepositEventError::UnexpectedTopicsLen(topics.len()));
+    }
+    if topics[0] != DEPOSIT_EVENT_ABI_HASH {
+        return Err(DepositEventError::InvalidSelector(topics[0]));
+    }
+    let from = read_address_topic(topics[1]).ok_or(DepositEventError::InvalidFrom(topics[1]))?;
+    let to = read_address_topic(topics[2]).ok_or(DepositEventError::InvalidTo(topics[2]))?;
+    if topics[3] != DEPOSIT_EVENT_VERSION_0 {
+        return Err(DepositEventError::UnsupportedVersion(topics[3]));
+    }
+
+    let opaque_data = decode_opaque_data(&log.data.data)?;
+    let mut tx = decode_opaque_data_v0(opaque_data, to)?;
+    tx.source_hash = UserDepositSource::new(l1_block_hash, log_index).source_hash();
+    tx.from = from;
+    Ok(tx)
+}
+
+/// Encodes a deposit into the version 0 `TransactionDeposited` log that `portal` emits for it.
+///
+/// This is the inverse of [`decode_deposit_log`]. The source hash and the system transaction
+/// flag are not part of the log.
+pub fn encode_deposit_log(tx: &TxDeposit, portal: Address) -> Log {
+    let (to, is_creation) = match tx.to {
+        TxKind::Call(to) => (to, false),
+        TxKind::Create => (Address::ZERO, true),
+    };
+
+    let mut opaque_data = Vec::with_capacity(OPAQUE_DATA_V0_FIXED_LEN + tx.input.len());
+    opaque_data.extend_from_slice(&U256::from(tx.mint).to_be_bytes::<32>());
+    opaque_data.extend_from_slice(&tx.value.to_be_bytes::<32>());
+    opaque_data.extend_from_slice(&tx.gas_limit.to_be_bytes());
+    opaque_data.push(is_creation as u8);
+    opaque_data.extend_from_slice(&tx.input);
+
+    // `abi.encode(bytes)`: offset, length and the content right-padded to 32 bytes.
+    let padded_len = opaque_data.len().div_ceil(32) * 32;
+    let mut data = Vec::with_capacity(64 + padded_len);
+    data.extend_from_slice(&U256::from(32).to_be_bytes::<32>());
+    data.extend_from_slice(&U256::from(opaque_data.len()).to_be_bytes::<32>());
+    data.extend_from_slice(&opaque_data);
+    data.resize(64 + padded_len, 0);
+
+    Log {
+        address: portal,
+        data: LogData::new_unchecked(
+            alloc::vec![
+                DEPOSIT_EVENT_ABI_HASH,
+                tx.from.into_word(),
+                to.into_word(),
+                DEPOSIT_EVENT_VERSION_0,
+            ],
+            Bytes::from(data),
+        ),
+    }
+}
+
+/// Reads an address from an indexed event topic, checking that it is left-padded with zeros.
+pub(crate) fn read_address_topic(topic: B256) -> Option<Address> {
+    topic[..12].iter().all(|b| *b == 0).then(|| Address::from_word(topic))
+}
+
+/// Extracts the `bytes opaqueData` argument from the ABI encoded event data.
+fn decode_opaque_data(data: &[u8]) -> Result<&[u8], DepositEventError> {
+    if data.len() < 64 || !data.len().is_multiple_of(32) {
+        return Err(DepositEventError::InvalidDataLength(data.len()));
+    }
+    if U256::from_be_slice(&data[..32]) != U256::from(32) {
+        return Err(DepositEventError::InvalidOpaqueDataOffset);
+    }
+
+    // The content must fill the remaining words, without any extra word.
+    let available = data.len() - 64;
+    let len = U256::from_be_slice(&data[32..64]);
+    let declared = usize::try_from(len)
+        .ok()
+        .filter(|len| *len <= available && available < len + 32)
+        .ok_or(DepositEventError::OpaqueDataLengthMismatch { declared: len, available })?;
+    Ok(&data[64..64 + declared])
+}
+
+/// Decodes version 0 opaque data into a deposit, without `from` and source hash.
+fn decode_opaque_data_v0(data: &[u8], to: Address) -> Result<TxDeposit, DepositEventError> {
+    if data.len() < OPAQUE_DATA_V0_FIXED_LEN {
+        return Err(DepositEventError::OpaqueDataTooShort(data.len()));
+    }
+
+    let mint = u128::try_from(U256::from_be_slice(&data[..32]))
+        .map_err(|_| DepositEventError::MintOverflow)?;
+    Ok(TxDeposit {
+        mint,
+        value: U256::from_be_slice(&data[32..64]),
+        gas_limit: u64::from_be_bytes(data[64..72].try_into().unwrap()),
+        to: if data[72] == 1 { TxKind::Create } else { TxKind::Call(to) },
+        input: Bytes::copy_from_slice(&data[OPAQUE_DATA_V0_FIXED_LEN..]),
+        ..Default::default()
+    })
+}
+
+/// Errors that can occur when decoding a `TransactionDeposited` log.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum DepositEventError {
+    /// The log does not have exactly four topics.
+    #[error("Unexpected number of deposit event topics: {0}")]
+    UnexpectedTopicsLen(usize),
+    /// The first topic is not the deposit event selector.
+    #[error("Invalid deposit event selector: {0}")]
+    InvalidSelector(B256),
+    /// The `from` topic is not a padded address.
+    #[error("Invalid deposit `from` topic: {0}")]
+    InvalidFrom(B256),
+    /// The `to` topic is not a padded address.
+    #[error("Invalid deposit `to` topic: {0}")]
+    InvalidTo(B256),
+    /// The deposit event version is not supported.
+    #[error("Unsupported deposit event version: {0}")]
+    UnsupportedVersion(B256),
+    /// The event data is not a sequence of 32 byte words holding at least an offset and a length.
+    #[error("Invalid deposit event data length: {0}")]
+    InvalidDataLength(usize),
+    /// The offset of the opaque data is not 32.
+    #[error("Invalid deposit opaque data offset")]
+    InvalidOpaqueDataOffset,
+    /// The declared length of the opaque data does not match the event data.
+    #[error("Invalid deposit opaque data length {declared}, {available} bytes available")]
+    OpaqueDataLengthMismatch {
+        /// The length declared in the event data.
+        declared: U256,
+        /// The number of bytes following the length.
+        available: usize,
+    },
+    /// The opaque data is shorter than its fixed fields.
+    #[error("Deposit opaque data too short: {0} bytes")]
+    OpaqueDataTooShort(usize),
+    /// The mint value does not fit in 128 bits.
+    #[error("Deposit mint value overflows u128")]
+    MintOverflow,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::{hex, keccak256};
+
+    const PORTAL: Address = address!("0xbEb5Fc579115071764c7423A4f12eDde41f106Ed");
+
+    fn deposit() -> TxDeposit {
+        TxDeposit {
+            source_hash: UserDepositSource::new(B256::repeat_byte(1), 7).source_hash(),
+            from: address!("0x1111111111111111111111111111111111111111"),
+            to: TxKind::Call(address!("0x2222222222222222222222222222222222222222")),
+            mint: 10,
+            value: U256::from(100),
+            gas_limit: 1000,
+            is_system_transaction: false,
+            input: Bytes::from_static(&hex!("deadbeef")),
+        }
+    }
+
+    #[test]
+    fn test_deposit_event_abi_hash() {
+        assert_eq!(keccak256(DEPOSIT_EVENT_ABI), DEPOSIT_EVENT_ABI_HASH);
+    }
+
+    #[test]
+    fn test_deposit_log_roundtrip() {
+        let tx = deposit();
+        let log = encode_deposit_log(&tx, PORTAL);
+        assert_eq!(log.address, PORTAL);
+        // 73 bytes of fixed fields and 4 bytes of input, padded to 96 bytes.
+        assert_eq!(log.data.data.len(), 64 + 96);
+        assert_eq!(decode_deposit_log(B256::repeat_byte(1), 7, &log).unwrap(), tx);
+
+        let tx = TxDeposit { to: TxKind::Create, input: Bytes::new(), ..deposit() };
+        let log = encode_deposit_log(&tx, PORTAL);
+        assert_eq!(log.topics()[2], B256::ZERO);
+        assert_eq!(decode_deposit_log(B256::repeat_byte(1), 7, &log).unwrap(), tx);
+    }
+
+    #[test]
+    fn test_decode_deposit_log_creation() {
+        // Contract creations ignore the `to` topic.
+        let mut log = encode_deposit_log(&TxDeposit { to: TxKind::Create, ..deposit() }, PORTAL);
+        let mut topics = log.topics().to_vec();
+        topics[2] = address!("0x5555555555555555555555555555555555555555").into_word();
+        log.data = LogData::new_unchecked(topics, log.data.data.clone());
+
+        let tx = decode_deposit_log(B256::ZERO, 0, &log).unwrap();
+        assert_eq!(tx.to, TxKind::Create);
+        assert_eq!(
+            tx.encoded_2718(),
+            hex!(
+                "7ef842a0ed428e1c45e1d9561b62834e1a2d3015a0caae3bfdc16b4da059ac885b01a145941111111111111111111111111111111111111111800a648203e88084deadbeef"
+            )
+        );
+    }
+
+    #[test]
+    fn test_decode_deposit_log_creation_flag() {
+        // Only a flag of 1 marks a contract creation, like op-node.
+        let log = encode_deposit_log(&deposit(), PORTAL);
+        let mut data = log.data.data.to_vec();
+        data[64 + 72] = 2;
+        let log = Log {
+            address: PORTAL,
+            data: LogData::new_unchecked(log.topics().to_vec(), data.into()),
+        };
+        assert_eq!(decode_deposit_log(B256::repeat_byte(1), 7, &log).unwrap(), deposit());
+    }
+
+    #[test]
+    fn test_decode_deposit_log_invalid_topics() {
+        let log = encode_deposit_log(&deposit(), PORTAL);
+        let with_topics = |topics: Vec<B256>| Log {
+            address: PORTAL,
+            data: LogData::new_unchecked(topics, log.data.data.clone()),
+        };
+
+        let err = decode_deposit_log(B256::ZERO, 0, &with_topics(log.topics()[..3].to_vec()));
+        assert_eq!(err, Err(DepositEventError::UnexpectedTopicsLen(3)));
+
+        let mut topics = log.topics().to_vec();
+        topics[0] = B256::ZERO;
+        let err = decode_deposit_log(B256::ZERO, 0, &with_topics(topics));
+        assert_eq!(err, Err(DepositEventError::InvalidSelector(B256::ZERO)));
+
+        let mut topics = log.topics().to_vec();
+        topics[1] = B256::repeat_byte(0xff);
+        let err = decode_deposit_log(B256::ZERO, 0, &with_topics(topics));
+        assert_eq!(err, Err(DepositEventError::InvalidFrom(B256::repeat_byte(0xff))));
+
+        let mut topics = log.topics().to_vec();
+        topics[3] = B256::with_last_byte(1);
+        let err = decode_deposit_log(B256::ZERO, 0, &with_topics(topics));
+        assert_eq!(err, Err(DepositEventError::UnsupportedVersion(B256::with_last_byte(1))));
+    }
+
+    #[test]
+    fn test_decode_deposit_log_invalid_data() {
+        let log = encode_deposit_log(&deposit(), PORTAL);
+        let with_data = |data: Vec<u8>| Log {
+            address: PORTAL,
+            data: LogData::new_unchecked(log.topics().to_vec(), data.into()),
+        };
+        let data = log.data.data.to_vec();
+
+        let err = decode_deposit_log(B256::ZERO, 0, &with_data(data[..63].to_vec()));
+        assert_eq!(err, Err(DepositEventError::InvalidDataLength(63)));
+
+        let mut bad_offset = data.clone();
+        bad_offset[31] = 64;
+        let err = decode_deposit_log(B256::ZERO, 0, &with_data(bad_offset));
+        assert_eq!(err, Err(DepositEventError::InvalidOpaqueDataOffset));
+
+        // An extra trailing word is rejected.
+        let mut extra_word = data.clone();
+        extra_word.extend_from_slice(&[0; 32]);
+        let err = decode_deposit_log(B256::ZERO, 0, &with_data(extra_word));
+        assert_eq!(
+            err,
+            Err(DepositEventError::OpaqueDataLengthMismatch {
+                declared: U256::from(77),
+                available: 128
+            })
+        );
+
+        // Opaque data shorter than the fixed fields.
+        let mut short = data[..64 + 32].to_vec();
+        short[63] = 32;
+        let err = decode_deposit_log(B256::ZERO, 0, &with_data(short));
+        assert_eq!(err, Err(DepositEventError::OpaqueDataTooShort(32)));
+
+        let mut mint_overflow = data;
+        mint_overflow[64] = 1;
+        let err = decode_deposit_log(B256::ZERO, 0, &with_data(mint_overflow));
+        assert_eq!(err, Err(DepositEventError::MintOverflow));
+    }
+
+    #[test]
+    fn test_l1_to_l2_alias() {
+        let l1 = address!("0x1111111111111111111111111111111111111111");
+        let l2 = address!("0x2222111111111111111111111111111111112222");
+        assert_eq!(apply_l1_to_l2_alias(l1), l2);
+        assert_eq!(undo_l1_to_l2_alias(l2), l1);
+
+        // The alias wraps around the address space.
+        let high = address!("0xffffffffffffffffffffffffffffffffffffffff");
+        assert_eq!(
+            apply_l1_to_l2_alias(high),
+            address!("0x1111000000000000000000000000000000001110")
+        );
+        assert_eq!(undo_l1_to_l2_alias(apply_l1_to_l2_alias(high)), high);
+    }
+}
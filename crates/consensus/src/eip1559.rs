@@ -1,5 +1,7 @@
-//! Support for EIP-1559 parameters after holocene.
+//! Support for EIP-1559 parameters after holocene, and the OP Stack base fee calculation.
 
+use crate::{OpHardfork, OpHardforks};
+use alloy_consensus::BlockHeader;
 use alloy_eips::eip1559::BaseFeeParams;
 use alloy_primitives::{B64, Bytes};
 
@@ -114,6 +116,96 @@ pub fn encode_jovian_extra_data(
     Ok(Bytes::copy_from_slice(&extra_data))
 }
 
+/// The chain configured EIP-1559 parameters, used until Holocene moves them into the block header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct OpBaseFeeParams {
+    /// The parameters used before Canyon.
+    pub bedrock: BaseFeeParams,
+    /// The parameters used from Canyon onwards.
+    pub canyon: BaseFeeParams,
+}
+
+impl OpBaseFeeParams {
+    /// The parameters of OP Mainnet.
+    pub const fn optimism() -> Self {
+        Self { bedrock: BaseFeeParams::optimism(), canyon: BaseFeeParams::optimism_canyon() }
+    }
+
+    /// The parameters of OP Sepolia.
+    pub const fn optimism_sepolia() -> Self {
+        Self {
+            bedrock: BaseFeeParams::optimism_sepolia(),
+            canyon: BaseFeeParams::optimism_sepolia_canyon(),
+        }
+    }
+
+    /// Returns the parameters that apply at `timestamp`.
+    pub fn at_timestamp(&self, hardforks: &OpHardforks, timestamp: u64) -> BaseFeeParams {
+        if hardforks.is_active_at(OpHardfork::Canyon, timestamp) {
+            self.canyon
+        } else {
+            self.bedrock
+        }
+    }
+}
+
+impl Default for OpBaseFeeParams {
+    fn default() -> Self {
+        Self::optimism()
+    }
+}
+
+/// Computes the base fee of the block following `parent`, which has the given `timestamp`.
+///
+/// - Before Holocene, the chain configured [`OpBaseFeeParams`] are used, switching to the Canyon
+///   parameters once Canyon is active at `timestamp`.
+/// - From Holocene onwards, the parameters are read from the `extra_data` of the parent. If both
+///   are zero, the chain configured parameters are used instead.
+/// - From Jovian onwards, the gas used of the parent is the maximum of its `gas_used` and its DA
+///   footprint (`blob_gas_used`), and the result is floored at the minimum base fee from the parent
+///   `extra_data`.
+///
+/// Holocene and Jovian rules apply based on the timestamp of the parent, as the parameters are
+/// read from its header.
+pub fn next_block_base_fee<H: BlockHeader>(
+    parent: &H,
+    timestamp: u64,
+    hardforks: &OpHardforks,
+    params: &OpBaseFeeParams,
+) -> Result<u64, EIP1559ParamError> {
+    let base_fee = parent.base_fee_per_gas().ok_or(EIP1559ParamError::MissingBaseFee)?;
+    let parent_time = parent.timestamp();
+
+    let (elasticity, denominator, min_base_fee) =
+        if hardforks.is_active_at(OpHardfork::Jovian, parent_time) {
+            decode_jovian_extra_data(parent.extra_data())?
+        } else if hardforks.is_active_at(OpHardfork::Holocene, parent_time) {
+            let (elasticity, denominator) = decode_holocene_extra_data(parent.extra_data())?;
+            (elasticity, denominator, 0)
+        } else {
+            (0, 0, 0)
+        };
+
+    let base_fee_params = if elasticity == 0 && denominator == 0 {
+        params.at_timestamp(hardforks, timestamp)
+    } else {
+        BaseFeeParams::new(denominator as u128, elasticity as u128)
+    };
+    if base_fee_params.elasticity_multiplier == 0 || base_fee_params.max_change_denominator == 0 {
+        return Err(EIP1559ParamError::InvalidBaseFeeParams);
+    }
+
+    let mut gas_used = parent.gas_used();
+    if hardforks.is_active_at(OpHardfork::Jovian, parent_time) {
+        gas_used = gas_used.max(parent.blob_gas_used().unwrap_or_default());
+    }
+
+    let next_base_fee = base_fee_params.next_block_base_fee(gas_used, parent.gas_limit(), base_fee);
+    Ok(next_base_fee.max(min_base_fee))
+}
+
 /// Error type for EIP-1559 parameters
 #[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
 pub enum EIP1559ParamError {
@@ -138,11 +230,18 @@ pub enum EIP1559ParamError {
     /// Minimum base fee cannot be None after Jovian.
     #[error("Minimum base fee cannot be None after Jovian")]
     MinBaseFeeNotSet,
+    /// The parent header has no base fee.
+    #[error("Parent header has no base fee")]
+    MissingBaseFee,
+    /// The elasticity or denominator is zero.
+    #[error("EIP1559 elasticity and denominator must be non-zero")]
+    InvalidBaseFeeParams,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy_consensus::Header;
     use core::str::FromStr;
 
     #[test]
@@ -215,4 +314,83 @@ mod tests {
         let res = decode_jovian_extra_data(&extra_data);
         assert_eq!(res.unwrap_err(), EIP1559ParamError::InvalidExtraDataLength);
     }
+
+    fn parent(extra_data: Bytes, gas_used: u64, blob_gas_used: Option<u64>) -> Header {
+        Header {
+            timestamp: 100,
+            gas_limit: 30_000_000,
+            gas_used,
+            blob_gas_used,
+            base_fee_per_gas: Some(1_000_000_000),
+            extra_data,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_next_block_base_fee_pre_holocene() {
+        let params = OpBaseFeeParams::optimism();
+        let hardforks = OpHardforks::default().with_activation(OpHardfork::Canyon, 200);
+        let parent = parent(Bytes::new(), 0, None);
+
+        // 1e9 - 1e9 / 50
+        assert_eq!(next_block_base_fee(&parent, 102, &hardforks, &params), Ok(980_000_000));
+        // 1e9 - 1e9 / 250, Canyon is active at the child timestamp.
+        assert_eq!(next_block_base_fee(&parent, 200, &hardforks, &params), Ok(996_000_000));
+
+        // At the gas target the base fee does not change.
+        let parent = Header { gas_used: 5_000_000, ..parent };
+        assert_eq!(next_block_base_fee(&parent, 102, &hardforks, &params), Ok(1_000_000_000));
+    }
+
+    #[test]
+    fn test_next_block_base_fee_holocene() {
+        let params = OpBaseFeeParams::optimism();
+        let hardforks = OpHardforks::default().with_activation(OpHardfork::Holocene, 0);
+
+        // denominator 8, elasticity 2: 1e9 - 1e9 / 8
+        let extra_data = encode_holocene_extra_data(
+            B64::from_str("0x0000000800000002").unwrap(),
+            BaseFeeParams::optimism(),
+        )
+        .unwrap();
+        let header = parent(extra_data, 0, None);
+        assert_eq!(next_block_base_fee(&header, 102, &hardforks, &params), Ok(875_000_000));
+
+        // Zero parameters fall back to the chain configuration.
+        let header = parent(Bytes::from_static(&[0; 9]), 0, None);
+        assert_eq!(next_block_base_fee(&header, 102, &hardforks, &params), Ok(996_000_000));
+
+        let header = parent(Bytes::new(), 0, None);
+        assert_eq!(
+            next_block_base_fee(&header, 102, &hardforks, &params),
+            Err(EIP1559ParamError::InvalidExtraDataLength)
+        );
+
+        let header = Header { base_fee_per_gas: None, ..header };
+        assert_eq!(
+            next_block_base_fee(&header, 102, &hardforks, &params),
+            Err(EIP1559ParamError::MissingBaseFee)
+        );
+    }
+
+    #[test]
+    fn test_next_block_base_fee_jovian() {
+        let params = OpBaseFeeParams::optimism();
+        let hardforks = OpHardforks::default().with_activation(OpHardfork::Jovian, 0);
+        let eip_1559_params = B64::from_str("0x0000000800000002").unwrap();
+
+        // The min base fee floors the decrease: max(1e9 - 1e9 / 8, 950_000_000)
+        let extra_data =
+            encode_jovian_extra_data(eip_1559_params, BaseFeeParams::optimism(), 950_000_000)
+                .unwrap();
+        let header = parent(extra_data, 0, Some(0));
+        assert_eq!(next_block_base_fee(&header, 102, &hardforks, &params), Ok(950_000_000));
+
+        // The DA footprint counts as gas used: 1e9 + 1e9 * (30M - 15M) / 15M / 8
+        let extra_data =
+            encode_jovian_extra_data(eip_1559_params, BaseFeeParams::optimism(), 0).unwrap();
+        let header = parent(extra_data, 0, Some(30_000_000));
+        assert_eq!(next_block_base_fee(&header, 102, &hardforks, &params), Ok(1_125_000_000));
+    }
 }
@@ -0,0 +1,182 @@
+//! Jovian DA footprint accounting.
+
+use crate::{L1BlockInfoError, L1BlockInfoTx, OpBlock, OpTxEnvelope};
+
+/// Returns the DA footprint of a transaction, given the DA footprint gas scalar of its block.
+///
+/// The DA footprint is `estimatedSize * daFootprintGasScalar`, where `estimatedSize` is the Fjord
+/// estimated compressed size of the transaction, see [`OpTxEnvelope::fjord_estimated_size`].
+/// Deposit transactions do not have a DA footprint.
+///
+/// See also: <https://specs.optimism.io/protocol/jovian/exec-engine.html#da-footprint-block-limit>
+pub fn tx_da_footprint(tx: &OpTxEnvelope, da_footprint_gas_scalar: u16) -> u64 {
+    if tx.is_deposit() {
+        return 0;
+    }
+    tx.fjord_estimated_size().saturating_mul(da_footprint_gas_scalar as u64)
+}
+
+/// Returns the total DA footprint of the transactions of a block, given its DA footprint gas
+/// scalar.
+///
+/// Since Jovian, this is the value of the `blob_gas_used` header field.
+pub fn block_da_footprint(block: &OpBlock, da_footprint_gas_scalar: u16) -> u64 {
+    block
+        .body
+        .transactions
+        .iter()
+        .map(|tx| tx_da_footprint(tx, da_footprint_gas_scalar))
+        .fold(0, u64::saturating_add)
+}
+
+/// Checks the DA footprint of a Jovian block against its header.
+///
+/// The DA footprint gas scalar is read from the L1 info deposit, which must be the first
+/// transaction of the block. The total DA footprint must equal the `blob_gas_used` header field
+/// and must not exceed the block gas limit.
+///
+/// Returns the total DA footprint of the block.
+pub fn validate_block_da_footprint(block: &OpBlock) -> Result<u64, DaFootprintError> {
+    let Some(OpTxEnvelope::Deposit(l1_info)) = block.body.transactions.first() else {
+        return Err(DaFootprintError::MissingL1InfoTx);
+    };
+    let scalar = L1BlockInfoTx::try_from(l1_info.inner())?
+        .da_footprint_gas_scalar()
+        .ok_or(DaFootprintError::MissingScalar)?;
+
+    let da_footprint = block_da_footprint(block, scalar);
+    let blob_gas_used = block.header.blob_gas_used.ok_or(DaFootprintError::MissingBlobGasUsed)?;
+    if da_footprint != blob_gas_used {
+        return Err(DaFootprintError::BlobGasUsedMismatch {
+            expected: da_footprint,
+            got: blob_gas_used,
+        });
+    }
+    if da_footprint > block.header.gas_limit {
+        return Err(DaFootprintError::ExceedsGasLimit {
+            da_footprint,
+            gas_limit: block.header.gas_limit,
+        });
+    }
+    Ok(da_footprint)
+}
+
+/// Errors that can occur when validating the DA footprint of a block.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum DaFootprintError {
+    /// The block does not start with an L1 info deposit.
+    #[error("Block does not start with an L1 info deposit")]
+    MissingL1InfoTx,
+    /// The L1 info deposit could not be decoded.
+    #[error(transparent)]
+    L1BlockInfo(#[from] L1BlockInfoError),
+    /// The L1 info deposit predates Jovian and has no DA footprint gas scalar.
+    #[error("L1 info deposit has no DA footprint gas scalar")]
+    MissingScalar,
+    /// The header has no `blob_gas_used` field.
+    #[error("Missing blob gas used in header")]
+    MissingBlobGasUsed,
+    /// The `blob_gas_used` header field does not match the DA footprint of the transactions.
+    #[error("Invalid blob gas used: expected DA footprint {expected}, got {got}")]
+    BlobGasUsedMismatch {
+        /// The DA footprint of the transactions.
+        expected: u64,
+        /// The `blob_gas_used` header field.
+        got: u64,
+    },
+    /// The DA footprint exceeds the block gas limit.
+    #[error("DA footprint {da_footprint} exceeds block gas limit {gas_limit}")]
+    ExceedsGasLimit {
+        /// The DA footprint of the transactions.
+        da_footprint: u64,
+        /// The block gas limit.
+        gas_limit: u64,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{L1BlockInfoJovian, TxDeposit};
+    use alloc::vec;
+    use alloy_consensus::{BlockBody, Header, Sealable, Signed, TxLegacy};
+    use alloy_primitives::{Bytes, Signature};
+
+    fn user_tx(input: Bytes) -> OpTxEnvelope {
+        OpTxEnvelope::Legacy(Signed::new_unhashed(
+            TxLegacy { input, ..Default::default() },
+            Signature::test_signature(),
+        ))
+    }
+
+    fn l1_info_tx(da_footprint_gas_scalar: u16) -> OpTxEnvelope {
+        let info = L1BlockInfoTx::Jovian(L1BlockInfoJovian {
+            da_footprint_gas_scalar,
+            ..Default::default()
+        });
+        OpTxEnvelope::Deposit(info.to_deposit_tx(true).seal_slow())
+    }
+
+    fn block(transactions: vec::Vec<OpTxEnvelope>, blob_gas_used: Option<u64>) -> OpBlock {
+        OpBlock {
+            header: Header { gas_limit: 30_000_000, blob_gas_used, ..Default::default() },
+            body: BlockBody { transactions, ommers: vec![], withdrawals: None },
+        }
+    }
+
+    #[test]
+    fn test_tx_da_footprint() {
+        // Small transactions are bounded by the minimum estimated size of 100 bytes.
+        assert_eq!(tx_da_footprint(&user_tx(Bytes::new()), 400), 40_000);
+        assert_eq!(tx_da_footprint(&user_tx(Bytes::new()), 0), 0);
+
+        let deposit = OpTxEnvelope::Deposit(TxDeposit::default().seal_slow());
+        assert_eq!(tx_da_footprint(&deposit, 400), 0);
+    }
+
+    #[test]
+    fn test_validate_block_da_footprint() {
+        let txs = vec![l1_info_tx(400), user_tx(Bytes::new()), user_tx(Bytes::new())];
+        let da_footprint = block_da_footprint(&block(txs.clone(), None), 400);
+        assert_eq!(da_footprint, 80_000);
+
+        assert_eq!(validate_block_da_footprint(&block(txs.clone(), Some(80_000))), Ok(80_000));
+        assert_eq!(
+            validate_block_da_footprint(&block(txs.clone(), Some(0))),
+            Err(DaFootprintError::BlobGasUsedMismatch { expected: 80_000, got: 0 })
+        );
+        assert_eq!(
+            validate_block_da_footprint(&block(txs, None)),
+            Err(DaFootprintError::MissingBlobGasUsed)
+        );
+    }
+
+    #[test]
+    fn test_validate_block_da_footprint_gas_limit() {
+        let txs = vec![l1_info_tx(u16::MAX), user_tx(Bytes::new())];
+        let mut block = block(txs, Some(100 * u16::MAX as u64));
+        block.header.gas_limit = 1_000_000;
+        assert_eq!(
+            validate_block_da_footprint(&block),
+            Err(DaFootprintError::ExceedsGasLimit {
+                da_footprint: 100 * u16::MAX as u64,
+                gas_limit: 1_000_000
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_block_da_footprint_pre_jovian() {
+        assert_eq!(
+            validate_block_da_footprint(&block(vec![user_tx(Bytes::new())], Some(0))),
+            Err(DaFootprintError::MissingL1InfoTx)
+        );
+
+        let info = L1BlockInfoTx::Isthmus(Default::default());
+        let txs = vec![OpTxEnvelope::Deposit(info.to_deposit_tx(true).seal_slow())];
+        assert_eq!(
+            validate_block_da_footprint(&block(txs, Some(0))),
+            Err(DaFootprintError::MissingScalar)
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! FastLZ compressed length estimation.
+//!
+//! Since Fjord, the L1 data fee is derived from the FastLZ compressed size of a transaction
+//! rather than from its zero and non-zero byte counts. Only the length of the compressed output
+//! is needed, so the functions here never allocate the compressed data.
+
+/// The number of entries in the FastLZ hash table.
+const HASH_TABLE_SIZE: usize = 8192;
+
+/// Returns the length of `input` after FastLZ (level 1) compression, without producing the
+/// compressed output.
+///
+/// This is a port of `FlzCompressLen` from op-geth, which in turn follows the solady
+/// implementation:
+/// <https://github.com/ethereum-optimism/op-geth/blob/647c346e2bef36219cc7b47d76b1cb87e7ca29e4/core/types/rollup_cost.go#L411>
+///
+/// The arithmetic is done on `u32`s to match the reference implementation exactly, including its
+/// quirks.
+pub fn flz_compress_len(input: &[u8]) -> u32 {
+    let len = input.len() as u32;
+    let ip_limit = len.saturating_sub(13);
+
+    let mut htab = [0u32; HASH_TABLE_SIZE];
+    let mut ip: u32 = 2;
+    let mut anchor: u32 = 0;
+    let mut size: u32 = 0;
+
+    while ip < ip_limit {
+        let mut reference: u32;
+        loop {
+            let seq = u24(input, ip);
+            let hash = hash(seq);
+            reference = htab[hash];
+            htab[hash] = ip;
+            let distance = ip - reference;
+            if ip >= ip_limit {
+                break;
+            }
+            ip += 1;
+            if distance < 8192 && seq == u24(input, reference) {
+                break;
+            }
+        }
+        if ip >= ip_limit {
+            break;
+        }
+
+        ip -= 1;
+        if ip > anchor {
+            size = literals(ip - anchor, size);
+        }
+
+        let match_len = cmp(input, reference + 3, ip + 3, ip_limit + 9);
+        size = flz_match(match_len, size);
+
+        ip = set_next_hash(&mut htab, input, ip + match_len);
+        ip = set_next_hash(&mut htab, input, ip);
+        anchor = ip;
+    }
+
+    literals(len - anchor, size)
+}
+
+/// Returns an upper bound of the FastLZ compressed length of an input of `len` bytes.
+///
+/// This matches the bound used by `GasPriceOracle.getL1FeeUpperBound`, which cannot compress
+/// the transaction on chain: `len + len / 255 + 16`, saturating at `u32::MAX`.
+pub const fn flz_compress_len_upper_bound(len: u32) -> u32 {
+    len.saturating_add(len / 255).saturating_add(16)
+}
+
+/// Adds the encoded size of a run of `r` literals to `size`.
+const fn literals(r: u32, size: u32) -> u32 {
+    let size = size + 0x21 * (r / 0x20);
+    let r = r % 0x20;
+    if r != 0 { size + r + 1 } else { size }
+}
+
+/// Adds the encoded size of a match of length `l` to `size`.
+const fn flz_match(l: u32, size: u32) -> u32 {
+    let l = l - 1;
+    let size = size + 3 * (l / 262);
+    if l % 262 >= 6 { size + 3 } else { size + 2 }
+}
+
+/// Returns the length of the common run starting at `p` and `q`, bounded by `r`.
+///
+/// Like the reference implementation, the byte that ends the run is counted as well.
+fn cmp(input: &[u8], p: u32, q: u32, r: u32) -> u32 {
+    let mut l = 0;
+    let mut r = r - q;
+    while l < r {
+        if input[(p + l) as usize] != input[(q + l) as usize] {
+            r = 0;
+        }
+        l += 1;
+    }
+    l
+}
+
+fn set_next_hash(htab: &mut [u32; HASH_TABLE_SIZE], input: &[u8], ip: u32) -> u32 {
+    htab[hash(u24(input, ip))] = ip;
+    ip + 1
+}
+
+const fn hash(v: u32) -> usize {
+    ((v.wrapping_mul(2654435769) >> 19) & 0x1fff) as usize
+}
+
+fn u24(input: &[u8], i: u32) -> u32 {
+    let i = i as usize;
+    u32::from(input[i]) | (u32::from(input[i + 1]) << 8) | (u32::from(input[i + 2]) << 16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::hex;
+
+    #[test]
+    fn test_flz_compress_len() {
+        assert_eq!(flz_compress_len(&[]), 0);
+        assert_eq!(flz_compress_len(&[0; 1000]), 21);
+        assert_eq!(flz_compress_len(&[42; 1000]), 21);
+        assert_eq!(flz_compress_len(&hex!("FACADE")), 4);
+    }
+
+    #[test]
+    fn test_flz_compress_len_contract_call() {
+        let input = hex!(
+            "02f901550a758302df1483be21b88304743f94f80e51afb613d764fa61751affd3313c190a86bb870151bd62fd12adb8e41ef24f3f000000000000000000000000000000000000000000000000000000000000006e000000000000000000000000af88d065e77c8cc2239327c5edb3a432268e5831000000000000000000000000000000000000000000000000000000000003c1e5000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000a000000000000000000000000000000000000000000000000000000000000000148c89ed219d02f1a5be012c689b4f5b731827bebe000000000000000000000000c001a033fd89cb37c31b2cba46b6466e040c61fc9b2a3675a7f5f493ebd5ad77c497f8a07cdf65680e238392693019b4092f610222e71b7cec06449cb922b93b6a12744e"
+        );
+        assert_eq!(flz_compress_len(&input), 202);
+    }
+
+    #[test]
+    fn test_flz_compress_len_upper_bound() {
+        assert_eq!(flz_compress_len_upper_bound(0), 16);
+        assert_eq!(flz_compress_len_upper_bound(1000), 1019);
+        assert_eq!(flz_compress_len_upper_bound(u32::MAX), u32::MAX);
+        for input in [&[0u8; 1000][..], &[42; 1000], &hex!("FACADE")] {
+            assert!(flz_compress_len(input) <= flz_compress_len_upper_bound(input.len() as u32));
+        }
+    }
+
+    #[test]
+    fn test_flz_compress_len_no_repeats() {
+        let mut input = alloc::vec::Vec::new();
+        let mut len = 0;
+        for i in 0..=255u8 {
+            input.push(i);
+            let prev = len;
+            len = flz_compress_len(&input);
+            assert!(len > prev);
+        }
+    }
+}
@@ -0,0 +1,384 @@
+//! L1 data fee calculation.
+
+use super::fastlz::{flz_compress_len, flz_compress_len_upper_bound};
+use crate::{DEPOSIT_TX_TYPE_ID, L1BlockInfoTx, OpHardfork, OpTxEnvelope};
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::U256;
+
+/// The calldata gas cost of a zero byte.
+const ZERO_BYTE_COST: u64 = 4;
+
+/// The calldata gas cost of a non-zero byte.
+const NON_ZERO_BYTE_COST: u64 = 16;
+
+/// The size of a transaction signature, which is added to the size of unsigned transaction data
+/// when estimating its L1 cost.
+const SIGNATURE_SIZE: u64 = 68;
+
+/// The minimum estimated transaction size since Fjord, scaled by 1e6.
+pub const FJORD_MIN_TX_SIZE_SCALED: u64 = 100_000_000;
+
+/// The intercept of the Fjord linear regression, scaled by 1e6.
+pub const FJORD_L1_COST_INTERCEPT: i64 = -42_585_600;
+
+/// The FastLZ coefficient of the Fjord linear regression, scaled by 1e6.
+pub const FJORD_L1_COST_FASTLZ_COEF: u64 = 836_500;
+
+/// The L1 fee parameters that are set by the L1 info deposit of a block.
+///
+/// Not every parameter is used by every hardfork: the overhead and fee scalar are only read by
+/// the Bedrock formula, while the base fee and blob base fee scalars are only read from Ecotone
+/// onwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct L1FeeParams {
+    /// The L1 origin block base fee.
+    pub l1_base_fee: U256,
+    /// The Bedrock L1 fee overhead.
+    pub l1_fee_overhead: U256,
+    /// The Bedrock L1 fee scalar, scaled by 1e6.
+    pub l1_fee_scalar: U256,
+    /// The L1 origin block blob base fee.
+    pub l1_blob_base_fee: U256,
+    /// The Ecotone scalar applied to the L1 base fee.
+    pub l1_base_fee_scalar: U256,
+    /// The Ecotone scalar applied to the L1 blob base fee.
+    pub l1_blob_base_fee_scalar: U256,
+}
+
+impl L1FeeParams {
+    /// Returns `true` if both Ecotone scalars are zero.
+    ///
+    /// This is the case in the first Ecotone block, whose L1 info deposit still uses the Bedrock
+    /// format, so the Bedrock formula is used instead.
+    pub fn is_empty_ecotone_scalars(&self) -> bool {
+        self.l1_base_fee_scalar.is_zero() && self.l1_blob_base_fee_scalar.is_zero()
+    }
+
+    /// Returns `l1BaseFee * 16 * l1BaseFeeScalar + l1BlobBaseFee * l1BlobBaseFeeScalar`.
+    fn l1_fee_scaled(&self) -> U256 {
+        let calldata_cost_per_byte = self
+            .l1_base_fee
+            .saturating_mul(U256::from(NON_ZERO_BYTE_COST))
+            .saturating_mul(self.l1_base_fee_scalar);
+        let blob_cost_per_byte = self.l1_blob_base_fee.saturating_mul(self.l1_blob_base_fee_scalar);
+        calldata_cost_per_byte.saturating_add(blob_cost_per_byte)
+    }
+}
+
+impl From<&L1BlockInfoTx> for L1FeeParams {
+    fn from(info: &L1BlockInfoTx) -> Self {
+        match info {
+            L1BlockInfoTx::Bedrock(info) => Self {
+                l1_base_fee: info.base_fee,
+                l1_fee_overhead: info.l1_fee_overhead,
+                l1_fee_scalar: info.l1_fee_scalar,
+                ..Default::default()
+            },
+            L1BlockInfoTx::Ecotone(info) => Self {
+                l1_base_fee: info.base_fee,
+                l1_blob_base_fee: info.blob_base_fee,
+                l1_base_fee_scalar: U256::from(info.base_fee_scalar),
+                l1_blob_base_fee_scalar: U256::from(info.blob_base_fee_scalar),
+                ..Default::default()
+            },
+            L1BlockInfoTx::Isthmus(info) => Self {
+                l1_base_fee: info.base_fee,
+                l1_blob_base_fee: info.blob_base_fee,
+                l1_base_fee_scalar: U256::from(info.base_fee_scalar),
+                l1_blob_base_fee_scalar: U256::from(info.blob_base_fee_scalar),
+                ..Default::default()
+            },
+            L1BlockInfoTx::Jovian(info) => Self {
+                l1_base_fee: info.base_fee,
+                l1_blob_base_fee: info.blob_base_fee,
+                l1_base_fee_scalar: U256::from(info.base_fee_scalar),
+                l1_blob_base_fee_scalar: U256::from(info.blob_base_fee_scalar),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl From<L1BlockInfoTx> for L1FeeParams {
+    fn from(info: L1BlockInfoTx) -> Self {
+        Self::from(&info)
+    }
+}
+
+/// The L1 data fee of a transaction, together with the intermediate values of the calculation.
+///
+/// The fields line up with the `l1Fee`, `l1GasUsed` receipt fields reported by op-geth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct L1Fee {
+    /// The L1 data fee, in wei.
+    pub fee: U256,
+    /// The L1 gas used by the transaction data.
+    ///
+    /// Before Ecotone this includes the fee overhead. From Fjord onwards this is derived from the
+    /// estimated compressed size.
+    pub l1_gas_used: U256,
+    /// The estimated compressed size of the transaction in bytes.
+    ///
+    /// Only set from Fjord onwards.
+    pub estimated_size: Option<u64>,
+}
+
+/// Computes the L1 data fee of transactions, following the op-geth cost functions.
+///
+/// The formula depends on the active hardfork:
+/// - Bedrock: `(dataGas + overhead) * l1BaseFee * scalar / 1e6`
+/// - Ecotone: `dataGas * (l1BaseFee * 16 * l1BaseFeeScalar + l1BlobBaseFee * l1BlobBaseFeeScalar) /
+///   16e6`
+/// - Fjord: `estimatedSize * (l1BaseFee * 16 * l1BaseFeeScalar + l1BlobBaseFee *
+///   l1BlobBaseFeeScalar) / 1e12`, where `estimatedSize` is derived from the FastLZ compressed size
+///   of the transaction.
+///
+/// Deposit transactions and empty inputs do not pay an L1 data fee.
+///
+/// See also: <https://specs.optimism.io/protocol/exec-engine.html#l1-cost-fees-l1-fee-vault>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct L1FeeCalculator {
+    hardfork: OpHardfork,
+    params: L1FeeParams,
+}
+
+impl L1FeeCalculator {
+    /// Creates a new calculator for the given active hardfork and fee parameters.
+    pub const fn new(hardfork: OpHardfork, params: L1FeeParams) -> Self {
+        Self { hardfork, params }
+    }
+
+    /// Returns the active hardfork.
+    pub const fn hardfork(&self) -> OpHardfork {
+        self.hardfork
+    }
+
+    /// Returns the fee parameters.
+    pub const fn params(&self) -> &L1FeeParams {
+        &self.params
+    }
+
+    /// Computes the L1 data fee of a transaction.
+    pub fn calculate(&self, tx: &OpTxEnvelope) -> L1Fee {
+        if tx.is_deposit() {
+            return L1Fee::default();
+        }
+        self.calculate_raw(&tx.encoded_2718())
+    }
+
+    /// Computes the L1 data fee of an EIP-2718 encoded transaction.
+    pub fn calculate_raw(&self, encoded: &[u8]) -> L1Fee {
+        if encoded.is_empty() || encoded[0] == DEPOSIT_TX_TYPE_ID {
+            return L1Fee::default();
+        }
+
+        if self.hardfork.is_enabled_in(OpHardfork::Fjord) {
+            self.calculate_fjord(encoded)
+        } else if self.hardfork.is_enabled_in(OpHardfork::Ecotone)
+            && !self.params.is_empty_ecotone_scalars()
+        {
+            self.calculate_ecotone(encoded)
+        } else {
+            self.calculate_bedrock(encoded)
+        }
+    }
+
+    /// Returns the rollup data gas of the transaction, before Fjord.
+    fn data_gas(&self, encoded: &[u8]) -> U256 {
+        let zeros = encoded.iter().filter(|b| **b == 0).count() as u64;
+        let mut non_zeros = encoded.len() as u64 - zeros;
+        // Before Regolith, the signature was counted even though it is part of the transaction.
+        if !self.hardfork.is_enabled_in(OpHardfork::Regolith) {
+            non_zeros += SIGNATURE_SIZE;
+        }
+        U256::from(zeros * ZERO_BYTE_COST + non_zeros * NON_ZERO_BYTE_COST)
+    }
+
+    fn calculate_bedrock(&self, encoded: &[u8]) -> L1Fee {
+        let l1_gas_used = self.data_gas(encoded).saturating_add(self.params.l1_fee_overhead);
+        let fee = l1_gas_used
+            .saturating_mul(self.params.l1_base_fee)
+            .saturating_mul(self.params.l1_fee_scalar)
+            / U256::from(1_000_000);
+        L1Fee { fee, l1_gas_used, estimated_size: None }
+    }
+
+    fn calculate_ecotone(&self, encoded: &[u8]) -> L1Fee {
+        let l1_gas_used = self.data_gas(encoded);
+        let fee = l1_gas_used.saturating_mul(self.params.l1_fee_scaled())
+            / U256::from(NON_ZERO_BYTE_COST * 1_000_000);
+        L1Fee { fee, l1_gas_used, estimated_size: None }
+    }
+
+    fn calculate_fjord(&self, encoded: &[u8]) -> L1Fee {
+        self.fjord_fee(fjord_estimated_size_scaled(flz_compress_len(encoded)))
+    }
+
+    /// Returns an upper bound of the Fjord L1 data fee of a transaction, given the size of the
+    /// unsigned transaction.
+    ///
+    /// This mirrors `GasPriceOracle.getL1FeeUpperBound`, which adds 68 bytes for the signature
+    /// and bounds the compressed size with [`flz_compress_len_upper_bound`]. It is only
+    /// meaningful from Fjord onwards.
+    pub fn fjord_upper_bound(&self, unsigned_tx_size: u32) -> L1Fee {
+        let tx_size = unsigned_tx_size.saturating_add(SIGNATURE_SIZE as u32);
+        self.fjord_fee(fjord_estimated_size_scaled(flz_compress_len_upper_bound(tx_size)))
+    }
+
+    fn fjord_fee(&self, estimated_size_scaled: u64) -> L1Fee {
+        let scaled = U256::from(estimated_size_scaled);
+        let fee =
+            scaled.saturating_mul(self.params.l1_fee_scaled()) / U256::from(1_000_000_000_000u64);
+        let l1_gas_used = scaled * U256::from(NON_ZERO_BYTE_COST) / U256::from(1_000_000);
+        L1Fee { fee, l1_gas_used, estimated_size: Some(estimated_size_scaled / 1_000_000) }
+    }
+}
+
+/// Returns the Fjord estimated size of a transaction with the given FastLZ compressed length,
+/// scaled by 1e6.
+///
+/// `max(minTransactionSize, intercept + fastlzCoef * fastlzSize)`
+pub const fn fjord_estimated_size_scaled(flz_len: u32) -> u64 {
+    let estimated = (FJORD_L1_COST_FASTLZ_COEF * flz_len as u64) as i64 + FJORD_L1_COST_INTERCEPT;
+    if estimated < FJORD_MIN_TX_SIZE_SCALED as i64 {
+        FJORD_MIN_TX_SIZE_SCALED
+    } else {
+        estimated as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{L1BlockInfoBedrock, L1BlockInfoEcotone};
+    use alloy_primitives::hex;
+
+    fn params_1000() -> L1FeeParams {
+        L1FeeParams {
+            l1_base_fee: U256::from(1_000),
+            l1_fee_overhead: U256::from(1_000),
+            l1_fee_scalar: U256::from(1_000),
+            l1_blob_base_fee: U256::from(1_000),
+            l1_base_fee_scalar: U256::from(1_000),
+            l1_blob_base_fee_scalar: U256::from(1_000),
+        }
+    }
+
+    #[test]
+    fn test_l1_fee_bedrock() {
+        let calc = L1FeeCalculator::new(OpHardfork::Regolith, params_1000());
+        // (3 * 16 + 1000) * 1000 * 1000 / 1e6 = 1048
+        let fee = calc.calculate_raw(&hex!("FACADE"));
+        assert_eq!(fee, L1Fee { fee: U256::from(1048), l1_gas_used: U256::from(1048), ..fee });
+        assert_eq!(fee.estimated_size, None);
+
+        // Before Regolith, 68 non-zero bytes are added for the signature.
+        let calc = L1FeeCalculator::new(OpHardfork::Bedrock, params_1000());
+        let fee = calc.calculate_raw(&hex!("FACADE"));
+        assert_eq!(fee.l1_gas_used, U256::from(1048 + 68 * 16));
+    }
+
+    #[test]
+    fn test_l1_fee_ecotone() {
+        let calc = L1FeeCalculator::new(OpHardfork::Ecotone, params_1000());
+        // (3 * 16) * (1000 * 16 * 1000 + 1000 * 1000) / 16e6 = 51
+        let fee = calc.calculate_raw(&hex!("FACADE"));
+        assert_eq!(fee.fee, U256::from(51));
+        assert_eq!(fee.l1_gas_used, U256::from(48));
+
+        // The first Ecotone block still uses the Bedrock formula.
+        let params = L1FeeParams {
+            l1_base_fee_scalar: U256::ZERO,
+            l1_blob_base_fee_scalar: U256::ZERO,
+            ..params_1000()
+        };
+        let calc = L1FeeCalculator::new(OpHardfork::Ecotone, params);
+        assert_eq!(calc.calculate_raw(&hex!("FACADE")).fee, U256::from(1048));
+    }
+
+    #[test]
+    fn test_l1_fee_fjord() {
+        let calc = L1FeeCalculator::new(OpHardfork::Fjord, params_1000());
+        // max(100e6, 836500 * 4 - 42585600) * 17e6 / 1e12 = 1700
+        let fee = calc.calculate_raw(&hex!("FACADE"));
+        assert_eq!(
+            fee,
+            L1Fee {
+                fee: U256::from(1700),
+                l1_gas_used: U256::from(1600),
+                estimated_size: Some(100)
+            }
+        );
+
+        // max(100e6, 836500 * 202 - 42585600) * 17e6 / 1e12 = 2148
+        let fee = calc.calculate_raw(&hex!(
+            "02f901550a758302df1483be21b88304743f94f80e51afb613d764fa61751affd3313c190a86bb870151bd62fd12adb8e41ef24f3f000000000000000000000000000000000000000000000000000000000000006e000000000000000000000000af88d065e77c8cc2239327c5edb3a432268e5831000000000000000000000000000000000000000000000000000000000003c1e5000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000a000000000000000000000000000000000000000000000000000000000000000148c89ed219d02f1a5be012c689b4f5b731827bebe000000000000000000000000c001a033fd89cb37c31b2cba46b6466e040c61fc9b2a3675a7f5f493ebd5ad77c497f8a07cdf65680e238392693019b4092f610222e71b7cec06449cb922b93b6a12744e"
+        ));
+        assert_eq!(fee.fee, U256::from(2148));
+        assert_eq!(fee.estimated_size, Some(126));
+    }
+
+    #[test]
+    fn test_l1_fee_fjord_upper_bound() {
+        let calc = L1FeeCalculator::new(OpHardfork::Fjord, params_1000());
+        // Small transactions are bounded by the minimum size.
+        assert_eq!(calc.fjord_upper_bound(0).fee, U256::from(1700));
+
+        // 1000 + 68 bytes: flzUpperBound = 1068 + 1068 / 255 + 16 = 1088
+        // estimatedSize = 836500 * 1088 - 42585600 = 867526400
+        let fee = calc.fjord_upper_bound(1000);
+        assert_eq!(fee.estimated_size, Some(867));
+        assert_eq!(fee.l1_gas_used, U256::from(13880));
+        assert_eq!(fee.fee, U256::from(14747));
+
+        // The size saturates instead of overflowing.
+        let fee = calc.fjord_upper_bound(u32::MAX);
+        assert_eq!(fee.estimated_size, Some(fjord_estimated_size_scaled(u32::MAX) / 1_000_000));
+    }
+
+    #[test]
+    fn test_l1_fee_deposit_and_empty() {
+        for hardfork in [OpHardfork::Bedrock, OpHardfork::Ecotone, OpHardfork::Fjord] {
+            let calc = L1FeeCalculator::new(hardfork, params_1000());
+            assert_eq!(calc.calculate_raw(&[]), L1Fee::default());
+            assert_eq!(calc.calculate_raw(&hex!("7EFACADE")), L1Fee::default());
+        }
+    }
+
+    // OP Mainnet Ecotone block 118024092, second transaction:
+    // <https://optimistic.etherscan.io/tx/0xa75ef696bf67439b4d5b61da85de9f3ceaa2e145abe982212101b244b63749c2>
+    #[test]
+    fn test_l1_fee_ecotone_mainnet() {
+        let params = L1FeeParams::from(&L1BlockInfoTx::Ecotone(L1BlockInfoEcotone {
+            base_fee: U256::from(47036678951u64),
+            blob_base_fee: U256::from(57422457042u64),
+            base_fee_scalar: 1368,
+            blob_base_fee_scalar: 810949,
+            ..Default::default()
+        }));
+        let calc = L1FeeCalculator::new(OpHardfork::Ecotone, params);
+        let fee = calc.calculate_raw(&hex!(
+            "02f8b30a832253fc8402d11f39842c8a46398301388094dc6ff44d5d932cbd77b52e5612ba0529dc6226f180b844a9059cbb000000000000000000000000d43e02db81f4d46cdf8521f623d21ea0ec7562a50000000000000000000000000000000000000000000000008ac7230489e80000c001a02947e24750723b48f886931562c55d9e07f856d8e06468e719755e18bbc3a570a0784da9ce59fd7754ea5be6e17a86b348e441348cd48ace59d174772465eadbd1"
+        ));
+        assert_eq!(fee.l1_gas_used, U256::from(2456));
+        assert_eq!(fee.fee, U256::from(7306020222001u64));
+    }
+
+    #[test]
+    fn test_l1_fee_params_from_bedrock_info() {
+        let info = L1BlockInfoTx::Bedrock(L1BlockInfoBedrock {
+            base_fee: U256::from(1),
+            l1_fee_overhead: U256::from(2100),
+            l1_fee_scalar: U256::from(1_000_000),
+            ..Default::default()
+        });
+        let params = L1FeeParams::from(info);
+        assert_eq!(params.l1_fee_overhead, U256::from(2100));
+        assert_eq!(params.l1_fee_scalar, U256::from(1_000_000));
+        assert!(params.is_empty_ecotone_scalars());
+    }
+}
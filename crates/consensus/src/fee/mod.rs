@@ -0,0 +1,23 @@
+//! Fees charged to L2 transactions on top of the execution gas.
+//!
+//! See also: <https://specs.optimism.io/protocol/exec-engine.html#fees>
+
+mod fastlz;
+pub use fastlz::{flz_compress_len, flz_compress_len_upper_bound};
+
+mod l1;
+pub use l1::{
+    FJORD_L1_COST_FASTLZ_COEF, FJORD_L1_COST_INTERCEPT, FJORD_MIN_TX_SIZE_SCALED, L1Fee,
+    L1FeeCalculator, L1FeeParams, fjord_estimated_size_scaled,
+};
+
+mod operator;
+pub use operator::{
+    OPERATOR_FEE_JOVIAN_MULTIPLIER, OPERATOR_FEE_SCALAR_DECIMAL, OperatorFeeCalculator,
+    OperatorFeeParams,
+};
+
+mod da_footprint;
+pub use da_footprint::{
+    DaFootprintError, block_da_footprint, tx_da_footprint, validate_block_da_footprint,
+};
@@ -0,0 +1,180 @@
+//! Operator fee calculation.
+
+use crate::{L1BlockInfoTx, OpHardfork, OpTxEnvelope};
+use alloy_consensus::Transaction;
+use alloy_primitives::U256;
+
+/// The decimals of the operator fee scalar under Isthmus.
+pub const OPERATOR_FEE_SCALAR_DECIMAL: u64 = 1_000_000;
+
+/// The multiplier applied to the operator fee scalar since Jovian.
+pub const OPERATOR_FEE_JOVIAN_MULTIPLIER: u64 = 100;
+
+/// The operator fee parameters that are set by the L1 info deposit of a block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct OperatorFeeParams {
+    /// The operator fee scalar.
+    pub operator_fee_scalar: u32,
+    /// The operator fee constant.
+    pub operator_fee_constant: u64,
+}
+
+impl From<&L1BlockInfoTx> for OperatorFeeParams {
+    fn from(info: &L1BlockInfoTx) -> Self {
+        Self {
+            operator_fee_scalar: info.operator_fee_scalar(),
+            operator_fee_constant: info.operator_fee_constant(),
+        }
+    }
+}
+
+impl From<L1BlockInfoTx> for OperatorFeeParams {
+    fn from(info: L1BlockInfoTx) -> Self {
+        Self::from(&info)
+    }
+}
+
+/// Computes the operator fee of transactions, introduced in Isthmus.
+///
+/// The formula depends on the active hardfork:
+/// - Isthmus: `gas * operatorFeeScalar / 1e6 + operatorFeeConstant`
+/// - Jovian: `gas * operatorFeeScalar * 100 + operatorFeeConstant`
+///
+/// Before Isthmus, and for deposit transactions, the operator fee is always zero.
+///
+/// The fee is charged upfront for the gas limit of the transaction, and the part that corresponds
+/// to unused gas is refunded after execution, see [`OperatorFeeCalculator::refund`].
+///
+/// See also: <https://specs.optimism.io/protocol/isthmus/exec-engine.html#operator-fee>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OperatorFeeCalculator {
+    hardfork: OpHardfork,
+    params: OperatorFeeParams,
+}
+
+impl OperatorFeeCalculator {
+    /// Creates a new calculator for the given active hardfork and fee parameters.
+    pub const fn new(hardfork: OpHardfork, params: OperatorFeeParams) -> Self {
+        Self { hardfork, params }
+    }
+
+    /// Returns the active hardfork.
+    pub const fn hardfork(&self) -> OpHardfork {
+        self.hardfork
+    }
+
+    /// Returns the fee parameters.
+    pub const fn params(&self) -> &OperatorFeeParams {
+        &self.params
+    }
+
+    /// Returns the operator fee charged for `gas`.
+    pub fn charge(&self, gas: u64) -> U256 {
+        if !self.hardfork.is_enabled_in(OpHardfork::Isthmus) {
+            return U256::ZERO;
+        }
+
+        let product = U256::from(gas).saturating_mul(U256::from(self.params.operator_fee_scalar));
+        let product = if self.hardfork.is_enabled_in(OpHardfork::Jovian) {
+            product.saturating_mul(U256::from(OPERATOR_FEE_JOVIAN_MULTIPLIER))
+        } else {
+            product / U256::from(OPERATOR_FEE_SCALAR_DECIMAL)
+        };
+        product.saturating_add(U256::from(self.params.operator_fee_constant))
+    }
+
+    /// Returns the part of the operator fee charged for `gas_limit` that is refunded when the
+    /// transaction only uses `gas_used`.
+    ///
+    /// `gas_used` is the gas used after refunds.
+    pub fn refund(&self, gas_limit: u64, gas_used: u64) -> U256 {
+        self.charge(gas_limit).saturating_sub(self.charge(gas_used))
+    }
+
+    /// Returns the operator fee charged upfront for `tx`, based on its gas limit.
+    pub fn charge_tx(&self, tx: &OpTxEnvelope) -> U256 {
+        if tx.is_deposit() {
+            return U256::ZERO;
+        }
+        self.charge(tx.gas_limit())
+    }
+
+    /// Returns the operator fee refunded to the sender of `tx` after execution.
+    ///
+    /// Deposit transactions are not charged an operator fee, so nothing is refunded.
+    pub fn refund_tx(&self, tx: &OpTxEnvelope, gas_used: u64) -> U256 {
+        if tx.is_deposit() {
+            return U256::ZERO;
+        }
+        self.refund(tx.gas_limit(), gas_used)
+    }
+
+    /// Returns the operator fee that `tx` pays in the end, i.e. the upfront charge minus the
+    /// refund.
+    pub fn fee_tx(&self, tx: &OpTxEnvelope, gas_used: u64) -> U256 {
+        self.charge_tx(tx).saturating_sub(self.refund_tx(tx, gas_used))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TxDeposit;
+    use alloy_consensus::{Sealable, Signed, TxLegacy};
+    use alloy_primitives::Signature;
+
+    const PARAMS: OperatorFeeParams =
+        OperatorFeeParams { operator_fee_scalar: 1_000, operator_fee_constant: 10 };
+
+    #[test]
+    fn test_operator_fee_charge() {
+        // 1000 * 1000 / 1e6 + 10
+        assert_eq!(
+            OperatorFeeCalculator::new(OpHardfork::Isthmus, PARAMS).charge(1_000),
+            U256::from(11)
+        );
+        // 1000 * 1000 * 100 + 10
+        assert_eq!(
+            OperatorFeeCalculator::new(OpHardfork::Jovian, PARAMS).charge(1_000),
+            U256::from(100_000_010)
+        );
+        assert_eq!(
+            OperatorFeeCalculator::new(OpHardfork::Holocene, PARAMS).charge(1_000),
+            U256::ZERO
+        );
+    }
+
+    #[test]
+    fn test_operator_fee_refund() {
+        let params = OperatorFeeParams { operator_fee_scalar: 2_000, operator_fee_constant: 5 };
+        let calc = OperatorFeeCalculator::new(OpHardfork::Isthmus, params);
+        // (50000 * 2000 / 1e6 + 5) - 5
+        assert_eq!(calc.refund(50_000, 0), U256::from(100));
+        assert_eq!(calc.refund(50_000, 50_000), U256::ZERO);
+
+        let calc = OperatorFeeCalculator::new(OpHardfork::Jovian, params);
+        assert_eq!(calc.refund(50_000, 20_000), U256::from(30_000u64 * 2_000 * 100));
+    }
+
+    #[test]
+    fn test_operator_fee_tx() {
+        let calc = OperatorFeeCalculator::new(OpHardfork::Isthmus, PARAMS);
+
+        let tx = OpTxEnvelope::Legacy(Signed::new_unhashed(
+            TxLegacy { gas_limit: 21_000, ..Default::default() },
+            Signature::test_signature(),
+        ));
+        assert_eq!(calc.charge_tx(&tx), U256::from(31));
+        assert_eq!(calc.refund_tx(&tx, 1_000), U256::from(20));
+        assert_eq!(calc.fee_tx(&tx, 1_000), U256::from(11));
+
+        let deposit = OpTxEnvelope::Deposit(
+            TxDeposit { gas_limit: 21_000, ..Default::default() }.seal_slow(),
+        );
+        assert_eq!(calc.charge_tx(&deposit), U256::ZERO);
+        assert_eq!(calc.refund_tx(&deposit, 1_000), U256::ZERO);
+        assert_eq!(calc.fee_tx(&deposit, 1_000), U256::ZERO);
+    }
+}
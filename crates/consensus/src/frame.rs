@@ -0,0 +1,216 @@
+//! Channel frames, the unit of batch data posted to L1 by the batcher.
+//!
+//! The data of a batcher transaction, either its calldata or the content of a blob, is a
+//! derivation version byte followed by one or more frames. Each frame carries a chunk of the data
+//! of a [`Channel`](crate::Channel).
+//!
+//! See also: <https://specs.optimism.io/protocol/derivation.html#frame-format>
+
+use crate::ChannelId;
+use alloc::vec::Vec;
+use alloy_primitives::Bytes;
+
+/// The derivation version of the batcher transaction data format, the only one in use.
+pub const DERIVATION_VERSION_0: u8 = 0;
+
+/// The maximum length of the data of a frame.
+pub const MAX_FRAME_LEN: usize = 1_000_000;
+
+/// The overhead of a frame, added to the length of its data when accounting for the size of a
+/// channel.
+pub const FRAME_OVERHEAD: usize = 200;
+
+/// A chunk of the data of a channel.
+///
+/// A frame is encoded as
+/// `channel_id (16 bytes) ++ frame_number (2 bytes) ++ frame_data_length (4 bytes) ++ frame_data
+/// ++ is_last (1 byte)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Frame {
+    /// The ID of the channel of the frame.
+    pub id: ChannelId,
+    /// The position of the frame in the channel.
+    pub number: u16,
+    /// The chunk of channel data carried by the frame.
+    pub data: Bytes,
+    /// Whether this is the last frame of the channel.
+    pub is_last: bool,
+}
+
+impl Frame {
+    /// The length of the encoded frame without its data.
+    pub const BASE_LENGTH: usize = 16 + 2 + 4 + 1;
+
+    /// Creates a new frame.
+    pub const fn new(id: ChannelId, number: u16, data: Bytes, is_last: bool) -> Self {
+        Self { id, number, data, is_last }
+    }
+
+    /// Returns the length of the encoded frame.
+    pub fn encoded_len(&self) -> usize {
+        Self::BASE_LENGTH + self.data.len()
+    }
+
+    /// Returns the size of the frame when accounting for the size of a channel, i.e. the length
+    /// of its data plus [`FRAME_OVERHEAD`].
+    pub fn size(&self) -> usize {
+        self.data.len() + FRAME_OVERHEAD
+    }
+
+    /// Encodes the frame.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut encoded = Vec::with_capacity(self.encoded_len());
+        encoded.extend_from_slice(self.id.as_slice());
+        encoded.extend_from_slice(&self.number.to_be_bytes());
+        encoded.extend_from_slice(&(self.data.len() as u32).to_be_bytes());
+        encoded.extend_from_slice(&self.data);
+        encoded.push(self.is_last as u8);
+        encoded
+    }
+
+    /// Decodes a frame from the start of `buf`, advancing it past the frame.
+    pub fn decode(buf: &mut &[u8]) -> Result<Self, FrameError> {
+        if buf.len() < Self::BASE_LENGTH {
+            return Err(FrameError::TooShort(buf.len()));
+        }
+        let id = ChannelId::from_slice(&buf[..16]);
+        let number = u16::from_be_bytes([buf[16], buf[17]]);
+        let data_len = u32::from_be_bytes(buf[18..22].try_into().unwrap()) as usize;
+        if data_len > MAX_FRAME_LEN {
+            return Err(FrameError::DataTooLarge(data_len));
+        }
+        let Some(&is_last) = buf.get(22 + data_len) else {
+            return Err(FrameError::Truncated {
+                expected: Self::BASE_LENGTH + data_len,
+                got: buf.len(),
+            });
+        };
+        let is_last = match is_last {
+            0 => false,
+            1 => true,
+            byte => return Err(FrameError::InvalidIsLast(byte)),
+        };
+        let data = Bytes::copy_from_slice(&buf[22..22 + data_len]);
+        *buf = &buf[Self::BASE_LENGTH + data_len..];
+        Ok(Self { id, number, data, is_last })
+    }
+
+    /// Parses the frames of the data of a batcher transaction.
+    ///
+    /// The data must start with [`DERIVATION_VERSION_0`] and be followed by at least one frame.
+    /// The whole data is rejected if any frame is invalid or if trailing bytes are left.
+    pub fn parse_frames(data: &[u8]) -> Result<Vec<Self>, FrameError> {
+        let (&version, mut buf) = data.split_first().ok_or(FrameError::NoFrames)?;
+        if version != DERIVATION_VERSION_0 {
+            return Err(FrameError::UnsupportedVersion(version));
+        }
+        if buf.is_empty() {
+            return Err(FrameError::NoFrames);
+        }
+        let mut frames = Vec::new();
+        while !buf.is_empty() {
+            frames.push(Self::decode(&mut buf)?);
+        }
+        Ok(frames)
+    }
+
+    /// Encodes `frames` as the data of a batcher transaction, see [`Frame::parse_frames`].
+    pub fn encode_frames<'a>(frames: impl IntoIterator<Item = &'a Self>) -> Bytes {
+        let mut data = alloc::vec![DERIVATION_VERSION_0];
+        for frame in frames {
+            data.extend_from_slice(&frame.encode());
+        }
+        data.into()
+    }
+}
+
+/// Errors that can occur when decoding frames.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum FrameError {
+    /// The data is shorter than a frame without data.
+    #[error("Frame too short: {0} bytes")]
+    TooShort(usize),
+    /// The data is shorter than the frame length declared by its header.
+    #[error("Frame truncated: expected {expected} bytes, got {got}")]
+    Truncated {
+        /// The length of the frame declared by its header.
+        expected: usize,
+        /// The length of the data.
+        got: usize,
+    },
+    /// The frame data is longer than [`MAX_FRAME_LEN`].
+    #[error("Frame data too large: {0} bytes")]
+    DataTooLarge(usize),
+    /// The `is_last` byte of the frame is neither 0 nor 1.
+    #[error("Invalid frame is_last byte: {0}")]
+    InvalidIsLast(u8),
+    /// The batcher transaction data does not carry any frame.
+    #[error("No frames in batcher transaction data")]
+    NoFrames,
+    /// The derivation version of the batcher transaction data is not supported.
+    #[error("Unsupported derivation version: {0}")]
+    UnsupportedVersion(u8),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use alloy_primitives::hex;
+
+    fn frame(number: u16, is_last: bool) -> Frame {
+        Frame::new(ChannelId::repeat_byte(0xff), number, Bytes::from(vec![0xdd; 3]), is_last)
+    }
+
+    #[test]
+    fn test_frame_encoding() {
+        let frame = frame(0x0102, true);
+        let encoded = frame.encode();
+        assert_eq!(encoded, hex!("ffffffffffffffffffffffffffffffff010200000003dddddd01"));
+        assert_eq!(encoded.len(), frame.encoded_len());
+        assert_eq!(frame.size(), 203);
+
+        let mut buf = encoded.as_slice();
+        assert_eq!(Frame::decode(&mut buf), Ok(frame));
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_decode_frame_invalid() {
+        let encoded = frame(1, false).encode();
+        let len = encoded.len();
+        assert_eq!(Frame::decode(&mut &encoded[..22]), Err(FrameError::TooShort(22)));
+        assert_eq!(
+            Frame::decode(&mut &encoded[..len - 1]),
+            Err(FrameError::Truncated { expected: len, got: len - 1 })
+        );
+
+        let mut invalid = encoded.clone();
+        invalid[len - 1] = 2;
+        assert_eq!(Frame::decode(&mut invalid.as_slice()), Err(FrameError::InvalidIsLast(2)));
+
+        let mut invalid = encoded;
+        invalid[18..22].copy_from_slice(&(MAX_FRAME_LEN as u32 + 1).to_be_bytes());
+        assert_eq!(
+            Frame::decode(&mut invalid.as_slice()),
+            Err(FrameError::DataTooLarge(MAX_FRAME_LEN + 1))
+        );
+    }
+
+    #[test]
+    fn test_parse_frames() {
+        let frames = vec![frame(0, false), frame(1, false), frame(2, true)];
+        let data = Frame::encode_frames(&frames);
+        assert_eq!(data[0], DERIVATION_VERSION_0);
+        assert_eq!(Frame::parse_frames(&data), Ok(frames));
+
+        assert_eq!(Frame::parse_frames(&[]), Err(FrameError::NoFrames));
+        assert_eq!(Frame::parse_frames(&[DERIVATION_VERSION_0]), Err(FrameError::NoFrames));
+        assert_eq!(Frame::parse_frames(&[1]), Err(FrameError::UnsupportedVersion(1)));
+
+        // Trailing bytes invalidate the whole data.
+        let mut data = data.to_vec();
+        data.push(0);
+        assert_eq!(Frame::parse_frames(&data), Err(FrameError::TooShort(1)));
+    }
+}
@@ -0,0 +1,342 @@
+//! OP Stack hardforks and their activation schedule.
+
+use alloc::string::String;
+use core::str::FromStr;
+
+/// The network upgrades of the OP Stack, in activation order.
+///
+/// The ordering of the variants follows the order in which the hardforks are activated, so two
+/// forks can be compared directly to check whether one includes the other:
+///
+/// ```
+/// use op_alloy_consensus::OpHardfork;
+///
+/// assert!(OpHardfork::Fjord >= OpHardfork::Ecotone);
+/// assert!(OpHardfork::Bedrock < OpHardfork::Regolith);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum OpHardfork {
+    /// The Bedrock network upgrade, the base of every OP Stack chain.
+    #[default]
+    Bedrock,
+    /// The Regolith network upgrade.
+    Regolith,
+    /// The Canyon network upgrade.
+    Canyon,
+    /// The Delta network upgrade.
+    Delta,
+    /// The Ecotone network upgrade.
+    Ecotone,
+    /// The Fjord network upgrade.
+    Fjord,
+    /// The Granite network upgrade.
+    Granite,
+    /// The Holocene network upgrade.
+    Holocene,
+    /// The Isthmus network upgrade.
+    Isthmus,
+    /// The Jovian network upgrade.
+    Jovian,
+    /// The Interop network upgrade.
+    Interop,
+}
+
+impl OpHardfork {
+    /// All hardforks, in activation order.
+    pub const VARIANTS: [Self; 11] = [
+        Self::Bedrock,
+        Self::Regolith,
+        Self::Canyon,
+        Self::Delta,
+        Self::Ecotone,
+        Self::Fjord,
+        Self::Granite,
+        Self::Holocene,
+        Self::Isthmus,
+        Self::Jovian,
+        Self::Interop,
+    ];
+
+    /// Returns `true` if the rules of `fork` apply when `self` is the active hardfork.
+    #[inline]
+    pub const fn is_enabled_in(self, fork: Self) -> bool {
+        self as u8 >= fork as u8
+    }
+
+    /// Returns the name of the hardfork in lowercase, as used in configuration files.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Bedrock => "bedrock",
+            Self::Regolith => "regolith",
+            Self::Canyon => "canyon",
+            Self::Delta => "delta",
+            Self::Ecotone => "ecotone",
+            Self::Fjord => "fjord",
+            Self::Granite => "granite",
+            Self::Holocene => "holocene",
+            Self::Isthmus => "isthmus",
+            Self::Jovian => "jovian",
+            Self::Interop => "interop",
+        }
+    }
+}
+
+impl core::fmt::Display for OpHardfork {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for OpHardfork {
+    type Err = ParseOpHardforkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::VARIANTS
+            .into_iter()
+            .find(|fork| fork.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| ParseOpHardforkError(s.into()))
+    }
+}
+
+/// Error returned when parsing an unknown [`OpHardfork`] name.
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+#[error("Unknown OP hardfork: {0}")]
+pub struct ParseOpHardforkError(pub String);
+
+/// The timestamp activation schedule of the OP Stack hardforks of a chain.
+///
+/// Bedrock is the base of every OP Stack chain and is always active. Every later hardfork is
+/// activated at a timestamp, or not scheduled at all.
+///
+/// Hardforks must activate in order, so a hardfork is considered active as soon as any later
+/// hardfork is active, even if it is not scheduled explicitly. This covers configurations that
+/// omit a hardfork, such as genesis files without a Delta activation time.
+///
+/// ```
+/// use op_alloy_consensus::{OpHardfork, OpHardforks};
+///
+/// let schedule = OpHardforks::default()
+///     .with_activation(OpHardfork::Regolith, 0)
+///     .with_activation(OpHardfork::Canyon, 100);
+/// assert_eq!(schedule.active_fork_at(99), OpHardfork::Regolith);
+/// assert_eq!(schedule.next_fork_after(99), Some((OpHardfork::Canyon, 100)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct OpHardforks {
+    activations: [Option<u64>; OpHardfork::VARIANTS.len()],
+}
+
+impl OpHardforks {
+    /// Creates an empty schedule, in which only [`OpHardfork::Bedrock`] is active.
+    pub const fn new() -> Self {
+        Self { activations: [None; OpHardfork::VARIANTS.len()] }
+    }
+
+    /// Sets the activation timestamp of `fork`.
+    ///
+    /// The activation of [`OpHardfork::Bedrock`] is ignored, as it is always active.
+    pub const fn with_activation(mut self, fork: OpHardfork, timestamp: u64) -> Self {
+        self.set_activation(fork, Some(timestamp));
+        self
+    }
+
+    /// Sets or clears the activation timestamp of `fork`.
+    ///
+    /// The activation of [`OpHardfork::Bedrock`] is ignored, as it is always active.
+    pub const fn set_activation(&mut self, fork: OpHardfork, timestamp: Option<u64>) {
+        if !matches!(fork, OpHardfork::Bedrock) {
+            self.activations[fork as usize] = timestamp;
+        }
+    }
+
+    /// Returns the activation timestamp of `fork`, if it is scheduled explicitly.
+    ///
+    /// Always returns `Some(0)` for [`OpHardfork::Bedrock`].
+    pub const fn activation(&self, fork: OpHardfork) -> Option<u64> {
+        if matches!(fork, OpHardfork::Bedrock) {
+            return Some(0);
+        }
+        self.activations[fork as usize]
+    }
+
+    /// Returns `true` if `fork` is active at `timestamp`.
+    pub fn is_active_at(&self, fork: OpHardfork, timestamp: u64) -> bool {
+        self.active_fork_at(timestamp) >= fork
+    }
+
+    /// Returns `true` if the block at `timestamp` is the first block in which `fork` is active,
+    /// given the L2 block time.
+    ///
+    /// Only explicitly scheduled hardforks have an activation block, and the genesis block is
+    /// never one. This is the block that includes the upgrade transactions of `fork`, see
+    /// [`upgrade_transactions`](crate::upgrade_transactions).
+    pub const fn is_activation_block(
+        &self,
+        fork: OpHardfork,
+        timestamp: u64,
+        block_time: u64,
+    ) -> bool {
+        match self.activation(fork) {
+            Some(time) => {
+                timestamp >= time && timestamp >= block_time && timestamp - block_time < time
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the latest hardfork that is active at `timestamp`.
+    pub fn active_fork_at(&self, timestamp: u64) -> OpHardfork {
+        OpHardfork::VARIANTS
+            .into_iter()
+            .rev()
+            .find(|fork| self.activation(*fork).is_some_and(|time| time <= timestamp))
+            .unwrap_or_default()
+    }
+
+    /// Returns the first hardfork that activates after `timestamp`, with its activation
+    /// timestamp.
+    pub fn next_fork_after(&self, timestamp: u64) -> Option<(OpHardfork, u64)> {
+        OpHardfork::VARIANTS
+            .into_iter()
+            .filter_map(|fork| self.activation(fork).map(|time| (fork, time)))
+            .filter(|(_, time)| *time > timestamp)
+            .min_by_key(|(_, time)| *time)
+    }
+
+    /// Checks that the scheduled hardforks activate in order.
+    pub fn validate(&self) -> Result<(), OpHardforksError> {
+        let mut previous = (OpHardfork::Bedrock, 0);
+        for fork in OpHardfork::VARIANTS {
+            let Some(time) = self.activation(fork) else { continue };
+            if time < previous.1 {
+                return Err(OpHardforksError::OutOfOrder {
+                    fork,
+                    time,
+                    previous: previous.0,
+                    previous_time: previous.1,
+                });
+            }
+            previous = (fork, time);
+        }
+        Ok(())
+    }
+}
+
+/// Errors that can occur when validating an [`OpHardforks`] schedule.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum OpHardforksError {
+    /// A hardfork activates before an earlier hardfork.
+    #[error("{fork} activates at {time}, before {previous} at {previous_time}")]
+    OutOfOrder {
+        /// The hardfork that activates too early.
+        fork: OpHardfork,
+        /// The activation timestamp of `fork`.
+        time: u64,
+        /// The earlier hardfork that activates later.
+        previous: OpHardfork,
+        /// The activation timestamp of `previous`.
+        previous_time: u64,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_hardfork_order() {
+        assert!(OpHardfork::VARIANTS.windows(2).all(|w| w[0] < w[1]));
+        assert!(OpHardfork::Isthmus.is_enabled_in(OpHardfork::Ecotone));
+        assert!(OpHardfork::Ecotone.is_enabled_in(OpHardfork::Ecotone));
+        assert!(!OpHardfork::Ecotone.is_enabled_in(OpHardfork::Fjord));
+    }
+
+    #[test]
+    fn test_hardfork_from_str() {
+        for fork in OpHardfork::VARIANTS {
+            assert_eq!(fork.as_str().parse::<OpHardfork>().unwrap(), fork);
+            assert_eq!(fork.to_string(), fork.as_str());
+            assert_eq!(fork.to_string().parse::<OpHardfork>().unwrap(), fork);
+        }
+        assert_eq!(
+            "shanghai".parse::<OpHardfork>().unwrap_err(),
+            ParseOpHardforkError("shanghai".into())
+        );
+    }
+
+    #[test]
+    fn test_schedule_activation() {
+        let schedule = OpHardforks::default()
+            .with_activation(OpHardfork::Regolith, 0)
+            .with_activation(OpHardfork::Canyon, 10)
+            .with_activation(OpHardfork::Ecotone, 20)
+            .with_activation(OpHardfork::Fjord, 30);
+
+        assert_eq!(schedule.active_fork_at(0), OpHardfork::Regolith);
+        assert_eq!(schedule.active_fork_at(19), OpHardfork::Canyon);
+        assert_eq!(schedule.active_fork_at(20), OpHardfork::Ecotone);
+        assert_eq!(schedule.active_fork_at(u64::MAX), OpHardfork::Fjord);
+
+        // Delta is not scheduled, but is implied by Ecotone.
+        assert!(!schedule.is_active_at(OpHardfork::Delta, 19));
+        assert!(schedule.is_active_at(OpHardfork::Delta, 20));
+        assert!(!schedule.is_active_at(OpHardfork::Granite, u64::MAX));
+        assert!(schedule.is_active_at(OpHardfork::Bedrock, 0));
+
+        assert_eq!(schedule.next_fork_after(0), Some((OpHardfork::Canyon, 10)));
+        assert_eq!(schedule.next_fork_after(20), Some((OpHardfork::Fjord, 30)));
+        assert_eq!(schedule.next_fork_after(30), None);
+        assert_eq!(schedule.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_schedule_activation_block() {
+        let schedule = OpHardforks::default()
+            .with_activation(OpHardfork::Regolith, 0)
+            .with_activation(OpHardfork::Ecotone, 21);
+
+        assert!(!schedule.is_activation_block(OpHardfork::Ecotone, 20, 2));
+        assert!(schedule.is_activation_block(OpHardfork::Ecotone, 22, 2));
+        assert!(!schedule.is_activation_block(OpHardfork::Ecotone, 24, 2));
+
+        // Genesis and implied hardforks have no activation block.
+        assert!(!schedule.is_activation_block(OpHardfork::Regolith, 0, 2));
+        assert!(!schedule.is_activation_block(OpHardfork::Delta, 22, 2));
+    }
+
+    #[test]
+    fn test_schedule_empty() {
+        let schedule = OpHardforks::default();
+        assert_eq!(schedule.active_fork_at(0), OpHardfork::Bedrock);
+        assert_eq!(schedule.activation(OpHardfork::Bedrock), Some(0));
+        assert_eq!(schedule.next_fork_after(0), None);
+        assert_eq!(schedule.with_activation(OpHardfork::Bedrock, 5), schedule);
+    }
+
+    #[test]
+    fn test_schedule_out_of_order() {
+        let schedule = OpHardforks::default()
+            .with_activation(OpHardfork::Ecotone, 20)
+            .with_activation(OpHardfork::Granite, 10);
+        assert_eq!(
+            schedule.validate(),
+            Err(OpHardforksError::OutOfOrder {
+                fork: OpHardfork::Granite,
+                time: 10,
+                previous: OpHardfork::Ecotone,
+                previous_time: 20,
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_hardfork_serde() {
+        assert_eq!(serde_json::to_string(&OpHardfork::Holocene).unwrap(), "\"holocene\"");
+        assert_eq!(serde_json::from_str::<OpHardfork>("\"jovian\"").unwrap(), OpHardfork::Jovian);
+        assert_eq!(OpHardfork::Granite.to_string(), "granite");
+    }
+}
@@ -0,0 +1,275 @@
+//! Declaration of executing messages in the access list of transactions.
+//!
+//! Each executing message must be declared in the access list of its transaction, as storage keys
+//! of [`CROSS_L2_INBOX_ADDRESS`]: a lookup entry that locates the initiating message, followed by
+//! a checksum entry that binds it to its origin and payload hash. This lets the transaction pool
+//! and the block builder check the messages before executing the transaction.
+//!
+//! See also: <https://specs.optimism.io/interop/predeploys.html#access-list>
+
+use super::{CHECKSUM_ENTRY_TYPE, ExecutingMessage, Identifier};
+use crate::{OpTxEnvelope, predeploys::CROSS_L2_INBOX_ADDRESS};
+use alloc::vec::Vec;
+use alloy_consensus::Transaction;
+use alloy_eips::eip2930::{AccessList, AccessListItem};
+use alloy_primitives::{B256, ChainId};
+
+/// The type byte of a lookup entry.
+pub const LOOKUP_ENTRY_TYPE: u8 = 0x01;
+
+/// The type byte of a chain ID extension entry, which carries the upper bytes of chain IDs that do
+/// not fit in 64 bits.
+pub const CHAIN_ID_EXTENSION_ENTRY_TYPE: u8 = 0x02;
+
+impl Identifier {
+    /// Returns the lookup entry of the identifier:
+    ///
+    /// `0x01 ++ 0 (3 bytes) ++ chainId (8 bytes) ++ blockNumber (8 bytes) ++ timestamp (8 bytes) ++
+    /// logIndex (4 bytes)`
+    pub fn lookup_entry(&self) -> B256 {
+        let mut entry = B256::ZERO;
+        entry[0] = LOOKUP_ENTRY_TYPE;
+        entry[4..12].copy_from_slice(&self.chain_id.to_be_bytes());
+        entry[12..20].copy_from_slice(&self.block_number.to_be_bytes());
+        entry[20..28].copy_from_slice(&self.timestamp.to_be_bytes());
+        entry[28..].copy_from_slice(&self.log_index.to_be_bytes());
+        entry
+    }
+}
+
+impl ExecutingMessage {
+    /// Returns the access list entries that declare the message: its lookup entry followed by its
+    /// checksum entry.
+    pub fn access_list_entries(&self) -> [B256; 2] {
+        [self.identifier.lookup_entry(), self.checksum()]
+    }
+}
+
+/// Returns the access list item of [`CROSS_L2_INBOX_ADDRESS`] that declares `messages`, in order.
+pub fn inbox_access_list_item<'a>(
+    messages: impl IntoIterator<Item = &'a ExecutingMessage>,
+) -> AccessListItem {
+    AccessListItem {
+        address: CROSS_L2_INBOX_ADDRESS,
+        storage_keys: messages
+            .into_iter()
+            .flat_map(ExecutingMessage::access_list_entries)
+            .collect(),
+    }
+}
+
+/// An executing message, as declared in the access list of a transaction.
+///
+/// The lookup entry does not include the origin and the payload hash of the message, which are
+/// only committed to by the checksum, see [`DeclaredMessage::matches`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DeclaredMessage {
+    /// The chain ID of the initiating message.
+    pub chain_id: ChainId,
+    /// The block number of the initiating message.
+    pub block_number: u64,
+    /// The timestamp of the initiating message.
+    pub timestamp: u64,
+    /// The log index of the initiating message.
+    pub log_index: u32,
+    /// The checksum entry of the message.
+    pub checksum: B256,
+}
+
+impl DeclaredMessage {
+    /// Returns whether `message` is the declared message.
+    pub fn matches(&self, message: &ExecutingMessage) -> bool {
+        let id = &message.identifier;
+        id.chain_id == self.chain_id
+            && id.block_number == self.block_number
+            && id.timestamp == self.timestamp
+            && id.log_index == self.log_index
+            && message.checksum() == self.checksum
+    }
+}
+
+/// Parses the executing messages declared in the storage keys of [`CROSS_L2_INBOX_ADDRESS`] in
+/// `access_list`.
+///
+/// Items of other addresses are ignored. Each lookup entry must be directly followed by its
+/// checksum entry, within the same item.
+pub fn parse_inbox_access_list(
+    access_list: &AccessList,
+) -> Result<Vec<DeclaredMessage>, AccessListError> {
+    let mut messages = Vec::new();
+    for item in access_list.iter().filter(|item| item.address == CROSS_L2_INBOX_ADDRESS) {
+        let mut entries = item.storage_keys.iter();
+        while let Some(entry) = entries.next() {
+            let lookup = match entry[0] {
+                LOOKUP_ENTRY_TYPE => parse_lookup_entry(entry)?,
+                CHAIN_ID_EXTENSION_ENTRY_TYPE => {
+                    return Err(AccessListError::UnexpectedChainIdExtension);
+                }
+                CHECKSUM_ENTRY_TYPE => return Err(AccessListError::UnexpectedChecksum(*entry)),
+                ty => return Err(AccessListError::UnknownEntryType(ty)),
+            };
+
+            let next = entries.next().ok_or(AccessListError::MissingChecksum)?;
+            if next[0] == CHAIN_ID_EXTENSION_ENTRY_TYPE {
+                // Chain IDs are limited to 64 bits, so the extension can only be invalid.
+                if next[8..].iter().any(|b| *b != 0) {
+                    return Err(AccessListError::ChainIdTooHigh);
+                }
+                return Err(AccessListError::InvalidChainIdExtension(*next));
+            }
+            if next[0] != CHECKSUM_ENTRY_TYPE {
+                return Err(AccessListError::MissingChecksum);
+            }
+            messages.push(DeclaredMessage { checksum: *next, ..lookup });
+        }
+    }
+    Ok(messages)
+}
+
+/// Parses the executing messages declared in the access list of `tx`.
+///
+/// Transactions without an access list, like legacy and deposit transactions, do not declare any
+/// message. See [`parse_inbox_access_list`].
+pub fn declared_messages(tx: &OpTxEnvelope) -> Result<Vec<DeclaredMessage>, AccessListError> {
+    tx.access_list().map_or_else(|| Ok(Vec::new()), parse_inbox_access_list)
+}
+
+/// Parses a lookup entry, leaving the checksum empty.
+fn parse_lookup_entry(entry: &B256) -> Result<DeclaredMessage, AccessListError> {
+    if entry[1..4].iter().any(|b| *b != 0) {
+        return Err(AccessListError::InvalidLookupEntry(*entry));
+    }
+    Ok(DeclaredMessage {
+        chain_id: u64::from_be_bytes(entry[4..12].try_into().unwrap()),
+        block_number: u64::from_be_bytes(entry[12..20].try_into().unwrap()),
+        timestamp: u64::from_be_bytes(entry[20..28].try_into().unwrap()),
+        log_index: u32::from_be_bytes(entry[28..].try_into().unwrap()),
+        checksum: B256::ZERO,
+    })
+}
+
+/// Errors that can occur when parsing the executing messages declared in an access list.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum AccessListError {
+    /// An entry has an unknown type byte.
+    #[error("Unknown inbox access list entry type: {0}")]
+    UnknownEntryType(u8),
+    /// A lookup entry has non-zero padding.
+    #[error("Invalid inbox lookup entry: {0}")]
+    InvalidLookupEntry(B256),
+    /// A lookup entry is not followed by a checksum entry.
+    #[error("Inbox lookup entry is not followed by a checksum entry")]
+    MissingChecksum,
+    /// A checksum entry is not preceded by a lookup entry.
+    #[error("Inbox checksum entry without lookup entry: {0}")]
+    UnexpectedChecksum(B256),
+    /// A chain ID extension entry is not preceded by a lookup entry.
+    #[error("Inbox chain ID extension entry without lookup entry")]
+    UnexpectedChainIdExtension,
+    /// A chain ID extension entry does not extend the chain ID.
+    #[error("Invalid inbox chain ID extension entry: {0}")]
+    InvalidChainIdExtension(B256),
+    /// A chain ID extension entry declares a chain ID that does not fit in 64 bits.
+    #[error("Inbox chain ID overflows u64")]
+    ChainIdTooHigh,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use alloy_consensus::{Signed, TxEip1559, TxLegacy};
+    use alloy_primitives::{Address, Signature, address, b256};
+
+    fn message(log_index: u32) -> ExecutingMessage {
+        ExecutingMessage::new(
+            B256::repeat_byte(0xaa),
+            Identifier {
+                origin: address!("0x4200000000000000000000000000000000000023"),
+                block_number: 0x0102_0304,
+                log_index,
+                timestamp: 0x6500_0000,
+                chain_id: 10,
+            },
+        )
+    }
+
+    fn access_list(storage_keys: Vec<B256>) -> AccessList {
+        AccessList(vec![
+            AccessListItem { address: Address::ZERO, storage_keys: vec![B256::ZERO] },
+            AccessListItem { address: CROSS_L2_INBOX_ADDRESS, storage_keys },
+        ])
+    }
+
+    #[test]
+    fn test_lookup_entry() {
+        assert_eq!(
+            message(7).identifier.lookup_entry(),
+            b256!("0x01000000000000000000000a0000000001020304000000006500000000000007")
+        );
+    }
+
+    #[test]
+    fn test_inbox_access_list_roundtrip() {
+        let messages = [message(1), message(2)];
+        let item = inbox_access_list_item(&messages);
+        assert_eq!(item.storage_keys.len(), 4);
+        assert_eq!(item.storage_keys[1][0], CHECKSUM_ENTRY_TYPE);
+
+        let declared = parse_inbox_access_list(&AccessList(vec![item])).unwrap();
+        assert_eq!(declared.len(), 2);
+        assert!(declared[0].matches(&messages[0]));
+        assert!(declared[1].matches(&messages[1]));
+        assert!(!declared[0].matches(&messages[1]));
+
+        let other_payload = ExecutingMessage { payload_hash: B256::ZERO, ..messages[0] };
+        assert!(!declared[0].matches(&other_payload));
+    }
+
+    #[test]
+    fn test_declared_messages() {
+        let messages = [message(1)];
+        let tx = TxEip1559 {
+            access_list: access_list(inbox_access_list_item(&messages).storage_keys),
+            ..Default::default()
+        };
+        let tx = OpTxEnvelope::Eip1559(Signed::new_unhashed(tx, Signature::test_signature()));
+        let declared = declared_messages(&tx).unwrap();
+        assert_eq!(declared.len(), 1);
+        assert!(declared[0].matches(&messages[0]));
+
+        let tx = OpTxEnvelope::Legacy(Signed::new_unhashed(
+            TxLegacy::default(),
+            Signature::test_signature(),
+        ));
+        assert_eq!(declared_messages(&tx), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_parse_inbox_access_list_invalid() {
+        let [lookup, checksum] = message(1).access_list_entries();
+        let parse = |keys: Vec<B256>| parse_inbox_access_list(&access_list(keys));
+
+        assert_eq!(parse(vec![lookup]), Err(AccessListError::MissingChecksum));
+        assert_eq!(parse(vec![lookup, lookup]), Err(AccessListError::MissingChecksum));
+        assert_eq!(parse(vec![checksum]), Err(AccessListError::UnexpectedChecksum(checksum)));
+        assert_eq!(parse(vec![B256::ZERO]), Err(AccessListError::UnknownEntryType(0)));
+
+        let mut bad_lookup = lookup;
+        bad_lookup[2] = 1;
+        assert_eq!(
+            parse(vec![bad_lookup, checksum]),
+            Err(AccessListError::InvalidLookupEntry(bad_lookup))
+        );
+
+        let mut extension = B256::ZERO;
+        extension[0] = CHAIN_ID_EXTENSION_ENTRY_TYPE;
+        assert_eq!(parse(vec![extension]), Err(AccessListError::UnexpectedChainIdExtension));
+        assert_eq!(
+            parse(vec![lookup, extension, checksum]),
+            Err(AccessListError::InvalidChainIdExtension(extension))
+        );
+        extension[31] = 1;
+        assert_eq!(parse(vec![lookup, extension, checksum]), Err(AccessListError::ChainIdTooHigh));
+    }
+}
@@ -0,0 +1,354 @@
+//! Executing messages and the identifiers of the initiating messages they refer to.
+//!
+//! See also: <https://specs.optimism.io/interop/messaging.html>
+
+use crate::{OpReceipt, predeploys::CROSS_L2_INBOX_ADDRESS};
+use alloc::vec::Vec;
+use alloy_primitives::{Address, B256, Bytes, ChainId, Log, LogData, U256, b256, keccak256};
+
+/// The signature of the event emitted by the `CrossL2Inbox` for each executing message.
+pub const EXECUTING_MESSAGE_EVENT_ABI: &str =
+    "ExecutingMessage(bytes32,(address,uint256,uint256,uint256,uint256))";
+
+/// `keccak256("ExecutingMessage(bytes32,(address,uint256,uint256,uint256,uint256))")`
+pub const EXECUTING_MESSAGE_EVENT_ABI_HASH: B256 =
+    b256!("0x5c37832d2e8d10e346e55ad62071a6a2f9fa5130614ef2ec6617555c6f467ba7");
+
+/// The type byte of a checksum entry, see [`Identifier::checksum`].
+pub const CHECKSUM_ENTRY_TYPE: u8 = 0x03;
+
+/// The identifier of an initiating message, i.e. of a log emitted on some chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Identifier {
+    /// The address that emitted the log.
+    pub origin: Address,
+    /// The number of the block that contains the log.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub block_number: u64,
+    /// The index of the log in the block.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub log_index: u32,
+    /// The timestamp of the block that contains the log.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub timestamp: u64,
+    /// The chain ID of the chain that emitted the log.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity", rename = "chainID"))]
+    pub chain_id: ChainId,
+}
+
+impl Identifier {
+    /// The length of the ABI encoding of an identifier.
+    pub const ENCODED_LENGTH: usize = 5 * 32;
+
+    /// Returns the checksum that binds the identifier to the hash of the message payload, as
+    /// computed by `CrossL2Inbox.calculateChecksum`:
+    ///
+    /// ```text
+    /// logHash      = keccak256(origin ++ payloadHash)
+    /// idPacked     = 0 (12 bytes) ++ blockNumber (8 bytes) ++ timestamp (8 bytes) ++ logIndex (4 bytes)
+    /// idLogHash    = keccak256(logHash ++ idPacked)
+    /// bareChecksum = keccak256(idLogHash ++ chainId (32 bytes))
+    /// checksum     = 0x03 ++ bareChecksum[1..]
+    /// ```
+    pub fn checksum(&self, payload_hash: B256) -> B256 {
+        let mut log = [0u8; 52];
+        log[..20].copy_from_slice(self.origin.as_slice());
+        log[20..].copy_from_slice(payload_hash.as_slice());
+
+        let mut id_log = [0u8; 64];
+        id_log[..32].copy_from_slice(keccak256(log).as_slice());
+        id_log[44..52].copy_from_slice(&self.block_number.to_be_bytes());
+        id_log[52..60].copy_from_slice(&self.timestamp.to_be_bytes());
+        id_log[60..].copy_from_slice(&self.log_index.to_be_bytes());
+
+        let mut id_log_chain = [0u8; 64];
+        id_log_chain[..32].copy_from_slice(keccak256(id_log).as_slice());
+        id_log_chain[32..].copy_from_slice(&U256::from(self.chain_id).to_be_bytes::<32>());
+
+        let mut checksum = keccak256(id_log_chain);
+        checksum[0] = CHECKSUM_ENTRY_TYPE;
+        checksum
+    }
+
+    /// ABI encodes the identifier as the `(address,uint256,uint256,uint256,uint256)` tuple.
+    pub fn abi_encode(&self) -> [u8; Self::ENCODED_LENGTH] {
+        let mut encoded = [0u8; Self::ENCODED_LENGTH];
+        encoded[..32].copy_from_slice(self.origin.into_word().as_slice());
+        encoded[56..64].copy_from_slice(&self.block_number.to_be_bytes());
+        encoded[92..96].copy_from_slice(&self.log_index.to_be_bytes());
+        encoded[120..128].copy_from_slice(&self.timestamp.to_be_bytes());
+        encoded[152..].copy_from_slice(&self.chain_id.to_be_bytes());
+        encoded
+    }
+
+    /// Decodes an identifier from its ABI encoding, checking that each field fits in its type.
+    pub fn abi_decode(data: &[u8]) -> Result<Self, ExecutingMessageError> {
+        if data.len() != Self::ENCODED_LENGTH {
+            return Err(ExecutingMessageError::InvalidDataLength(data.len()));
+        }
+        let word = |i: usize| U256::from_be_slice(&data[i * 32..(i + 1) * 32]);
+        let origin = B256::from_slice(&data[..32]);
+        if origin[..12].iter().any(|b| *b != 0) {
+            return Err(ExecutingMessageError::InvalidOrigin(origin));
+        }
+        Ok(Self {
+            origin: Address::from_word(origin),
+            block_number: word(1)
+                .try_into()
+                .map_err(|_| ExecutingMessageError::BlockNumberTooHigh)?,
+            log_index: word(2).try_into().map_err(|_| ExecutingMessageError::LogIndexTooHigh)?,
+            timestamp: word(3).try_into().map_err(|_| ExecutingMessageError::TimestampTooHigh)?,
+            chain_id: word(4).try_into().map_err(|_| ExecutingMessageError::ChainIdTooHigh)?,
+        })
+    }
+}
+
+/// An executing message, as announced by the `ExecutingMessage` event of the `CrossL2Inbox`:
+///
+/// ```solidity
+/// event ExecutingMessage(bytes32 indexed msgHash, Identifier id);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ExecutingMessage {
+    /// The hash of the payload of the initiating message, see [`message_payload_hash`].
+    pub payload_hash: B256,
+    /// The identifier of the initiating message.
+    pub identifier: Identifier,
+}
+
+impl ExecutingMessage {
+    /// Creates a new executing message.
+    pub const fn new(payload_hash: B256, identifier: Identifier) -> Self {
+        Self { payload_hash, identifier }
+    }
+
+    /// Returns the checksum of the message, see [`Identifier::checksum`].
+    pub fn checksum(&self) -> B256 {
+        self.identifier.checksum(self.payload_hash)
+    }
+
+    /// Decodes an executing message from an `ExecutingMessage` log of the `CrossL2Inbox`.
+    pub fn decode_log(log: &Log) -> Result<Self, ExecutingMessageError> {
+        if log.address != CROSS_L2_INBOX_ADDRESS {
+            return Err(ExecutingMessageError::InvalidAddress(log.address));
+        }
+        let topics = log.topics();
+        if topics.len() != 2 {
+            return Err(ExecutingMessageError::UnexpectedTopicsLen(topics.len()));
+        }
+        if topics[0] != EXECUTING_MESSAGE_EVENT_ABI_HASH {
+            return Err(ExecutingMessageError::InvalidSelector(topics[0]));
+        }
+        Ok(Self::new(topics[1], Identifier::abi_decode(&log.data.data)?))
+    }
+
+    /// Decodes all the executing messages of a transaction from its receipt.
+    ///
+    /// Logs that are not `ExecutingMessage` events of the `CrossL2Inbox` are skipped.
+    pub fn from_receipt(receipt: &OpReceipt) -> Result<Vec<Self>, ExecutingMessageError> {
+        receipt
+            .as_receipt()
+            .logs
+            .iter()
+            .filter(|log| {
+                log.address == CROSS_L2_INBOX_ADDRESS
+                    && log.topics().first() == Some(&EXECUTING_MESSAGE_EVENT_ABI_HASH)
+            })
+            .map(Self::decode_log)
+            .collect()
+    }
+
+    /// Encodes the executing message into the `ExecutingMessage` log that the `CrossL2Inbox`
+    /// emits for it.
+    ///
+    /// This is the inverse of [`ExecutingMessage::decode_log`].
+    pub fn to_log(&self) -> Log {
+        Log {
+            address: CROSS_L2_INBOX_ADDRESS,
+            data: LogData::new_unchecked(
+                alloc::vec![EXECUTING_MESSAGE_EVENT_ABI_HASH, self.payload_hash],
+                Bytes::copy_from_slice(&self.identifier.abi_encode()),
+            ),
+        }
+    }
+}
+
+/// Returns the payload of the initiating message emitted as `log`: its topics followed by its
+/// data.
+pub fn message_payload(log: &Log) -> Bytes {
+    let topics = log.topics();
+    let mut payload = Vec::with_capacity(topics.len() * 32 + log.data.data.len());
+    for topic in topics {
+        payload.extend_from_slice(topic.as_slice());
+    }
+    payload.extend_from_slice(&log.data.data);
+    payload.into()
+}
+
+/// Returns the hash of the payload of the initiating message emitted as `log`, see
+/// [`message_payload`].
+pub fn message_payload_hash(log: &Log) -> B256 {
+    keccak256(message_payload(log))
+}
+
+/// Errors that can occur when decoding an `ExecutingMessage` log.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutingMessageError {
+    /// The log was not emitted by the `CrossL2Inbox`.
+    #[error("Log not emitted by the CrossL2Inbox: {0}")]
+    InvalidAddress(Address),
+    /// The log does not have exactly two topics.
+    #[error("Unexpected number of ExecutingMessage event topics: {0}")]
+    UnexpectedTopicsLen(usize),
+    /// The first topic is not the `ExecutingMessage` event selector.
+    #[error("Invalid ExecutingMessage event selector: {0}")]
+    InvalidSelector(B256),
+    /// The event data is not the encoding of an identifier.
+    #[error("Invalid ExecutingMessage event data length: {0}")]
+    InvalidDataLength(usize),
+    /// The origin is not a padded address.
+    #[error("Invalid identifier origin: {0}")]
+    InvalidOrigin(B256),
+    /// The block number does not fit in 64 bits.
+    #[error("Identifier block number overflows u64")]
+    BlockNumberTooHigh,
+    /// The log index does not fit in 32 bits.
+    #[error("Identifier log index overflows u32")]
+    LogIndexTooHigh,
+    /// The timestamp does not fit in 64 bits.
+    #[error("Identifier timestamp overflows u64")]
+    TimestampTooHigh,
+    /// The chain ID does not fit in 64 bits.
+    #[error("Identifier chain ID overflows u64")]
+    ChainIdTooHigh,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{Eip658Value, Receipt};
+    use alloy_primitives::{address, hex};
+
+    fn identifier() -> Identifier {
+        Identifier {
+            origin: address!("0x4200000000000000000000000000000000000023"),
+            block_number: 0x0102_0304,
+            log_index: 7,
+            timestamp: 0x6500_0000,
+            chain_id: 10,
+        }
+    }
+
+    #[test]
+    fn test_executing_message_event_abi_hash() {
+        assert_eq!(keccak256(EXECUTING_MESSAGE_EVENT_ABI), EXECUTING_MESSAGE_EVENT_ABI_HASH);
+    }
+
+    #[test]
+    fn test_identifier_checksum() {
+        let id = identifier();
+        let payload_hash = B256::repeat_byte(0xaa);
+
+        let log_hash = keccak256([id.origin.as_slice(), payload_hash.as_slice()].concat());
+        let id_packed = [
+            &[0; 12][..],
+            &id.block_number.to_be_bytes(),
+            &id.timestamp.to_be_bytes(),
+            &id.log_index.to_be_bytes(),
+        ]
+        .concat();
+        let id_log_hash = keccak256([log_hash.as_slice(), &id_packed].concat());
+        let bare_checksum = keccak256([id_log_hash.as_slice(), &[0; 31], &[10]].concat());
+
+        let checksum = id.checksum(payload_hash);
+        assert_eq!(checksum[0], CHECKSUM_ENTRY_TYPE);
+        assert_eq!(checksum[1..], bare_checksum[1..]);
+        assert_eq!(ExecutingMessage::new(payload_hash, id).checksum(), checksum);
+    }
+
+    #[test]
+    fn test_executing_message_log_roundtrip() {
+        let message = ExecutingMessage::new(B256::repeat_byte(1), identifier());
+        let log = message.to_log();
+        assert_eq!(log.data.data.len(), Identifier::ENCODED_LENGTH);
+        assert_eq!(ExecutingMessage::decode_log(&log), Ok(message));
+
+        let receipt = OpReceipt::Eip1559(Receipt {
+            status: Eip658Value::Eip658(true),
+            cumulative_gas_used: 100_000,
+            logs: alloc::vec![Log { address: Address::ZERO, ..log.clone() }, log],
+        });
+        assert_eq!(ExecutingMessage::from_receipt(&receipt), Ok(alloc::vec![message]));
+    }
+
+    #[test]
+    fn test_decode_executing_message_invalid() {
+        let log = ExecutingMessage::new(B256::ZERO, identifier()).to_log();
+        let with_data = |data: Vec<u8>| Log {
+            address: log.address,
+            data: LogData::new_unchecked(log.topics().to_vec(), data.into()),
+        };
+        let data = log.data.data.to_vec();
+
+        let err = ExecutingMessage::decode_log(&with_data(data[..128].to_vec()));
+        assert_eq!(err, Err(ExecutingMessageError::InvalidDataLength(128)));
+
+        let mut bad_origin = data.clone();
+        bad_origin[0] = 1;
+        let origin = B256::from_slice(&bad_origin[..32]);
+        let err = ExecutingMessage::decode_log(&with_data(bad_origin));
+        assert_eq!(err, Err(ExecutingMessageError::InvalidOrigin(origin)));
+
+        for (index, err) in [
+            (32, ExecutingMessageError::BlockNumberTooHigh),
+            (64, ExecutingMessageError::LogIndexTooHigh),
+            (96, ExecutingMessageError::TimestampTooHigh),
+            (128, ExecutingMessageError::ChainIdTooHigh),
+        ] {
+            let mut data = data.clone();
+            data[index] = 1;
+            assert_eq!(ExecutingMessage::decode_log(&with_data(data)), Err(err));
+        }
+    }
+
+    #[test]
+    fn test_message_payload() {
+        let log = Log {
+            address: Address::ZERO,
+            data: LogData::new_unchecked(
+                alloc::vec![B256::repeat_byte(1), B256::repeat_byte(2)],
+                Bytes::from_static(&hex!("cafe")),
+            ),
+        };
+        let payload = message_payload(&log);
+        assert_eq!(payload.len(), 66);
+        assert_eq!(payload[..32], [1; 32]);
+        assert_eq!(payload[64..], hex!("cafe"));
+        assert_eq!(message_payload_hash(&log), keccak256(&payload));
+    }
+
+    #[cfg(feature = "bindings")]
+    #[test]
+    fn test_executing_message_matches_bindings() {
+        use crate::predeploys::bindings::CrossL2Inbox;
+        use alloy_sol_types::SolEvent;
+
+        let message = ExecutingMessage::new(B256::repeat_byte(1), identifier());
+        let id = message.identifier;
+        let event = CrossL2Inbox::ExecutingMessage {
+            msgHash: message.payload_hash,
+            id: CrossL2Inbox::Identifier {
+                origin: id.origin,
+                blockNumber: U256::from(id.block_number),
+                logIndex: U256::from(id.log_index),
+                timestamp: U256::from(id.timestamp),
+                chainId: U256::from(id.chain_id),
+            },
+        };
+        assert_eq!(event.encode_log_data(), message.to_log().data);
+    }
+}
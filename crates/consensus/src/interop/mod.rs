@@ -1,12 +1,25 @@
 //! Commonly used types for interop.
 
 use alloc::string::{String, ToString};
-use alloy_primitives::{Address, address};
 use core::str::FromStr;
 use derive_more::Display;
 
-/// The address of the L2 cross chain inbox predeploy proxy.
-pub const CROSS_L2_INBOX_ADDRESS: Address = address!("0x4200000000000000000000000000000000000022");
+pub use crate::predeploys::CROSS_L2_INBOX_ADDRESS;
+
+mod access_list;
+pub use access_list::{
+    AccessListError, CHAIN_ID_EXTENSION_ENTRY_TYPE, DeclaredMessage, LOOKUP_ENTRY_TYPE,
+    declared_messages, inbox_access_list_item, parse_inbox_access_list,
+};
+
+mod message;
+pub use message::{
+    CHECKSUM_ENTRY_TYPE, EXECUTING_MESSAGE_EVENT_ABI, EXECUTING_MESSAGE_EVENT_ABI_HASH,
+    ExecutingMessage, ExecutingMessageError, Identifier, message_payload, message_payload_hash,
+};
+
+mod super_root;
+pub use super_root::{ChainOutputRoot, SUPER_ROOT_VERSION, SuperRoot, SuperRootError};
 
 /// The safety level of a message.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
@@ -0,0 +1,239 @@
+//! Super roots, the commitments to the state of all the chains of a dependency set.
+//!
+//! See also: <https://specs.optimism.io/interop/fault-proof.html#super-root>
+
+use alloc::vec::Vec;
+use alloy_primitives::{B256, U256, keccak256};
+
+/// The version of the super root encoding, the only one in use.
+pub const SUPER_ROOT_VERSION: u8 = 1;
+
+/// The output root of a chain at the timestamp of a [`SuperRoot`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ChainOutputRoot {
+    /// The chain ID.
+    pub chain_id: u64,
+    /// The output root of the last block of the chain at or before the timestamp.
+    pub output_root: B256,
+}
+
+impl ChainOutputRoot {
+    /// Creates a new chain output root.
+    pub const fn new(chain_id: u64, output_root: B256) -> Self {
+        Self { chain_id, output_root }
+    }
+}
+
+/// A snapshot of the output roots of all the chains of a dependency set at a given timestamp.
+///
+/// The output roots are ordered by strictly increasing chain ID. The version 1 encoding is:
+///
+/// `version (1 byte) ++ timestamp (8 bytes) ++ [chain_id (32 bytes) ++ output_root (32 bytes)]*`
+///
+/// and the super root is the hash of this encoding.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct SuperRoot {
+    /// The timestamp of the snapshot, in seconds.
+    pub timestamp: u64,
+    /// The output roots of the chains, ordered by chain ID.
+    pub output_roots: Vec<ChainOutputRoot>,
+}
+
+impl SuperRoot {
+    /// The length of the encoding of a super root without any output root.
+    const HEADER_LEN: usize = 1 + 8;
+
+    /// The length of the encoding of a single output root.
+    const OUTPUT_ROOT_LEN: usize = 64;
+
+    /// Creates a new super root, checking that the output roots are ordered by strictly increasing
+    /// chain ID.
+    pub fn new(timestamp: u64, output_roots: Vec<ChainOutputRoot>) -> Result<Self, SuperRootError> {
+        let super_root = Self { timestamp, output_roots };
+        super_root.validate()?;
+        Ok(super_root)
+    }
+
+    /// Creates a new super root from output roots in any order.
+    ///
+    /// The output roots are sorted by chain ID. Duplicate chain IDs are still rejected.
+    pub fn from_unsorted(
+        timestamp: u64,
+        mut output_roots: Vec<ChainOutputRoot>,
+    ) -> Result<Self, SuperRootError> {
+        output_roots.sort_by_key(|root| root.chain_id);
+        Self::new(timestamp, output_roots)
+    }
+
+    /// Checks that the output roots are ordered by strictly increasing chain ID.
+    pub fn validate(&self) -> Result<(), SuperRootError> {
+        for pair in self.output_roots.windows(2) {
+            let (previous, chain_id) = (pair[0].chain_id, pair[1].chain_id);
+            if chain_id == previous {
+                return Err(SuperRootError::DuplicateChainId(chain_id));
+            }
+            if chain_id < previous {
+                return Err(SuperRootError::UnorderedChainId { previous, chain_id });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the output root of `chain_id`, if it is part of the super root.
+    pub fn output_root(&self, chain_id: u64) -> Option<B256> {
+        self.output_roots
+            .binary_search_by_key(&chain_id, |root| root.chain_id)
+            .ok()
+            .map(|index| self.output_roots[index].output_root)
+    }
+
+    /// Returns the length of the encoded super root.
+    pub const fn encoded_len(&self) -> usize {
+        Self::HEADER_LEN + Self::OUTPUT_ROOT_LEN * self.output_roots.len()
+    }
+
+    /// Encodes the super root.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut encoded = Vec::with_capacity(self.encoded_len());
+        encoded.push(SUPER_ROOT_VERSION);
+        encoded.extend_from_slice(&self.timestamp.to_be_bytes());
+        for root in &self.output_roots {
+            encoded.extend_from_slice(&U256::from(root.chain_id).to_be_bytes::<32>());
+            encoded.extend_from_slice(root.output_root.as_slice());
+        }
+        encoded
+    }
+
+    /// Decodes a super root, checking the ordering of its output roots.
+    pub fn decode(data: &[u8]) -> Result<Self, SuperRootError> {
+        let Some((&version, rest)) = data.split_first() else {
+            return Err(SuperRootError::InvalidLength(0));
+        };
+        if version != SUPER_ROOT_VERSION {
+            return Err(SuperRootError::UnsupportedVersion(version));
+        }
+        if rest.len() < 8 || !(rest.len() - 8).is_multiple_of(Self::OUTPUT_ROOT_LEN) {
+            return Err(SuperRootError::InvalidLength(data.len()));
+        }
+
+        let (timestamp, rest) = rest.split_at(8);
+        let output_roots = rest
+            .chunks_exact(Self::OUTPUT_ROOT_LEN)
+            .map(|chunk| {
+                let chain_id = U256::from_be_slice(&chunk[..32]);
+                Ok(ChainOutputRoot {
+                    chain_id: chain_id
+                        .try_into()
+                        .map_err(|_| SuperRootError::ChainIdOverflow(chain_id))?,
+                    output_root: B256::from_slice(&chunk[32..]),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Self::new(u64::from_be_bytes(timestamp.try_into().unwrap()), output_roots)
+    }
+
+    /// Returns the super root, i.e. the hash of the encoding.
+    pub fn hash(&self) -> B256 {
+        keccak256(self.encode())
+    }
+}
+
+/// Errors that can occur when creating or decoding a super root.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum SuperRootError {
+    /// The encoded super root does not hold a timestamp and a whole number of output roots.
+    #[error("Invalid super root length: {0}")]
+    InvalidLength(usize),
+    /// The super root version is not supported.
+    #[error("Unsupported super root version: {0}")]
+    UnsupportedVersion(u8),
+    /// An encoded chain ID does not fit in 64 bits.
+    #[error("Super root chain ID overflows u64: {0}")]
+    ChainIdOverflow(U256),
+    /// The output roots are not ordered by chain ID.
+    #[error("Super root chain ID {chain_id} follows chain ID {previous}")]
+    UnorderedChainId {
+        /// The chain ID of the previous output root.
+        previous: u64,
+        /// The chain ID that is out of order.
+        chain_id: u64,
+    },
+    /// Two output roots have the same chain ID.
+    #[error("Duplicate super root chain ID: {0}")]
+    DuplicateChainId(u64),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use alloy_primitives::b256;
+
+    fn super_root() -> SuperRoot {
+        SuperRoot::new(
+            10,
+            vec![ChainOutputRoot::new(1, B256::ZERO), ChainOutputRoot::new(2, B256::ZERO)],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_super_root_hash() {
+        let root = super_root();
+        let encoded = root.encode();
+        assert_eq!(encoded.len(), root.encoded_len());
+        assert_eq!(encoded[..9], [1, 0, 0, 0, 0, 0, 0, 0, 10]);
+        assert_eq!(SuperRoot::decode(&encoded), Ok(root.clone()));
+        assert_eq!(
+            root.hash(),
+            b256!("0x0980033cbf4337f614a2401ab7efbfdc66ab647812f1c98d891d92ddfb376541")
+        );
+        assert_eq!(root.output_root(2), Some(B256::ZERO));
+        assert_eq!(root.output_root(3), None);
+    }
+
+    #[test]
+    fn test_super_root_ordering() {
+        let roots = vec![ChainOutputRoot::new(2, B256::ZERO), ChainOutputRoot::new(1, B256::ZERO)];
+        assert_eq!(
+            SuperRoot::new(10, roots.clone()),
+            Err(SuperRootError::UnorderedChainId { previous: 2, chain_id: 1 })
+        );
+        assert_eq!(SuperRoot::from_unsorted(10, roots), Ok(super_root()));
+
+        let roots = vec![ChainOutputRoot::new(1, B256::ZERO), ChainOutputRoot::new(1, B256::ZERO)];
+        assert_eq!(SuperRoot::from_unsorted(10, roots), Err(SuperRootError::DuplicateChainId(1)));
+    }
+
+    #[test]
+    fn test_decode_super_root_invalid() {
+        assert_eq!(SuperRoot::decode(&[]), Err(SuperRootError::InvalidLength(0)));
+        assert_eq!(SuperRoot::decode(&[0xff]), Err(SuperRootError::UnsupportedVersion(0xff)));
+        assert_eq!(SuperRoot::decode(&[1, 0]), Err(SuperRootError::InvalidLength(2)));
+
+        let encoded = super_root().encode();
+        assert_eq!(
+            SuperRoot::decode(&encoded[..encoded.len() - 1]),
+            Err(SuperRootError::InvalidLength(encoded.len() - 1))
+        );
+
+        let mut overflow = encoded.clone();
+        overflow[9] = 1;
+        assert_eq!(
+            SuperRoot::decode(&overflow),
+            Err(SuperRootError::ChainIdOverflow(U256::from(1) << 248 | U256::from(1)))
+        );
+
+        // Swap the two output roots.
+        let swapped = [&encoded[..9], &encoded[73..], &encoded[9..73]].concat();
+        assert_eq!(
+            SuperRoot::decode(&swapped),
+            Err(SuperRootError::UnorderedChainId { previous: 2, chain_id: 1 })
+        );
+    }
+}
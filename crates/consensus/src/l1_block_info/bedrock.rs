@@ -0,0 +1,156 @@
+//! Bedrock L1 block info transaction calldata.
+
+use super::{L1BlockInfoError, read_address_word, read_u64_word};
+use alloc::vec::Vec;
+use alloy_primitives::{Address, B256, Bytes, U256};
+
+/// The fields of a Bedrock `setL1BlockValues` call.
+///
+/// Bedrock calldata is regular ABI encoding, with every argument padded to a 32 byte word:
+///
+/// ```text
+/// +---------+--------------------------+
+/// | Bytes   | Field                    |
+/// +---------+--------------------------+
+/// | 4       | Function selector        |
+/// | 32      | Number                   |
+/// | 32      | Time                     |
+/// | 32      | BaseFee                  |
+/// | 32      | BlockHash                |
+/// | 32      | SequenceNumber           |
+/// | 32      | BatcherHash              |
+/// | 32      | L1FeeOverhead            |
+/// | 32      | L1FeeScalar              |
+/// +---------+--------------------------+
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct L1BlockInfoBedrock {
+    /// The L1 origin block number.
+    pub number: u64,
+    /// The L1 origin block timestamp.
+    pub time: u64,
+    /// The L1 origin block base fee.
+    pub base_fee: U256,
+    /// The L1 origin block hash.
+    pub block_hash: B256,
+    /// The sequence number of the L2 block within the epoch.
+    pub sequence_number: u64,
+    /// The address of the batch submitter.
+    pub batcher_address: Address,
+    /// The L1 fee overhead.
+    pub l1_fee_overhead: U256,
+    /// The L1 fee scalar.
+    pub l1_fee_scalar: U256,
+}
+
+impl L1BlockInfoBedrock {
+    /// The 4 byte selector of
+    /// `setL1BlockValues(uint64,uint64,uint256,bytes32,uint64,bytes32,uint256,uint256)`.
+    pub const SELECTOR: [u8; 4] = [0x01, 0x5d, 0x8e, 0xb9];
+
+    /// The length of the calldata.
+    pub const CALLDATA_LEN: usize = 4 + 32 * 8;
+
+    /// Encodes the fields into `setL1BlockValues` calldata.
+    pub fn encode_calldata(&self) -> Bytes {
+        let mut buf = Vec::with_capacity(Self::CALLDATA_LEN);
+        buf.extend_from_slice(&Self::SELECTOR);
+        buf.extend_from_slice(&U256::from(self.number).to_be_bytes::<32>());
+        buf.extend_from_slice(&U256::from(self.time).to_be_bytes::<32>());
+        buf.extend_from_slice(&self.base_fee.to_be_bytes::<32>());
+        buf.extend_from_slice(self.block_hash.as_slice());
+        buf.extend_from_slice(&U256::from(self.sequence_number).to_be_bytes::<32>());
+        buf.extend_from_slice(self.batcher_address.into_word().as_slice());
+        buf.extend_from_slice(&self.l1_fee_overhead.to_be_bytes::<32>());
+        buf.extend_from_slice(&self.l1_fee_scalar.to_be_bytes::<32>());
+        buf.into()
+    }
+
+    /// Decodes the fields from `setL1BlockValues` calldata, including the selector.
+    pub fn decode_calldata(data: &[u8]) -> Result<Self, L1BlockInfoError> {
+        if data.len() != Self::CALLDATA_LEN {
+            return Err(L1BlockInfoError::InvalidLength {
+                expected: Self::CALLDATA_LEN,
+                got: data.len(),
+            });
+        }
+        if data[..4] != Self::SELECTOR {
+            return Err(L1BlockInfoError::InvalidSelector(data[..4].try_into().unwrap()));
+        }
+
+        let word = |i: usize| &data[4 + 32 * i..4 + 32 * (i + 1)];
+        Ok(Self {
+            number: read_u64_word(word(0))?,
+            time: read_u64_word(word(1))?,
+            base_fee: U256::from_be_slice(word(2)),
+            block_hash: B256::from_slice(word(3)),
+            sequence_number: read_u64_word(word(4))?,
+            batcher_address: read_address_word(word(5))?,
+            l1_fee_overhead: U256::from_be_slice(word(6)),
+            l1_fee_scalar: U256::from_be_slice(word(7)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256, hex};
+
+    // Calldata of the pre-Regolith L1 info deposit used in the `TxDeposit` RLP tests.
+    const CALLDATA: [u8; L1BlockInfoBedrock::CALLDATA_LEN] = hex!(
+        "015d8eb9"
+        "0000000000000000000000000000000000000000000000000000000000805765"
+        "0000000000000000000000000000000000000000000000000000000063d96d10"
+        "000000000000000000000000000000000000000000000000000000000009f352"
+        "73d89754a1e0387b89520d989d3be9c37c1f32495a88faf1ea05c61121ab0d19"
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0000000000000000000000002d679b567db6187c0c8323fa982cfb88b74dbcc7"
+        "0000000000000000000000000000000000000000000000000000000000000834"
+        "00000000000000000000000000000000000000000000000000000000000f4240"
+    );
+
+    #[test]
+    fn test_decode_bedrock_calldata() {
+        let info = L1BlockInfoBedrock::decode_calldata(&CALLDATA).unwrap();
+        assert_eq!(
+            info,
+            L1BlockInfoBedrock {
+                number: 0x805765,
+                time: 0x63d96d10,
+                base_fee: U256::from(0x9f352),
+                block_hash: b256!(
+                    "0x73d89754a1e0387b89520d989d3be9c37c1f32495a88faf1ea05c61121ab0d19"
+                ),
+                sequence_number: 1,
+                batcher_address: address!("0x2d679b567db6187c0c8323fa982cfb88b74dbcc7"),
+                l1_fee_overhead: U256::from(2100),
+                l1_fee_scalar: U256::from(1_000_000),
+            }
+        );
+        assert_eq!(info.encode_calldata()[..], CALLDATA[..]);
+    }
+
+    #[test]
+    fn test_decode_bedrock_invalid_length() {
+        let err = L1BlockInfoBedrock::decode_calldata(&CALLDATA[..100]).unwrap_err();
+        assert_eq!(
+            err,
+            L1BlockInfoError::InvalidLength {
+                expected: L1BlockInfoBedrock::CALLDATA_LEN,
+                got: 100
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_bedrock_dirty_padding() {
+        let mut calldata = CALLDATA;
+        // Set a bit in the padding of the `number` word.
+        calldata[4] = 1;
+        let err = L1BlockInfoBedrock::decode_calldata(&calldata).unwrap_err();
+        assert_eq!(err, L1BlockInfoError::InvalidPadding);
+    }
+}
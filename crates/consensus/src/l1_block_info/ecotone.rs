@@ -0,0 +1,155 @@
+//! Ecotone L1 block info transaction calldata.
+
+use super::{L1BlockInfoError, read_address_word};
+use alloc::vec::Vec;
+use alloy_primitives::{Address, B256, Bytes, U256};
+
+/// The fields of an Ecotone `setL1BlockValuesEcotone` call.
+///
+/// From Ecotone onwards, the calldata is tightly packed instead of ABI encoded:
+///
+/// ```text
+/// +---------+--------------------------+
+/// | Bytes   | Field                    |
+/// +---------+--------------------------+
+/// | 4       | Function selector        |
+/// | 4       | BaseFeeScalar            |
+/// | 4       | BlobBaseFeeScalar        |
+/// | 8       | SequenceNumber           |
+/// | 8       | Timestamp                |
+/// | 8       | L1BlockNumber            |
+/// | 32      | BaseFee                  |
+/// | 32      | BlobBaseFee              |
+/// | 32      | BlockHash                |
+/// | 32      | BatcherHash              |
+/// +---------+--------------------------+
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct L1BlockInfoEcotone {
+    /// The L1 origin block number.
+    pub number: u64,
+    /// The L1 origin block timestamp.
+    pub time: u64,
+    /// The L1 origin block base fee.
+    pub base_fee: U256,
+    /// The L1 origin block hash.
+    pub block_hash: B256,
+    /// The sequence number of the L2 block within the epoch.
+    pub sequence_number: u64,
+    /// The address of the batch submitter.
+    pub batcher_address: Address,
+    /// The L1 origin block blob base fee.
+    pub blob_base_fee: U256,
+    /// The scalar applied to the L1 blob base fee.
+    pub blob_base_fee_scalar: u32,
+    /// The scalar applied to the L1 base fee.
+    pub base_fee_scalar: u32,
+}
+
+impl L1BlockInfoEcotone {
+    /// The 4 byte selector of `setL1BlockValuesEcotone()`.
+    pub const SELECTOR: [u8; 4] = [0x44, 0x0a, 0x5e, 0x20];
+
+    /// The length of the calldata.
+    pub const CALLDATA_LEN: usize = 4 + 4 + 4 + 8 + 8 + 8 + 32 * 4;
+
+    /// Encodes the fields into `setL1BlockValuesEcotone` calldata.
+    pub fn encode_calldata(&self) -> Bytes {
+        let mut buf = Vec::with_capacity(Self::CALLDATA_LEN);
+        buf.extend_from_slice(&Self::SELECTOR);
+        self.encode_packed(&mut buf);
+        buf.into()
+    }
+
+    /// Decodes the fields from `setL1BlockValuesEcotone` calldata, including the selector.
+    pub fn decode_calldata(data: &[u8]) -> Result<Self, L1BlockInfoError> {
+        if data.len() != Self::CALLDATA_LEN {
+            return Err(L1BlockInfoError::InvalidLength {
+                expected: Self::CALLDATA_LEN,
+                got: data.len(),
+            });
+        }
+        if data[..4] != Self::SELECTOR {
+            return Err(L1BlockInfoError::InvalidSelector(data[..4].try_into().unwrap()));
+        }
+        Self::decode_packed(&data[4..])
+    }
+
+    /// Appends the packed fields, without a selector, to `buf`.
+    ///
+    /// The Ecotone layout is the prefix of every later format.
+    pub(super) fn encode_packed(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.base_fee_scalar.to_be_bytes());
+        buf.extend_from_slice(&self.blob_base_fee_scalar.to_be_bytes());
+        buf.extend_from_slice(&self.sequence_number.to_be_bytes());
+        buf.extend_from_slice(&self.time.to_be_bytes());
+        buf.extend_from_slice(&self.number.to_be_bytes());
+        buf.extend_from_slice(&self.base_fee.to_be_bytes::<32>());
+        buf.extend_from_slice(&self.blob_base_fee.to_be_bytes::<32>());
+        buf.extend_from_slice(self.block_hash.as_slice());
+        buf.extend_from_slice(self.batcher_address.into_word().as_slice());
+    }
+
+    /// Decodes the packed fields from `data`, which must hold at least
+    /// `CALLDATA_LEN - 4` bytes and must not include the selector.
+    pub(super) fn decode_packed(data: &[u8]) -> Result<Self, L1BlockInfoError> {
+        Ok(Self {
+            base_fee_scalar: u32::from_be_bytes(data[0..4].try_into().unwrap()),
+            blob_base_fee_scalar: u32::from_be_bytes(data[4..8].try_into().unwrap()),
+            sequence_number: u64::from_be_bytes(data[8..16].try_into().unwrap()),
+            time: u64::from_be_bytes(data[16..24].try_into().unwrap()),
+            number: u64::from_be_bytes(data[24..32].try_into().unwrap()),
+            base_fee: U256::from_be_slice(&data[32..64]),
+            blob_base_fee: U256::from_be_slice(&data[64..96]),
+            block_hash: B256::from_slice(&data[96..128]),
+            batcher_address: read_address_word(&data[128..160])?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256, hex};
+
+    // Calldata of the Ecotone L1 info deposit used in the `TxDeposit` roundtrip tests.
+    const CALLDATA: [u8; L1BlockInfoEcotone::CALLDATA_LEN] = hex!(
+        "440a5e20000008dd00101c12000000000000000400000000681c941f0000000001566261"
+        "000000000000000000000000000000000000000000000000000000005f629c02"
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "937badfbcce566e0ba932a3f7659644aa0c6ef019541d3134a1d8cb9f84d45c7"
+        "0000000000000000000000005050f69a9786f081509234f1a7f4684b5e5b76c9"
+    );
+
+    #[test]
+    fn test_decode_ecotone_calldata() {
+        let info = L1BlockInfoEcotone::decode_calldata(&CALLDATA).unwrap();
+        assert_eq!(
+            info,
+            L1BlockInfoEcotone {
+                number: 0x1566261,
+                time: 0x681c941f,
+                base_fee: U256::from(0x5f629c02),
+                block_hash: b256!(
+                    "0x937badfbcce566e0ba932a3f7659644aa0c6ef019541d3134a1d8cb9f84d45c7"
+                ),
+                sequence_number: 4,
+                batcher_address: address!("0x5050f69a9786f081509234f1a7f4684b5e5b76c9"),
+                blob_base_fee: U256::from(1),
+                blob_base_fee_scalar: 0x101c12,
+                base_fee_scalar: 0x8dd,
+            }
+        );
+        assert_eq!(info.encode_calldata()[..], CALLDATA[..]);
+    }
+
+    #[test]
+    fn test_decode_ecotone_invalid_selector() {
+        let mut calldata = CALLDATA;
+        calldata[0] = 0;
+        let err = L1BlockInfoEcotone::decode_calldata(&calldata).unwrap_err();
+        assert_eq!(err, L1BlockInfoError::InvalidSelector([0x00, 0x0a, 0x5e, 0x20]));
+    }
+}
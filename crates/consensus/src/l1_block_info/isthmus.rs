@@ -0,0 +1,161 @@
+//! Isthmus L1 block info transaction calldata.
+
+use super::{L1BlockInfoEcotone, L1BlockInfoError};
+use alloc::vec::Vec;
+use alloy_primitives::{Address, B256, Bytes, U256};
+
+/// The fields of an Isthmus `setL1BlockValuesIsthmus` call.
+///
+/// Isthmus appends the operator fee parameters to the packed Ecotone layout:
+///
+/// ```text
+/// +---------+--------------------------+
+/// | Bytes   | Field                    |
+/// +---------+--------------------------+
+/// | 4       | Function selector        |
+/// | 160     | Ecotone fields           |
+/// | 4       | OperatorFeeScalar        |
+/// | 8       | OperatorFeeConstant      |
+/// +---------+--------------------------+
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct L1BlockInfoIsthmus {
+    /// The L1 origin block number.
+    pub number: u64,
+    /// The L1 origin block timestamp.
+    pub time: u64,
+    /// The L1 origin block base fee.
+    pub base_fee: U256,
+    /// The L1 origin block hash.
+    pub block_hash: B256,
+    /// The sequence number of the L2 block within the epoch.
+    pub sequence_number: u64,
+    /// The address of the batch submitter.
+    pub batcher_address: Address,
+    /// The L1 origin block blob base fee.
+    pub blob_base_fee: U256,
+    /// The scalar applied to the L1 blob base fee.
+    pub blob_base_fee_scalar: u32,
+    /// The scalar applied to the L1 base fee.
+    pub base_fee_scalar: u32,
+    /// The operator fee scalar.
+    pub operator_fee_scalar: u32,
+    /// The operator fee constant.
+    pub operator_fee_constant: u64,
+}
+
+impl L1BlockInfoIsthmus {
+    /// The 4 byte selector of `setL1BlockValuesIsthmus()`.
+    pub const SELECTOR: [u8; 4] = [0x09, 0x89, 0x99, 0xbe];
+
+    /// The length of the calldata.
+    pub const CALLDATA_LEN: usize = L1BlockInfoEcotone::CALLDATA_LEN + 4 + 8;
+
+    /// Encodes the fields into `setL1BlockValuesIsthmus` calldata.
+    pub fn encode_calldata(&self) -> Bytes {
+        let mut buf = Vec::with_capacity(Self::CALLDATA_LEN);
+        buf.extend_from_slice(&Self::SELECTOR);
+        self.encode_packed(&mut buf);
+        buf.into()
+    }
+
+    /// Decodes the fields from `setL1BlockValuesIsthmus` calldata, including the selector.
+    pub fn decode_calldata(data: &[u8]) -> Result<Self, L1BlockInfoError> {
+        if data.len() != Self::CALLDATA_LEN {
+            return Err(L1BlockInfoError::InvalidLength {
+                expected: Self::CALLDATA_LEN,
+                got: data.len(),
+            });
+        }
+        if data[..4] != Self::SELECTOR {
+            return Err(L1BlockInfoError::InvalidSelector(data[..4].try_into().unwrap()));
+        }
+        Self::decode_packed(&data[4..])
+    }
+
+    /// Appends the packed fields, without a selector, to `buf`.
+    pub(super) fn encode_packed(&self, buf: &mut Vec<u8>) {
+        self.as_ecotone().encode_packed(buf);
+        buf.extend_from_slice(&self.operator_fee_scalar.to_be_bytes());
+        buf.extend_from_slice(&self.operator_fee_constant.to_be_bytes());
+    }
+
+    /// Decodes the packed fields from `data`, which must hold at least
+    /// `CALLDATA_LEN - 4` bytes and must not include the selector.
+    pub(super) fn decode_packed(data: &[u8]) -> Result<Self, L1BlockInfoError> {
+        let ecotone = L1BlockInfoEcotone::decode_packed(data)?;
+        Ok(Self {
+            number: ecotone.number,
+            time: ecotone.time,
+            base_fee: ecotone.base_fee,
+            block_hash: ecotone.block_hash,
+            sequence_number: ecotone.sequence_number,
+            batcher_address: ecotone.batcher_address,
+            blob_base_fee: ecotone.blob_base_fee,
+            blob_base_fee_scalar: ecotone.blob_base_fee_scalar,
+            base_fee_scalar: ecotone.base_fee_scalar,
+            operator_fee_scalar: u32::from_be_bytes(data[160..164].try_into().unwrap()),
+            operator_fee_constant: u64::from_be_bytes(data[164..172].try_into().unwrap()),
+        })
+    }
+
+    /// Returns the Ecotone subset of the fields.
+    const fn as_ecotone(&self) -> L1BlockInfoEcotone {
+        L1BlockInfoEcotone {
+            number: self.number,
+            time: self.time,
+            base_fee: self.base_fee,
+            block_hash: self.block_hash,
+            sequence_number: self.sequence_number,
+            batcher_address: self.batcher_address,
+            blob_base_fee: self.blob_base_fee,
+            blob_base_fee_scalar: self.blob_base_fee_scalar,
+            base_fee_scalar: self.base_fee_scalar,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::hex;
+
+    #[test]
+    fn test_isthmus_calldata_roundtrip() {
+        let info = L1BlockInfoIsthmus {
+            number: 1,
+            time: 2,
+            base_fee: U256::from(3),
+            block_hash: B256::repeat_byte(4),
+            sequence_number: 5,
+            batcher_address: Address::repeat_byte(6),
+            blob_base_fee: U256::from(7),
+            blob_base_fee_scalar: 8,
+            base_fee_scalar: 9,
+            operator_fee_scalar: 0xabcd,
+            operator_fee_constant: 0xdcba,
+        };
+
+        let calldata = info.encode_calldata();
+        assert_eq!(calldata.len(), L1BlockInfoIsthmus::CALLDATA_LEN);
+        assert_eq!(calldata[..4], hex!("098999be"));
+        assert_eq!(calldata[164..], hex!("0000abcd000000000000dcba"));
+        assert_eq!(L1BlockInfoIsthmus::decode_calldata(&calldata).unwrap(), info);
+    }
+
+    #[test]
+    fn test_decode_isthmus_ecotone_length() {
+        let mut calldata = [0u8; L1BlockInfoEcotone::CALLDATA_LEN];
+        calldata[..4].copy_from_slice(&L1BlockInfoIsthmus::SELECTOR);
+        let err = L1BlockInfoIsthmus::decode_calldata(&calldata).unwrap_err();
+        assert_eq!(
+            err,
+            L1BlockInfoError::InvalidLength {
+                expected: L1BlockInfoIsthmus::CALLDATA_LEN,
+                got: L1BlockInfoEcotone::CALLDATA_LEN,
+            }
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! Jovian L1 block info transaction calldata.
+
+use super::{L1BlockInfoError, L1BlockInfoIsthmus};
+use alloc::vec::Vec;
+use alloy_primitives::{Address, B256, Bytes, U256};
+
+/// The fields of a Jovian `setL1BlockValuesJovian` call.
+///
+/// Jovian appends the DA footprint gas scalar to the packed Isthmus layout:
+///
+/// ```text
+/// +---------+--------------------------+
+/// | Bytes   | Field                    |
+/// +---------+--------------------------+
+/// | 4       | Function selector        |
+/// | 172     | Isthmus fields           |
+/// | 2       | DAFootprintGasScalar     |
+/// +---------+--------------------------+
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct L1BlockInfoJovian {
+    /// The L1 origin block number.
+    pub number: u64,
+    /// The L1 origin block timestamp.
+    pub time: u64,
+    /// The L1 origin block base fee.
+    pub base_fee: U256,
+    /// The L1 origin block hash.
+    pub block_hash: B256,
+    /// The sequence number of the L2 block within the epoch.
+    pub sequence_number: u64,
+    /// The address of the batch submitter.
+    pub batcher_address: Address,
+    /// The L1 origin block blob base fee.
+    pub blob_base_fee: U256,
+    /// The scalar applied to the L1 blob base fee.
+    pub blob_base_fee_scalar: u32,
+    /// The scalar applied to the L1 base fee.
+    pub base_fee_scalar: u32,
+    /// The operator fee scalar.
+    pub operator_fee_scalar: u32,
+    /// The operator fee constant.
+    pub operator_fee_constant: u64,
+    /// The DA footprint gas scalar.
+    pub da_footprint_gas_scalar: u16,
+}
+
+impl L1BlockInfoJovian {
+    /// The 4 byte selector of `setL1BlockValuesJovian()`.
+    pub const SELECTOR: [u8; 4] = [0x3d, 0xb6, 0xbe, 0x2b];
+
+    /// The length of the calldata.
+    pub const CALLDATA_LEN: usize = L1BlockInfoIsthmus::CALLDATA_LEN + 2;
+
+    /// Encodes the fields into `setL1BlockValuesJovian` calldata.
+    pub fn encode_calldata(&self) -> Bytes {
+        let mut buf = Vec::with_capacity(Self::CALLDATA_LEN);
+        buf.extend_from_slice(&Self::SELECTOR);
+        self.as_isthmus().encode_packed(&mut buf);
+        buf.extend_from_slice(&self.da_footprint_gas_scalar.to_be_bytes());
+        buf.into()
+    }
+
+    /// Decodes the fields from `setL1BlockValuesJovian` calldata, including the selector.
+    pub fn decode_calldata(data: &[u8]) -> Result<Self, L1BlockInfoError> {
+        if data.len() != Self::CALLDATA_LEN {
+            return Err(L1BlockInfoError::InvalidLength {
+                expected: Self::CALLDATA_LEN,
+                got: data.len(),
+            });
+        }
+        if data[..4] != Self::SELECTOR {
+            return Err(L1BlockInfoError::InvalidSelector(data[..4].try_into().unwrap()));
+        }
+
+        let isthmus = L1BlockInfoIsthmus::decode_packed(&data[4..])?;
+        Ok(Self {
+            number: isthmus.number,
+            time: isthmus.time,
+            base_fee: isthmus.base_fee,
+            block_hash: isthmus.block_hash,
+            sequence_number: isthmus.sequence_number,
+            batcher_address: isthmus.batcher_address,
+            blob_base_fee: isthmus.blob_base_fee,
+            blob_base_fee_scalar: isthmus.blob_base_fee_scalar,
+            base_fee_scalar: isthmus.base_fee_scalar,
+            operator_fee_scalar: isthmus.operator_fee_scalar,
+            operator_fee_constant: isthmus.operator_fee_constant,
+            da_footprint_gas_scalar: u16::from_be_bytes(data[176..178].try_into().unwrap()),
+        })
+    }
+
+    /// Returns the Isthmus subset of the fields.
+    const fn as_isthmus(&self) -> L1BlockInfoIsthmus {
+        L1BlockInfoIsthmus {
+            number: self.number,
+            time: self.time,
+            base_fee: self.base_fee,
+            block_hash: self.block_hash,
+            sequence_number: self.sequence_number,
+            batcher_address: self.batcher_address,
+            blob_base_fee: self.blob_base_fee,
+            blob_base_fee_scalar: self.blob_base_fee_scalar,
+            base_fee_scalar: self.base_fee_scalar,
+            operator_fee_scalar: self.operator_fee_scalar,
+            operator_fee_constant: self.operator_fee_constant,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::hex;
+
+    #[test]
+    fn test_jovian_calldata_roundtrip() {
+        let info = L1BlockInfoJovian {
+            number: 1,
+            time: 2,
+            base_fee: U256::from(3),
+            block_hash: B256::repeat_byte(4),
+            sequence_number: 5,
+            batcher_address: Address::repeat_byte(6),
+            blob_base_fee: U256::from(7),
+            blob_base_fee_scalar: 8,
+            base_fee_scalar: 9,
+            operator_fee_scalar: 0xabcd,
+            operator_fee_constant: 0xdcba,
+            da_footprint_gas_scalar: 400,
+        };
+
+        let calldata = info.encode_calldata();
+        assert_eq!(calldata.len(), L1BlockInfoJovian::CALLDATA_LEN);
+        assert_eq!(calldata[..4], hex!("3db6be2b"));
+        assert_eq!(calldata[164..], hex!("0000abcd000000000000dcba0190"));
+        assert_eq!(L1BlockInfoJovian::decode_calldata(&calldata).unwrap(), info);
+    }
+}
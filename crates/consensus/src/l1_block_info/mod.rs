@@ -0,0 +1,346 @@
+//! The L1 attributes deposit transaction.
+//!
+//! The first transaction of every L2 block is a system deposit that calls the `L1Block` predeploy
+//! with the attributes of the block's L1 origin. The calldata format changed with Ecotone,
+//! Isthmus and Jovian: <https://specs.optimism.io/protocol/deposits.html#l1-attributes-deposited-transaction>
+
+use crate::{L1InfoDepositSource, TxDeposit, predeploys::L1_BLOCK_ADDRESS};
+use alloy_eips::BlockNumHash;
+use alloy_primitives::{Address, B256, Bytes, TxKind, U256, address};
+
+mod bedrock;
+pub use bedrock::L1BlockInfoBedrock;
+
+mod ecotone;
+pub use ecotone::L1BlockInfoEcotone;
+
+mod isthmus;
+pub use isthmus::L1BlockInfoIsthmus;
+
+mod jovian;
+pub use jovian::L1BlockInfoJovian;
+
+/// The depositor account that sends the L1 attributes deposit transaction.
+pub const L1_INFO_DEPOSITOR_ADDRESS: Address =
+    address!("0xdeaddeaddeaddeaddeaddeaddeaddeaddead0001");
+
+/// The gas limit of the L1 attributes deposit transaction from Regolith onwards.
+pub const REGOLITH_SYSTEM_TX_GAS: u64 = 1_000_000;
+
+/// The gas limit of the L1 attributes deposit transaction before Regolith.
+pub const BEDROCK_SYSTEM_TX_GAS: u64 = 150_000_000;
+
+/// The decoded calldata of an L1 attributes deposit transaction, in any of its hardfork formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum L1BlockInfoTx {
+    /// A Bedrock `setL1BlockValues` call.
+    Bedrock(L1BlockInfoBedrock),
+    /// An Ecotone `setL1BlockValuesEcotone` call.
+    Ecotone(L1BlockInfoEcotone),
+    /// An Isthmus `setL1BlockValuesIsthmus` call.
+    Isthmus(L1BlockInfoIsthmus),
+    /// A Jovian `setL1BlockValuesJovian` call.
+    Jovian(L1BlockInfoJovian),
+}
+
+impl L1BlockInfoTx {
+    /// Decodes the calldata of an L1 attributes deposit, selecting the format by its selector.
+    pub fn decode_calldata(data: &[u8]) -> Result<Self, L1BlockInfoError> {
+        let selector: [u8; 4] =
+            data.get(..4).ok_or(L1BlockInfoError::MissingSelector)?.try_into().unwrap();
+        match selector {
+            L1BlockInfoBedrock::SELECTOR => {
+                L1BlockInfoBedrock::decode_calldata(data).map(Self::Bedrock)
+            }
+            L1BlockInfoEcotone::SELECTOR => {
+                L1BlockInfoEcotone::decode_calldata(data).map(Self::Ecotone)
+            }
+            L1BlockInfoIsthmus::SELECTOR => {
+                L1BlockInfoIsthmus::decode_calldata(data).map(Self::Isthmus)
+            }
+            L1BlockInfoJovian::SELECTOR => {
+                L1BlockInfoJovian::decode_calldata(data).map(Self::Jovian)
+            }
+            _ => Err(L1BlockInfoError::InvalidSelector(selector)),
+        }
+    }
+
+    /// Encodes the calldata of the L1 attributes deposit.
+    pub fn encode_calldata(&self) -> Bytes {
+        match self {
+            Self::Bedrock(info) => info.encode_calldata(),
+            Self::Ecotone(info) => info.encode_calldata(),
+            Self::Isthmus(info) => info.encode_calldata(),
+            Self::Jovian(info) => info.encode_calldata(),
+        }
+    }
+
+    /// Builds the full L1 attributes [`TxDeposit`].
+    ///
+    /// Before Regolith the deposit is flagged as a system transaction and is given
+    /// [`BEDROCK_SYSTEM_TX_GAS`]. From Regolith onwards it is a regular deposit with
+    /// [`REGOLITH_SYSTEM_TX_GAS`].
+    pub fn to_deposit_tx(&self, is_regolith: bool) -> TxDeposit {
+        TxDeposit {
+            source_hash: self.source().source_hash(),
+            from: L1_INFO_DEPOSITOR_ADDRESS,
+            to: TxKind::Call(L1_BLOCK_ADDRESS),
+            mint: 0,
+            value: U256::ZERO,
+            gas_limit: if is_regolith { REGOLITH_SYSTEM_TX_GAS } else { BEDROCK_SYSTEM_TX_GAS },
+            is_system_transaction: !is_regolith,
+            input: self.encode_calldata(),
+        }
+    }
+
+    /// Returns the deposit source of the L1 attributes deposit.
+    pub const fn source(&self) -> L1InfoDepositSource {
+        L1InfoDepositSource::new(self.block_hash(), self.sequence_number())
+    }
+
+    /// Returns the number and hash of the L1 origin block.
+    pub const fn id(&self) -> BlockNumHash {
+        BlockNumHash { number: self.number(), hash: self.block_hash() }
+    }
+
+    /// Returns the L1 origin block number.
+    pub const fn number(&self) -> u64 {
+        match self {
+            Self::Bedrock(info) => info.number,
+            Self::Ecotone(info) => info.number,
+            Self::Isthmus(info) => info.number,
+            Self::Jovian(info) => info.number,
+        }
+    }
+
+    /// Returns the L1 origin block timestamp.
+    pub const fn time(&self) -> u64 {
+        match self {
+            Self::Bedrock(info) => info.time,
+            Self::Ecotone(info) => info.time,
+            Self::Isthmus(info) => info.time,
+            Self::Jovian(info) => info.time,
+        }
+    }
+
+    /// Returns the L1 origin block hash.
+    pub const fn block_hash(&self) -> B256 {
+        match self {
+            Self::Bedrock(info) => info.block_hash,
+            Self::Ecotone(info) => info.block_hash,
+            Self::Isthmus(info) => info.block_hash,
+            Self::Jovian(info) => info.block_hash,
+        }
+    }
+
+    /// Returns the sequence number of the L2 block within its epoch.
+    pub const fn sequence_number(&self) -> u64 {
+        match self {
+            Self::Bedrock(info) => info.sequence_number,
+            Self::Ecotone(info) => info.sequence_number,
+            Self::Isthmus(info) => info.sequence_number,
+            Self::Jovian(info) => info.sequence_number,
+        }
+    }
+
+    /// Returns the batcher address.
+    pub const fn batcher_address(&self) -> Address {
+        match self {
+            Self::Bedrock(info) => info.batcher_address,
+            Self::Ecotone(info) => info.batcher_address,
+            Self::Isthmus(info) => info.batcher_address,
+            Self::Jovian(info) => info.batcher_address,
+        }
+    }
+
+    /// Returns the L1 origin block base fee.
+    pub const fn l1_base_fee(&self) -> U256 {
+        match self {
+            Self::Bedrock(info) => info.base_fee,
+            Self::Ecotone(info) => info.base_fee,
+            Self::Isthmus(info) => info.base_fee,
+            Self::Jovian(info) => info.base_fee,
+        }
+    }
+
+    /// Returns the L1 origin block blob base fee, which is zero before Ecotone.
+    pub const fn blob_base_fee(&self) -> U256 {
+        match self {
+            Self::Bedrock(_) => U256::ZERO,
+            Self::Ecotone(info) => info.blob_base_fee,
+            Self::Isthmus(info) => info.blob_base_fee,
+            Self::Jovian(info) => info.blob_base_fee,
+        }
+    }
+
+    /// Returns the operator fee scalar, which is zero before Isthmus.
+    pub const fn operator_fee_scalar(&self) -> u32 {
+        match self {
+            Self::Bedrock(_) | Self::Ecotone(_) => 0,
+            Self::Isthmus(info) => info.operator_fee_scalar,
+            Self::Jovian(info) => info.operator_fee_scalar,
+        }
+    }
+
+    /// Returns the operator fee constant, which is zero before Isthmus.
+    pub const fn operator_fee_constant(&self) -> u64 {
+        match self {
+            Self::Bedrock(_) | Self::Ecotone(_) => 0,
+            Self::Isthmus(info) => info.operator_fee_constant,
+            Self::Jovian(info) => info.operator_fee_constant,
+        }
+    }
+
+    /// Returns the DA footprint gas scalar, which is only set from Jovian onwards.
+    pub const fn da_footprint_gas_scalar(&self) -> Option<u16> {
+        match self {
+            Self::Jovian(info) => Some(info.da_footprint_gas_scalar),
+            _ => None,
+        }
+    }
+}
+
+impl From<L1BlockInfoBedrock> for L1BlockInfoTx {
+    fn from(info: L1BlockInfoBedrock) -> Self {
+        Self::Bedrock(info)
+    }
+}
+
+impl From<L1BlockInfoEcotone> for L1BlockInfoTx {
+    fn from(info: L1BlockInfoEcotone) -> Self {
+        Self::Ecotone(info)
+    }
+}
+
+impl From<L1BlockInfoIsthmus> for L1BlockInfoTx {
+    fn from(info: L1BlockInfoIsthmus) -> Self {
+        Self::Isthmus(info)
+    }
+}
+
+impl From<L1BlockInfoJovian> for L1BlockInfoTx {
+    fn from(info: L1BlockInfoJovian) -> Self {
+        Self::Jovian(info)
+    }
+}
+
+impl TryFrom<&TxDeposit> for L1BlockInfoTx {
+    type Error = L1BlockInfoError;
+
+    fn try_from(tx: &TxDeposit) -> Result<Self, Self::Error> {
+        Self::decode_calldata(&tx.input)
+    }
+}
+
+/// Errors that can occur when decoding L1 attributes deposit calldata.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum L1BlockInfoError {
+    /// The calldata is shorter than a function selector.
+    #[error("L1 info calldata is missing the function selector")]
+    MissingSelector,
+    /// The function selector does not match any known format.
+    #[error("Invalid L1 info function selector: {0:02x?}")]
+    InvalidSelector([u8; 4]),
+    /// The calldata length does not match the format of its selector.
+    #[error("Invalid L1 info calldata length: expected {expected}, got {got}")]
+    InvalidLength {
+        /// The length required by the format.
+        expected: usize,
+        /// The length of the calldata.
+        got: usize,
+    },
+    /// An ABI word holding a narrower value has non-zero padding.
+    #[error("Non-zero padding in L1 info calldata word")]
+    InvalidPadding,
+}
+
+/// Reads a `uint64` from a left-padded 32 byte ABI word.
+fn read_u64_word(word: &[u8]) -> Result<u64, L1BlockInfoError> {
+    if word[..24].iter().any(|b| *b != 0) {
+        return Err(L1BlockInfoError::InvalidPadding);
+    }
+    Ok(u64::from_be_bytes(word[24..32].try_into().unwrap()))
+}
+
+/// Reads an address from a left-padded 32 byte ABI word.
+fn read_address_word(word: &[u8]) -> Result<Address, L1BlockInfoError> {
+    if word[..12].iter().any(|b| *b != 0) {
+        return Err(L1BlockInfoError::InvalidPadding);
+    }
+    Ok(Address::from_slice(&word[12..32]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::eip2718::{Decodable2718, Encodable2718};
+    use alloy_primitives::hex;
+
+    #[test]
+    fn test_bedrock_deposit_tx_roundtrip() {
+        let raw = hex!(
+            "7ef9015aa044bae9d41b8380d781187b426c6fe43df5fb2fb57bd4466ef6a701e1f01e015694deaddeaddeaddeaddeaddeaddeaddeaddead000194420000000000000000000000000000000000001580808408f0d18001b90104015d8eb900000000000000000000000000000000000000000000000000000000008057650000000000000000000000000000000000000000000000000000000063d96d10000000000000000000000000000000000000000000000000000000000009f35273d89754a1e0387b89520d989d3be9c37c1f32495a88faf1ea05c61121ab0d1900000000000000000000000000000000000000000000000000000000000000010000000000000000000000002d679b567db6187c0c8323fa982cfb88b74dbcc7000000000000000000000000000000000000000000000000000000000000083400000000000000000000000000000000000000000000000000000000000f4240"
+        );
+        let tx = TxDeposit::decode_2718(&mut raw.as_slice()).unwrap();
+
+        let info = L1BlockInfoTx::try_from(&tx).unwrap();
+        assert!(matches!(info, L1BlockInfoTx::Bedrock(_)));
+        assert_eq!(info.sequence_number(), 1);
+
+        let rebuilt = info.to_deposit_tx(false);
+        assert_eq!(rebuilt, tx);
+        assert_eq!(rebuilt.encoded_2718(), raw);
+    }
+
+    #[test]
+    fn test_ecotone_deposit_tx_roundtrip() {
+        let raw = hex!(
+            "7ef8f8a0871ec5fb6afe7e5ae950bbb4cfd7d7cb277b413e67da806d50834a814b14c9f494deaddeaddeaddeaddeaddeaddeaddeaddead00019442000000000000000000000000000000000000158080830f424080b8a4440a5e20000008dd00101c12000000000000000400000000681c941f0000000001566261000000000000000000000000000000000000000000000000000000005f629c020000000000000000000000000000000000000000000000000000000000000001937badfbcce566e0ba932a3f7659644aa0c6ef019541d3134a1d8cb9f84d45c70000000000000000000000005050f69a9786f081509234f1a7f4684b5e5b76c9"
+        );
+        let tx = TxDeposit::decode_2718(&mut raw.as_slice()).unwrap();
+
+        let info = L1BlockInfoTx::try_from(&tx).unwrap();
+        assert!(matches!(info, L1BlockInfoTx::Ecotone(_)));
+        assert_eq!(info.id().number, 0x1566261);
+        assert_eq!(info.operator_fee_scalar(), 0);
+        assert_eq!(info.da_footprint_gas_scalar(), None);
+
+        let rebuilt = info.to_deposit_tx(true);
+        assert_eq!(rebuilt, tx);
+        assert_eq!(rebuilt.encoded_2718(), raw);
+    }
+
+    #[test]
+    fn test_decode_calldata_dispatch() {
+        let jovian = L1BlockInfoTx::Jovian(L1BlockInfoJovian {
+            operator_fee_scalar: 1,
+            da_footprint_gas_scalar: 400,
+            ..Default::default()
+        });
+        assert_eq!(L1BlockInfoTx::decode_calldata(&jovian.encode_calldata()).unwrap(), jovian);
+
+        let isthmus = L1BlockInfoTx::Isthmus(L1BlockInfoIsthmus {
+            operator_fee_constant: 2,
+            ..Default::default()
+        });
+        assert_eq!(L1BlockInfoTx::decode_calldata(&isthmus.encode_calldata()).unwrap(), isthmus);
+    }
+
+    #[test]
+    fn test_decode_calldata_errors() {
+        assert_eq!(
+            L1BlockInfoTx::decode_calldata(&[0x01, 0x5d]).unwrap_err(),
+            L1BlockInfoError::MissingSelector
+        );
+        assert_eq!(
+            L1BlockInfoTx::decode_calldata(&[0xde, 0xad, 0xbe, 0xef]).unwrap_err(),
+            L1BlockInfoError::InvalidSelector([0xde, 0xad, 0xbe, 0xef])
+        );
+        assert_eq!(
+            L1BlockInfoTx::decode_calldata(&L1BlockInfoJovian::SELECTOR).unwrap_err(),
+            L1BlockInfoError::InvalidLength { expected: L1BlockInfoJovian::CALLDATA_LEN, got: 4 }
+        );
+    }
+}
@@ -15,6 +15,7 @@ mod alloy_compat;
 mod receipts;
 pub use receipts::{
     OpDepositReceipt, OpDepositReceiptWithBloom, OpReceipt, OpReceiptEnvelope, OpTxReceipt,
+    calculate_receipt_root_op, receipts_logs_bloom,
 };
 
 pub mod transaction;
@@ -25,22 +26,67 @@ pub use transaction::{
 
 pub mod eip1559;
 pub use eip1559::{
-    EIP1559ParamError, decode_eip_1559_params, decode_holocene_extra_data,
+    EIP1559ParamError, OpBaseFeeParams, decode_eip_1559_params, decode_holocene_extra_data,
     decode_jovian_extra_data, encode_holocene_extra_data, encode_jovian_extra_data,
+    next_block_base_fee,
 };
 
 mod source;
 pub use source::*;
 
+pub mod deposit_event;
+pub use deposit_event::{
+    DEPOSIT_EVENT_ABI_HASH, DepositEventError, decode_deposit_log, encode_deposit_log,
+};
+
+pub mod output_root;
+pub use output_root::{OutputRoot, OutputRootError};
+
+pub mod withdrawal;
+pub use withdrawal::{MESSAGE_PASSED_EVENT_ABI_HASH, WithdrawalError, WithdrawalTransaction};
+
+pub mod hardfork;
+pub use hardfork::{OpHardfork, OpHardforks, OpHardforksError, ParseOpHardforkError};
+
+pub mod l1_block_info;
+pub use l1_block_info::{
+    L1BlockInfoBedrock, L1BlockInfoEcotone, L1BlockInfoError, L1BlockInfoIsthmus,
+    L1BlockInfoJovian, L1BlockInfoTx,
+};
+
+pub mod fee;
+pub use fee::{L1Fee, L1FeeCalculator, L1FeeParams, OperatorFeeCalculator, OperatorFeeParams};
+
 mod size;
 
 mod block;
-pub use block::OpBlock;
+pub use block::{OpBlock, OpBlockError, OpBlockValidator, OpBlockViolation};
 
 pub mod interop;
 
 pub mod predeploys;
-pub use predeploys::L2_TO_L1_MESSAGE_PASSER_ADDRESS;
+pub use predeploys::{L1_BLOCK_ADDRESS, L2_TO_L1_MESSAGE_PASSER_ADDRESS, Predeploy};
+
+pub mod frame;
+pub use frame::{DERIVATION_VERSION_0, Frame, FrameError};
+
+pub mod blob;
+pub use blob::{BlobData, BlobDataError};
+
+pub mod channel;
+pub use channel::{Channel, ChannelError, ChannelId};
+
+pub mod batch;
+pub use batch::{Batch, BatchError, RawSpanBatch, SingularBatch, SpanBatch, SpanBatchElement};
+
+pub mod batch_inbox;
+pub use batch_inbox::{BatchInboxFilter, BatcherTxData, BatcherTxSkipReason};
+
+pub mod system_config;
+pub use system_config::{ConfigUpdate, ConfigUpdateError, SystemConfig, SystemConfigHistory};
+
+pub mod upgrades;
+pub use upgrades::{UpgradeTransaction, upgrade_deposits, upgrade_transactions};
 
 #[cfg(feature = "serde")]
 pub use transaction::serde_deposit_tx_rpc;
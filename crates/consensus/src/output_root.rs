@@ -0,0 +1,201 @@
+//! L2 output roots, the commitments to L2 blocks that are proposed on L1.
+//!
+//! See also: <https://specs.optimism.io/protocol/proposals.html#l2-output-commitment-construction>
+
+use alloy_consensus::{BlockHeader, Sealable, Sealed};
+use alloy_primitives::{B256, keccak256};
+
+/// A version 0 L2 output root.
+///
+/// The output root is `keccak256(version ++ stateRoot ++ messagePasserStorageRoot ++ blockHash)`,
+/// where the version is a 32 byte word. It commits to the storage root of the
+/// `L2ToL1MessagePasser` so that withdrawals can be proven against it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct OutputRoot {
+    /// The state root of the L2 block.
+    pub state_root: B256,
+    /// The storage root of the `L2ToL1MessagePasser` predeploy after the L2 block.
+    pub message_passer_storage_root: B256,
+    /// The hash of the L2 block.
+    pub block_hash: B256,
+}
+
+impl OutputRoot {
+    /// The version of the output root, the only one in use.
+    pub const VERSION: B256 = B256::ZERO;
+
+    /// The length of the encoded output root preimage.
+    pub const ENCODED_LENGTH: usize = 128;
+
+    /// Creates a new output root from its parts.
+    pub const fn new(
+        state_root: B256,
+        message_passer_storage_root: B256,
+        block_hash: B256,
+    ) -> Self {
+        Self { state_root, message_passer_storage_root, block_hash }
+    }
+
+    /// Creates the output root of an Isthmus block from its header.
+    ///
+    /// Since Isthmus, the `withdrawals_root` header field carries the storage root of the
+    /// `L2ToL1MessagePasser`. Isthmus headers are recognized by their `requests_hash` field;
+    /// earlier headers do not commit to the message passer storage root, which then has to be
+    /// fetched from the state with [`OutputRoot::new`].
+    pub fn from_header<H: BlockHeader + Sealable>(header: &H) -> Result<Self, OutputRootError> {
+        Self::from_header_and_hash(header, header.hash_slow())
+    }
+
+    /// Creates the output root of an Isthmus block from its sealed header.
+    ///
+    /// See [`OutputRoot::from_header`].
+    pub fn from_sealed_header<H: BlockHeader>(header: &Sealed<H>) -> Result<Self, OutputRootError> {
+        Self::from_header_and_hash(header.inner(), header.hash())
+    }
+
+    fn from_header_and_hash<H: BlockHeader>(
+        header: &H,
+        block_hash: B256,
+    ) -> Result<Self, OutputRootError> {
+        let storage_root = header
+            .withdrawals_root()
+            .filter(|_| header.requests_hash().is_some())
+            .ok_or(OutputRootError::MissingMessagePasserStorageRoot)?;
+        Ok(Self::new(header.state_root(), storage_root, block_hash))
+    }
+
+    /// Encodes the 128 byte preimage of the output root.
+    pub fn encode(&self) -> [u8; Self::ENCODED_LENGTH] {
+        let mut encoded = [0u8; Self::ENCODED_LENGTH];
+        encoded[..32].copy_from_slice(Self::VERSION.as_slice());
+        encoded[32..64].copy_from_slice(self.state_root.as_slice());
+        encoded[64..96].copy_from_slice(self.message_passer_storage_root.as_slice());
+        encoded[96..].copy_from_slice(self.block_hash.as_slice());
+        encoded
+    }
+
+    /// Decodes an output root from its 128 byte preimage.
+    pub fn decode(data: &[u8]) -> Result<Self, OutputRootError> {
+        if data.len() != Self::ENCODED_LENGTH {
+            return Err(OutputRootError::InvalidLength(data.len()));
+        }
+        let version = B256::from_slice(&data[..32]);
+        if version != Self::VERSION {
+            return Err(OutputRootError::UnsupportedVersion(version));
+        }
+        Ok(Self::new(
+            B256::from_slice(&data[32..64]),
+            B256::from_slice(&data[64..96]),
+            B256::from_slice(&data[96..]),
+        ))
+    }
+
+    /// Returns the output root, i.e. the hash of the encoded preimage.
+    pub fn hash(&self) -> B256 {
+        keccak256(self.encode())
+    }
+
+    /// Checks that the output root hashes to `expected`.
+    pub fn verify(&self, expected: B256) -> Result<(), OutputRootError> {
+        let computed = self.hash();
+        if computed != expected {
+            return Err(OutputRootError::Mismatch { expected, computed });
+        }
+        Ok(())
+    }
+}
+
+/// Errors that can occur when constructing, decoding or verifying an output root.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum OutputRootError {
+    /// The header does not carry the message passer storage root, because it predates Isthmus.
+    #[error("Header does not carry the message passer storage root")]
+    MissingMessagePasserStorageRoot,
+    /// The preimage is not 128 bytes long.
+    #[error("Invalid output root preimage length: {0}")]
+    InvalidLength(usize),
+    /// The output root version is not supported.
+    #[error("Unsupported output root version: {0}")]
+    UnsupportedVersion(B256),
+    /// The output root does not match the expected one.
+    #[error("Output root mismatch: expected {expected}, computed {computed}")]
+    Mismatch {
+        /// The expected output root.
+        expected: B256,
+        /// The computed output root.
+        computed: B256,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{EMPTY_ROOT_HASH, Header};
+    use alloy_primitives::{b256, hex};
+
+    fn output_root() -> OutputRoot {
+        OutputRoot::new(
+            B256::left_padding_from(&[0xbe, 0xef]),
+            B256::left_padding_from(&[0xba, 0xbe]),
+            B256::left_padding_from(&[0xc0, 0xde]),
+        )
+    }
+
+    #[test]
+    fn test_output_root_encoding() {
+        let root = output_root();
+        let encoded = root.encode();
+        assert_eq!(
+            encoded,
+            hex!(
+                "0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000beef000000000000000000000000000000000000000000000000000000000000babe000000000000000000000000000000000000000000000000000000000000c0de"
+            )
+        );
+        assert_eq!(OutputRoot::decode(&encoded), Ok(root));
+        assert_eq!(
+            root.hash(),
+            b256!("0x0c39fb6b07cf6694b13e63e59f7b15255be1c93a4d6d3e0da6c99729647c0d11")
+        );
+        assert_eq!(root.verify(root.hash()), Ok(()));
+        assert_eq!(
+            root.verify(B256::ZERO),
+            Err(OutputRootError::Mismatch { expected: B256::ZERO, computed: root.hash() })
+        );
+    }
+
+    #[test]
+    fn test_decode_output_root_invalid() {
+        let mut encoded = output_root().encode();
+        assert_eq!(OutputRoot::decode(&encoded[1..]), Err(OutputRootError::InvalidLength(127)));
+
+        encoded[31] = 1;
+        assert_eq!(
+            OutputRoot::decode(&encoded),
+            Err(OutputRootError::UnsupportedVersion(B256::with_last_byte(1)))
+        );
+    }
+
+    #[test]
+    fn test_output_root_from_header() {
+        let storage_root = B256::repeat_byte(2);
+        let header = Header {
+            state_root: B256::repeat_byte(1),
+            withdrawals_root: Some(storage_root),
+            requests_hash: Some(B256::repeat_byte(3)),
+            ..Default::default()
+        };
+        let expected = OutputRoot::new(header.state_root, storage_root, header.hash_slow());
+        assert_eq!(OutputRoot::from_header(&header), Ok(expected));
+        assert_eq!(OutputRoot::from_sealed_header(&header.clone().seal_slow()), Ok(expected));
+
+        // Before Isthmus, the withdrawals root is the empty root.
+        let header =
+            Header { withdrawals_root: Some(EMPTY_ROOT_HASH), requests_hash: None, ..header };
+        assert_eq!(
+            OutputRoot::from_header(&header),
+            Err(OutputRootError::MissingMessagePasserStorageRoot)
+        );
+    }
+}
@@ -0,0 +1,230 @@
+//! Solidity call and event bindings for the predeploys.
+//!
+//! Only the parts of the interfaces that are relevant to off-chain tooling are included.
+
+#![allow(missing_docs)]
+
+alloy_sol_types::sol! {
+    /// The proxy that fronts most predeploys.
+    #[derive(Debug, PartialEq, Eq)]
+    interface Proxy {
+        event Upgraded(address indexed implementation);
+        event AdminChanged(address previousAdmin, address newAdmin);
+
+        function upgradeTo(address _implementation) external;
+        function upgradeToAndCall(address _implementation, bytes calldata _data) external payable returns (bytes memory);
+        function changeAdmin(address _admin) external;
+        function admin() external returns (address);
+        function implementation() external returns (address);
+    }
+
+    /// The `ProxyAdmin` predeploy, which owns the other predeploy proxies.
+    #[derive(Debug, PartialEq, Eq)]
+    interface ProxyAdmin {
+        event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
+
+        function upgrade(address _proxy, address _implementation) external;
+        function upgradeAndCall(address _proxy, address _implementation, bytes memory _data) external payable;
+        function changeProxyAdmin(address _proxy, address _newAdmin) external;
+        function getProxyImplementation(address _proxy) external view returns (address);
+        function getProxyAdmin(address _proxy) external view returns (address);
+        function owner() external view returns (address);
+        function transferOwnership(address newOwner) external;
+    }
+
+    /// The `L1Block` predeploy.
+    #[derive(Debug, PartialEq, Eq)]
+    interface L1Block {
+        function number() external view returns (uint64);
+        function timestamp() external view returns (uint64);
+        function basefee() external view returns (uint256);
+        function hash() external view returns (bytes32);
+        function sequenceNumber() external view returns (uint64);
+        function batcherHash() external view returns (bytes32);
+        function l1FeeOverhead() external view returns (uint256);
+        function l1FeeScalar() external view returns (uint256);
+        function blobBaseFee() external view returns (uint256);
+        function baseFeeScalar() external view returns (uint32);
+        function blobBaseFeeScalar() external view returns (uint32);
+        function operatorFeeScalar() external view returns (uint32);
+        function operatorFeeConstant() external view returns (uint64);
+        function daFootprintGasScalar() external view returns (uint16);
+    }
+
+    /// The `GasPriceOracle` predeploy.
+    #[derive(Debug, PartialEq, Eq)]
+    interface GasPriceOracle {
+        function getL1Fee(bytes memory _data) external view returns (uint256);
+        function getL1GasUsed(bytes memory _data) external view returns (uint256);
+        function getL1FeeUpperBound(uint256 _unsignedTxSize) external view returns (uint256);
+        function getOperatorFee(uint256 _gasUsed) external view returns (uint256);
+        function gasPrice() external view returns (uint256);
+        function baseFee() external view returns (uint256);
+        function l1BaseFee() external view returns (uint256);
+        function blobBaseFee() external view returns (uint256);
+        function baseFeeScalar() external view returns (uint32);
+        function blobBaseFeeScalar() external view returns (uint32);
+        function decimals() external pure returns (uint256);
+        function isEcotone() external view returns (bool);
+        function isFjord() external view returns (bool);
+        function isIsthmus() external view returns (bool);
+        function isJovian() external view returns (bool);
+        function setEcotone() external;
+        function setFjord() external;
+        function setIsthmus() external;
+        function setJovian() external;
+    }
+
+    /// The `L1BlockNumber` predeploy.
+    #[derive(Debug, PartialEq, Eq)]
+    interface L1BlockNumber {
+        function getL1BlockNumber() external view returns (uint256);
+    }
+
+    /// The `L2ToL1MessagePasser` predeploy.
+    #[derive(Debug, PartialEq, Eq)]
+    interface L2ToL1MessagePasser {
+        event MessagePassed(
+            uint256 indexed nonce,
+            address indexed sender,
+            address indexed target,
+            uint256 value,
+            uint256 gasLimit,
+            bytes data,
+            bytes32 withdrawalHash
+        );
+        event WithdrawerBalanceBurnt(uint256 indexed amount);
+
+        function initiateWithdrawal(address _target, uint256 _gasLimit, bytes memory _data) external payable;
+        function burn() external;
+        function messageNonce() external view returns (uint256);
+        function sentMessages(bytes32 _withdrawalHash) external view returns (bool);
+    }
+
+    /// The `L2CrossDomainMessenger` predeploy.
+    #[derive(Debug, PartialEq, Eq)]
+    interface L2CrossDomainMessenger {
+        event SentMessage(address indexed target, address sender, bytes message, uint256 messageNonce, uint256 gasLimit);
+        event SentMessageExtension1(address indexed sender, uint256 value);
+        event RelayedMessage(bytes32 indexed msgHash);
+        event FailedRelayedMessage(bytes32 indexed msgHash);
+
+        function sendMessage(address _target, bytes calldata _message, uint32 _minGasLimit) external payable;
+        function relayMessage(
+            uint256 _nonce,
+            address _sender,
+            address _target,
+            uint256 _value,
+            uint256 _minGasLimit,
+            bytes calldata _message
+        ) external payable;
+        function messageNonce() external view returns (uint256);
+        function xDomainMessageSender() external view returns (address);
+    }
+
+    /// The `L2StandardBridge` predeploy.
+    #[derive(Debug, PartialEq, Eq)]
+    interface L2StandardBridge {
+        event WithdrawalInitiated(
+            address indexed l1Token,
+            address indexed l2Token,
+            address indexed from,
+            address to,
+            uint256 amount,
+            bytes extraData
+        );
+        event DepositFinalized(
+            address indexed l1Token,
+            address indexed l2Token,
+            address indexed from,
+            address to,
+            uint256 amount,
+            bytes extraData
+        );
+        event ETHBridgeInitiated(address indexed from, address indexed to, uint256 amount, bytes extraData);
+        event ETHBridgeFinalized(address indexed from, address indexed to, uint256 amount, bytes extraData);
+
+        function withdraw(address _l2Token, uint256 _amount, uint32 _minGasLimit, bytes calldata _extraData) external payable;
+        function withdrawTo(
+            address _l2Token,
+            address _to,
+            uint256 _amount,
+            uint32 _minGasLimit,
+            bytes calldata _extraData
+        ) external payable;
+        function bridgeETH(uint32 _minGasLimit, bytes calldata _extraData) external payable;
+        function bridgeETHTo(address _to, uint32 _minGasLimit, bytes calldata _extraData) external payable;
+    }
+
+    /// The fee vaults: `SequencerFeeVault`, `BaseFeeVault`, `L1FeeVault` and `OperatorFeeVault`.
+    #[derive(Debug, PartialEq, Eq)]
+    interface FeeVault {
+        event Withdrawal(uint256 value, address to, address from, uint8 withdrawalNetwork);
+
+        function withdraw() external;
+        function totalProcessed() external view returns (uint256);
+        function recipient() external view returns (address);
+        function minWithdrawalAmount() external view returns (uint256);
+        function withdrawalNetwork() external view returns (uint8);
+    }
+
+    /// The `CrossL2Inbox` predeploy.
+    #[derive(Debug, PartialEq, Eq)]
+    interface CrossL2Inbox {
+        struct Identifier {
+            address origin;
+            uint256 blockNumber;
+            uint256 logIndex;
+            uint256 timestamp;
+            uint256 chainId;
+        }
+
+        event ExecutingMessage(bytes32 indexed msgHash, Identifier id);
+
+        function validateMessage(Identifier calldata _id, bytes32 _msgHash) external;
+        function calculateChecksum(Identifier memory _id, bytes32 _msgHash) external pure returns (bytes32);
+    }
+
+    /// The `L2ToL2CrossDomainMessenger` predeploy.
+    #[derive(Debug, PartialEq, Eq)]
+    interface L2ToL2CrossDomainMessenger {
+        event SentMessage(
+            uint256 indexed destination,
+            address indexed target,
+            uint256 indexed messageNonce,
+            address sender,
+            bytes message
+        );
+
+        function sendMessage(uint256 _destination, address _target, bytes calldata _message) external returns (bytes32);
+        function relayMessage(CrossL2Inbox.Identifier calldata _id, bytes calldata _sentMessage) external payable returns (bytes memory);
+        function messageNonce() external view returns (uint256);
+        function crossDomainMessageSender() external view returns (address);
+        function crossDomainMessageSource() external view returns (uint256);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{b256, hex};
+    use alloy_sol_types::{SolCall, SolEvent};
+
+    #[test]
+    fn test_selectors() {
+        assert_eq!(Proxy::upgradeToCall::SELECTOR, hex!("3659cfe6"));
+        assert_eq!(ProxyAdmin::upgradeCall::SELECTOR, hex!("99a88ec4"));
+        assert_eq!(ProxyAdmin::upgradeAndCallCall::SELECTOR, hex!("9623609d"));
+        assert_eq!(GasPriceOracle::setEcotoneCall::SELECTOR, hex!("22b90ab3"));
+        assert_eq!(GasPriceOracle::setFjordCall::SELECTOR, hex!("8e98b106"));
+        assert_eq!(GasPriceOracle::setIsthmusCall::SELECTOR, hex!("291b0383"));
+    }
+
+    #[test]
+    fn test_message_passed_signature() {
+        assert_eq!(
+            L2ToL1MessagePasser::MessagePassed::SIGNATURE_HASH,
+            b256!("0x02a52367d10742d8032712c1bb8e0144ff1ec5ffda1ed7d70bb05a2744955054")
+        );
+    }
+}
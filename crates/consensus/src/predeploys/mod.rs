@@ -0,0 +1,382 @@
+//! Addresses of OP Stack pre-deployed contracts.
+//!
+//! See also: <https://specs.optimism.io/protocol/predeploys.html>
+
+use crate::OpHardfork;
+use alloy_primitives::{Address, address};
+
+#[cfg(feature = "bindings")]
+pub mod bindings;
+
+/// The address of the `LegacyMessagePasser` predeploy.
+pub const LEGACY_MESSAGE_PASSER_ADDRESS: Address =
+    address!("0x4200000000000000000000000000000000000000");
+
+/// The address of the `DeployerWhitelist` predeploy.
+pub const DEPLOYER_WHITELIST_ADDRESS: Address =
+    address!("0x4200000000000000000000000000000000000002");
+
+/// The address of the `LegacyERC20ETH` predeploy.
+pub const LEGACY_ERC20_ETH_ADDRESS: Address =
+    address!("0xdeaddeaddeaddeaddeaddeaddeaddeaddead0000");
+
+/// The address of the `WETH9` predeploy.
+pub const WETH9_ADDRESS: Address = address!("0x4200000000000000000000000000000000000006");
+
+/// The address of the `L2CrossDomainMessenger` predeploy.
+pub const L2_CROSS_DOMAIN_MESSENGER_ADDRESS: Address =
+    address!("0x4200000000000000000000000000000000000007");
+
+/// The address of the `GasPriceOracle` predeploy.
+pub const GAS_PRICE_ORACLE_ADDRESS: Address =
+    address!("0x420000000000000000000000000000000000000f");
+
+/// The address of the `L2StandardBridge` predeploy.
+pub const L2_STANDARD_BRIDGE_ADDRESS: Address =
+    address!("0x4200000000000000000000000000000000000010");
+
+/// The address of the `SequencerFeeVault` predeploy.
+pub const SEQUENCER_FEE_VAULT_ADDRESS: Address =
+    address!("0x4200000000000000000000000000000000000011");
+
+/// The address of the `OptimismMintableERC20Factory` predeploy.
+pub const OPTIMISM_MINTABLE_ERC20_FACTORY_ADDRESS: Address =
+    address!("0x4200000000000000000000000000000000000012");
+
+/// The address of the `L1BlockNumber` predeploy.
+pub const L1_BLOCK_NUMBER_ADDRESS: Address = address!("0x4200000000000000000000000000000000000013");
+
+/// The address of the `L2ERC721Bridge` predeploy.
+pub const L2_ERC721_BRIDGE_ADDRESS: Address =
+    address!("0x4200000000000000000000000000000000000014");
+
+/// The address of the `L1Block` predeploy.
+pub const L1_BLOCK_ADDRESS: Address = address!("0x4200000000000000000000000000000000000015");
+
+/// The address of the `L2ToL1MessagePasser` predeploy.
+pub const L2_TO_L1_MESSAGE_PASSER_ADDRESS: Address =
+    address!("0x4200000000000000000000000000000000000016");
+
+/// The address of the `OptimismMintableERC721Factory` predeploy.
+pub const OPTIMISM_MINTABLE_ERC721_FACTORY_ADDRESS: Address =
+    address!("0x4200000000000000000000000000000000000017");
+
+/// The address of the `ProxyAdmin` predeploy.
+pub const PROXY_ADMIN_ADDRESS: Address = address!("0x4200000000000000000000000000000000000018");
+
+/// The address of the `BaseFeeVault` predeploy.
+pub const BASE_FEE_VAULT_ADDRESS: Address = address!("0x4200000000000000000000000000000000000019");
+
+/// The address of the `L1FeeVault` predeploy.
+pub const L1_FEE_VAULT_ADDRESS: Address = address!("0x420000000000000000000000000000000000001a");
+
+/// The address of the `OperatorFeeVault` predeploy.
+pub const OPERATOR_FEE_VAULT_ADDRESS: Address =
+    address!("0x420000000000000000000000000000000000001b");
+
+/// The address of the `SchemaRegistry` predeploy.
+pub const SCHEMA_REGISTRY_ADDRESS: Address = address!("0x4200000000000000000000000000000000000020");
+
+/// The address of the `EAS` predeploy.
+pub const EAS_ADDRESS: Address = address!("0x4200000000000000000000000000000000000021");
+
+/// The address of the `CrossL2Inbox` predeploy.
+pub const CROSS_L2_INBOX_ADDRESS: Address = address!("0x4200000000000000000000000000000000000022");
+
+/// The address of the `L2ToL2CrossDomainMessenger` predeploy.
+pub const L2_TO_L2_CROSS_DOMAIN_MESSENGER_ADDRESS: Address =
+    address!("0x4200000000000000000000000000000000000023");
+
+/// The address of the `SuperchainETHBridge` predeploy.
+pub const SUPERCHAIN_ETH_BRIDGE_ADDRESS: Address =
+    address!("0x4200000000000000000000000000000000000024");
+
+/// The address of the `ETHLiquidity` predeploy.
+pub const ETH_LIQUIDITY_ADDRESS: Address = address!("0x4200000000000000000000000000000000000025");
+
+/// The address of the `OptimismSuperchainERC20Factory` predeploy.
+pub const OPTIMISM_SUPERCHAIN_ERC20_FACTORY_ADDRESS: Address =
+    address!("0x4200000000000000000000000000000000000026");
+
+/// The address of the `OptimismSuperchainERC20Beacon` predeploy.
+pub const OPTIMISM_SUPERCHAIN_ERC20_BEACON_ADDRESS: Address =
+    address!("0x4200000000000000000000000000000000000027");
+
+/// The address of the `SuperchainTokenBridge` predeploy.
+pub const SUPERCHAIN_TOKEN_BRIDGE_ADDRESS: Address =
+    address!("0x4200000000000000000000000000000000000028");
+
+/// The address of the `GovernanceToken` predeploy.
+pub const GOVERNANCE_TOKEN_ADDRESS: Address =
+    address!("0x4200000000000000000000000000000000000042");
+
+/// The address of the EIP-4788 beacon block roots contract, deployed by the Ecotone upgrade
+/// transactions.
+pub const BEACON_BLOCK_ROOT_ADDRESS: Address =
+    address!("0x000f3df6d732807ef1319fb7b8bb8522d0beac02");
+
+/// The address of the EIP-2935 block hash history contract, deployed by the Isthmus upgrade
+/// transactions.
+pub const HISTORY_STORAGE_ADDRESS: Address = address!("0x0000f90827f1c53a10cb7a02335b175320002935");
+
+/// An entry of the predeploy registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Predeploy {
+    /// The contract name.
+    pub name: &'static str,
+    /// The address of the predeploy.
+    pub address: Address,
+    /// Whether the predeploy is a proxy to an implementation contract.
+    pub proxied: bool,
+    /// Whether the predeploy is deprecated.
+    pub deprecated: bool,
+    /// The hardfork that introduced the predeploy.
+    ///
+    /// Predeploys that already existed on the legacy network are reported as introduced in
+    /// Bedrock.
+    pub introduced: OpHardfork,
+}
+
+impl Predeploy {
+    /// All known predeploys, sorted by address.
+    pub const ALL: [Self; 30] = [
+        Self::HISTORY_STORAGE,
+        Self::BEACON_BLOCK_ROOT,
+        Self::LEGACY_MESSAGE_PASSER,
+        Self::DEPLOYER_WHITELIST,
+        Self::WETH9,
+        Self::L2_CROSS_DOMAIN_MESSENGER,
+        Self::GAS_PRICE_ORACLE,
+        Self::L2_STANDARD_BRIDGE,
+        Self::SEQUENCER_FEE_VAULT,
+        Self::OPTIMISM_MINTABLE_ERC20_FACTORY,
+        Self::L1_BLOCK_NUMBER,
+        Self::L2_ERC721_BRIDGE,
+        Self::L1_BLOCK,
+        Self::L2_TO_L1_MESSAGE_PASSER,
+        Self::OPTIMISM_MINTABLE_ERC721_FACTORY,
+        Self::PROXY_ADMIN,
+        Self::BASE_FEE_VAULT,
+        Self::L1_FEE_VAULT,
+        Self::OPERATOR_FEE_VAULT,
+        Self::SCHEMA_REGISTRY,
+        Self::EAS,
+        Self::CROSS_L2_INBOX,
+        Self::L2_TO_L2_CROSS_DOMAIN_MESSENGER,
+        Self::SUPERCHAIN_ETH_BRIDGE,
+        Self::ETH_LIQUIDITY,
+        Self::OPTIMISM_SUPERCHAIN_ERC20_FACTORY,
+        Self::OPTIMISM_SUPERCHAIN_ERC20_BEACON,
+        Self::SUPERCHAIN_TOKEN_BRIDGE,
+        Self::GOVERNANCE_TOKEN,
+        Self::LEGACY_ERC20_ETH,
+    ];
+
+    /// The `LegacyMessagePasser` predeploy, which stored withdrawal commitments before Bedrock.
+    pub const LEGACY_MESSAGE_PASSER: Self =
+        Self::legacy("LegacyMessagePasser", LEGACY_MESSAGE_PASSER_ADDRESS, true, true);
+
+    /// The `DeployerWhitelist` predeploy.
+    pub const DEPLOYER_WHITELIST: Self =
+        Self::legacy("DeployerWhitelist", DEPLOYER_WHITELIST_ADDRESS, true, true);
+
+    /// The `LegacyERC20ETH` predeploy, which represented ether before Bedrock.
+    pub const LEGACY_ERC20_ETH: Self =
+        Self::legacy("LegacyERC20ETH", LEGACY_ERC20_ETH_ADDRESS, false, true);
+
+    /// The `WETH9` predeploy.
+    pub const WETH9: Self = Self::legacy("WETH9", WETH9_ADDRESS, false, false);
+
+    /// The `L2CrossDomainMessenger` predeploy.
+    pub const L2_CROSS_DOMAIN_MESSENGER: Self =
+        Self::legacy("L2CrossDomainMessenger", L2_CROSS_DOMAIN_MESSENGER_ADDRESS, true, false);
+
+    /// The `GasPriceOracle` predeploy.
+    pub const GAS_PRICE_ORACLE: Self =
+        Self::legacy("GasPriceOracle", GAS_PRICE_ORACLE_ADDRESS, true, false);
+
+    /// The `L2StandardBridge` predeploy.
+    pub const L2_STANDARD_BRIDGE: Self =
+        Self::legacy("L2StandardBridge", L2_STANDARD_BRIDGE_ADDRESS, true, false);
+
+    /// The `SequencerFeeVault` predeploy.
+    pub const SEQUENCER_FEE_VAULT: Self =
+        Self::legacy("SequencerFeeVault", SEQUENCER_FEE_VAULT_ADDRESS, true, false);
+
+    /// The `OptimismMintableERC20Factory` predeploy.
+    pub const OPTIMISM_MINTABLE_ERC20_FACTORY: Self = Self::legacy(
+        "OptimismMintableERC20Factory",
+        OPTIMISM_MINTABLE_ERC20_FACTORY_ADDRESS,
+        true,
+        false,
+    );
+
+    /// The `L1BlockNumber` predeploy.
+    pub const L1_BLOCK_NUMBER: Self =
+        Self::legacy("L1BlockNumber", L1_BLOCK_NUMBER_ADDRESS, true, true);
+
+    /// The `L2ERC721Bridge` predeploy.
+    pub const L2_ERC721_BRIDGE: Self =
+        Self::legacy("L2ERC721Bridge", L2_ERC721_BRIDGE_ADDRESS, true, false);
+
+    /// The `GovernanceToken` predeploy.
+    pub const GOVERNANCE_TOKEN: Self =
+        Self::legacy("GovernanceToken", GOVERNANCE_TOKEN_ADDRESS, false, false);
+
+    /// The `L1Block` predeploy, which exposes the L1 attributes of the current L2 block.
+    pub const L1_BLOCK: Self = Self::new("L1Block", L1_BLOCK_ADDRESS, OpHardfork::Bedrock);
+
+    /// The `L2ToL1MessagePasser` predeploy, which stores commitments to withdrawals.
+    pub const L2_TO_L1_MESSAGE_PASSER: Self =
+        Self::new("L2ToL1MessagePasser", L2_TO_L1_MESSAGE_PASSER_ADDRESS, OpHardfork::Bedrock);
+
+    /// The `OptimismMintableERC721Factory` predeploy.
+    pub const OPTIMISM_MINTABLE_ERC721_FACTORY: Self = Self::new(
+        "OptimismMintableERC721Factory",
+        OPTIMISM_MINTABLE_ERC721_FACTORY_ADDRESS,
+        OpHardfork::Bedrock,
+    );
+
+    /// The `ProxyAdmin` predeploy, which owns the other predeploy proxies.
+    pub const PROXY_ADMIN: Self = Self::new("ProxyAdmin", PROXY_ADMIN_ADDRESS, OpHardfork::Bedrock);
+
+    /// The `BaseFeeVault` predeploy, which receives the base fees.
+    pub const BASE_FEE_VAULT: Self =
+        Self::new("BaseFeeVault", BASE_FEE_VAULT_ADDRESS, OpHardfork::Bedrock);
+
+    /// The `L1FeeVault` predeploy, which receives the L1 data fees.
+    pub const L1_FEE_VAULT: Self =
+        Self::new("L1FeeVault", L1_FEE_VAULT_ADDRESS, OpHardfork::Bedrock);
+
+    /// The `OperatorFeeVault` predeploy, which receives the operator fees.
+    pub const OPERATOR_FEE_VAULT: Self =
+        Self::new("OperatorFeeVault", OPERATOR_FEE_VAULT_ADDRESS, OpHardfork::Isthmus);
+
+    /// The `SchemaRegistry` predeploy of the Ethereum Attestation Service.
+    pub const SCHEMA_REGISTRY: Self =
+        Self::new("SchemaRegistry", SCHEMA_REGISTRY_ADDRESS, OpHardfork::Bedrock);
+
+    /// The `EAS` predeploy of the Ethereum Attestation Service.
+    pub const EAS: Self = Self::new("EAS", EAS_ADDRESS, OpHardfork::Bedrock);
+
+    /// The EIP-4788 beacon block roots contract.
+    pub const BEACON_BLOCK_ROOT: Self = Self {
+        name: "BeaconBlockRoot",
+        address: BEACON_BLOCK_ROOT_ADDRESS,
+        proxied: false,
+        deprecated: false,
+        introduced: OpHardfork::Ecotone,
+    };
+
+    /// The EIP-2935 block hash history contract.
+    pub const HISTORY_STORAGE: Self = Self {
+        name: "HistoryStorage",
+        address: HISTORY_STORAGE_ADDRESS,
+        proxied: false,
+        deprecated: false,
+        introduced: OpHardfork::Isthmus,
+    };
+
+    /// The `CrossL2Inbox` predeploy, which validates executing messages.
+    pub const CROSS_L2_INBOX: Self =
+        Self::new("CrossL2Inbox", CROSS_L2_INBOX_ADDRESS, OpHardfork::Interop);
+
+    /// The `L2ToL2CrossDomainMessenger` predeploy, which sends and relays cross-chain messages.
+    pub const L2_TO_L2_CROSS_DOMAIN_MESSENGER: Self = Self::new(
+        "L2ToL2CrossDomainMessenger",
+        L2_TO_L2_CROSS_DOMAIN_MESSENGER_ADDRESS,
+        OpHardfork::Interop,
+    );
+
+    /// The `SuperchainETHBridge` predeploy, which sends and relays ether across chains.
+    pub const SUPERCHAIN_ETH_BRIDGE: Self =
+        Self::new("SuperchainETHBridge", SUPERCHAIN_ETH_BRIDGE_ADDRESS, OpHardfork::Interop);
+
+    /// The `ETHLiquidity` predeploy, which holds the ether bridged across chains.
+    pub const ETH_LIQUIDITY: Self =
+        Self::new("ETHLiquidity", ETH_LIQUIDITY_ADDRESS, OpHardfork::Interop);
+
+    /// The `OptimismSuperchainERC20Factory` predeploy, which deploys superchain tokens.
+    pub const OPTIMISM_SUPERCHAIN_ERC20_FACTORY: Self = Self::new(
+        "OptimismSuperchainERC20Factory",
+        OPTIMISM_SUPERCHAIN_ERC20_FACTORY_ADDRESS,
+        OpHardfork::Interop,
+    );
+
+    /// The `OptimismSuperchainERC20Beacon` predeploy, the beacon of the superchain tokens.
+    pub const OPTIMISM_SUPERCHAIN_ERC20_BEACON: Self = Self::new(
+        "OptimismSuperchainERC20Beacon",
+        OPTIMISM_SUPERCHAIN_ERC20_BEACON_ADDRESS,
+        OpHardfork::Interop,
+    );
+
+    /// The `SuperchainTokenBridge` predeploy, which sends and relays superchain tokens.
+    pub const SUPERCHAIN_TOKEN_BRIDGE: Self =
+        Self::new("SuperchainTokenBridge", SUPERCHAIN_TOKEN_BRIDGE_ADDRESS, OpHardfork::Interop);
+
+    /// Creates a proxied, non-deprecated predeploy entry.
+    const fn new(name: &'static str, address: Address, introduced: OpHardfork) -> Self {
+        Self { name, address, proxied: true, deprecated: false, introduced }
+    }
+
+    /// Creates an entry for a predeploy of the legacy network.
+    const fn legacy(name: &'static str, address: Address, proxied: bool, deprecated: bool) -> Self {
+        Self { name, address, proxied, deprecated, introduced: OpHardfork::Bedrock }
+    }
+
+    /// Returns the predeploy at `address`, if any.
+    pub fn from_address(address: Address) -> Option<Self> {
+        Self::ALL.into_iter().find(|predeploy| predeploy.address == address)
+    }
+
+    /// Returns the predeploy with the contract name `name`, if any.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|predeploy| predeploy.name == name)
+    }
+
+    /// Returns whether the predeploy exists once `hardfork` is active.
+    pub const fn is_active_in(&self, hardfork: OpHardfork) -> bool {
+        hardfork.is_enabled_in(self.introduced)
+    }
+}
+
+/// Returns whether `address` is one of the known predeploys.
+pub fn is_predeploy(address: Address) -> bool {
+    Predeploy::from_address(address).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_predeploys_sorted_and_unique() {
+        assert!(Predeploy::ALL.windows(2).all(|w| w[0].address < w[1].address));
+        for predeploy in Predeploy::ALL {
+            assert_eq!(Predeploy::from_name(predeploy.name), Some(predeploy));
+        }
+    }
+
+    #[test]
+    fn test_predeploy_lookup() {
+        assert_eq!(Predeploy::from_address(L1_BLOCK_ADDRESS), Some(Predeploy::L1_BLOCK));
+        assert_eq!(Predeploy::from_name("GasPriceOracle"), Some(Predeploy::GAS_PRICE_ORACLE));
+        assert_eq!(
+            Predeploy::from_address(address!("0x4200000000000000000000000000000000000028")),
+            Some(Predeploy::SUPERCHAIN_TOKEN_BRIDGE)
+        );
+        assert!(is_predeploy(address!("0x4200000000000000000000000000000000000019")));
+        assert!(!is_predeploy(address!("0x4200000000000000000000000000000000000001")));
+        assert!(!is_predeploy(Address::ZERO));
+    }
+
+    #[test]
+    fn test_predeploy_is_active_in() {
+        assert!(Predeploy::L1_BLOCK.is_active_in(OpHardfork::Bedrock));
+        assert!(!Predeploy::OPERATOR_FEE_VAULT.is_active_in(OpHardfork::Holocene));
+        assert!(Predeploy::OPERATOR_FEE_VAULT.is_active_in(OpHardfork::Jovian));
+        assert!(!Predeploy::CROSS_L2_INBOX.is_active_in(OpHardfork::Jovian));
+        assert!(Predeploy::HISTORY_STORAGE.is_active_in(OpHardfork::Isthmus));
+        assert!(Predeploy::SUPERCHAIN_TOKEN_BRIDGE.is_active_in(OpHardfork::Interop));
+    }
+}
@@ -11,6 +11,9 @@ pub use deposit::{OpDepositReceipt, OpDepositReceiptWithBloom};
 pub(crate) mod receipt;
 pub use receipt::OpReceipt;
 
+mod root;
+pub use root::{calculate_receipt_root_op, receipts_logs_bloom};
+
 /// Receipt is the result of a transaction execution.
 pub trait OpTxReceipt: TxReceipt {
     /// Returns the deposit nonce of the transaction.
@@ -0,0 +1,114 @@
+//! Receipts root and logs bloom of a block.
+
+use super::OpReceipt;
+use crate::{OpHardfork, OpHardforks};
+use alloy_consensus::{Eip2718EncodableReceipt, TxReceipt, proofs::ordered_trie_root_with_encoder};
+use alloy_primitives::{B256, Bloom};
+
+/// Calculates the receipts root of a block from its receipts, given the hardfork schedule of the
+/// chain and the timestamp of the block.
+///
+/// op-geth omitted the deposit nonce from the encoding of deposit receipts when computing the
+/// receipts root of Regolith blocks. Canyon fixed the encoding, and introduced the deposit
+/// receipt version, so the deposit nonces are stripped from the receipts of Regolith blocks that
+/// predate Canyon to match the committed `receipts_root`.
+pub fn calculate_receipt_root_op(
+    receipts: &[OpReceipt],
+    schedule: &OpHardforks,
+    timestamp: u64,
+) -> B256 {
+    let strip_deposit_nonce = schedule.active_fork_at(timestamp) == OpHardfork::Regolith;
+    ordered_trie_root_with_encoder(receipts, |receipt, buf| {
+        let bloom = receipt.bloom();
+        match receipt {
+            OpReceipt::Deposit(deposit) if strip_deposit_nonce => {
+                let mut deposit = deposit.clone();
+                deposit.deposit_nonce = None;
+                OpReceipt::Deposit(deposit).eip2718_encode_with_bloom(&bloom, buf)
+            }
+            _ => receipt.eip2718_encode_with_bloom(&bloom, buf),
+        }
+    })
+}
+
+/// Returns the logs bloom of a block, i.e. the union of the logs blooms of its receipts.
+pub fn receipts_logs_bloom<'a>(receipts: impl IntoIterator<Item = &'a OpReceipt>) -> Bloom {
+    receipts.into_iter().fold(Bloom::ZERO, |mut bloom, receipt| {
+        for log in receipt.logs() {
+            bloom.accrue_log(log);
+        }
+        bloom
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OpDepositReceipt;
+    use alloc::{vec, vec::Vec};
+    use alloy_consensus::{
+        EMPTY_ROOT_HASH, Receipt, ReceiptWithBloom, proofs::calculate_receipt_root,
+    };
+    use alloy_primitives::{Address, Log, LogData, logs_bloom};
+
+    fn log(address: u8, topic: u8) -> Log {
+        Log {
+            address: Address::repeat_byte(address),
+            data: LogData::new_unchecked(vec![B256::repeat_byte(topic)], Default::default()),
+        }
+    }
+
+    fn receipts() -> Vec<OpReceipt> {
+        vec![
+            OpReceipt::Deposit(OpDepositReceipt {
+                inner: Receipt { status: true.into(), cumulative_gas_used: 50_000, logs: vec![] },
+                deposit_nonce: Some(7),
+                deposit_receipt_version: None,
+            }),
+            OpReceipt::Eip1559(Receipt {
+                status: true.into(),
+                cumulative_gas_used: 100_000,
+                logs: vec![log(1, 2), log(3, 4)],
+            }),
+            OpReceipt::Legacy(Receipt {
+                status: false.into(),
+                cumulative_gas_used: 121_000,
+                logs: vec![log(5, 6)],
+            }),
+        ]
+    }
+
+    fn root_with_blooms(receipts: Vec<OpReceipt>) -> B256 {
+        let receipts: Vec<_> = receipts.into_iter().map(ReceiptWithBloom::from).collect();
+        calculate_receipt_root(&receipts)
+    }
+
+    #[test]
+    fn test_receipt_root_canyon() {
+        let schedule = OpHardforks::default()
+            .with_activation(OpHardfork::Regolith, 0)
+            .with_activation(OpHardfork::Canyon, 100);
+        assert_eq!(calculate_receipt_root_op(&[], &schedule, 0), EMPTY_ROOT_HASH);
+
+        let expected = root_with_blooms(receipts());
+        assert_eq!(calculate_receipt_root_op(&receipts(), &schedule, 100), expected);
+
+        // Before Canyon, the deposit nonce is not part of the receipts root.
+        let mut stripped = receipts();
+        let OpReceipt::Deposit(deposit) = &mut stripped[0] else { unreachable!() };
+        deposit.deposit_nonce = None;
+        let regolith = calculate_receipt_root_op(&receipts(), &schedule, 99);
+        assert_ne!(regolith, expected);
+        assert_eq!(regolith, root_with_blooms(stripped));
+    }
+
+    #[test]
+    fn test_receipts_logs_bloom() {
+        let receipts = receipts();
+        assert_eq!(receipts_logs_bloom(&[]), Bloom::ZERO);
+        assert_eq!(
+            receipts_logs_bloom(&receipts),
+            logs_bloom(receipts.iter().flat_map(|receipt| receipt.logs()))
+        );
+    }
+}
@@ -0,0 +1,618 @@
+//! The L1 `SystemConfig` contract parameters that drive derivation, and their updates.
+//!
+//! The `SystemConfig` contract emits a `ConfigUpdate` log whenever one of its parameters changes.
+//! The config in effect for the L2 blocks of an epoch is the genesis config with the updates of
+//! all the L1 blocks up to and including the L1 origin of the epoch applied in order.
+//!
+//! See also: <https://specs.optimism.io/protocol/system-config.html>
+
+use crate::eip1559::decode_eip_1559_params;
+use alloc::{collections::BTreeMap, vec::Vec};
+use alloy_consensus::TxReceipt;
+use alloy_primitives::{Address, B64, B256, Bytes, Log, LogData, U256, b256};
+
+/// The signature of the config update event emitted by the `SystemConfig` contract.
+pub const CONFIG_UPDATE_EVENT_ABI: &str = "ConfigUpdate(uint256,uint8,bytes)";
+
+/// `keccak256("ConfigUpdate(uint256,uint8,bytes)")`
+pub const CONFIG_UPDATE_EVENT_ABI_HASH: B256 =
+    b256!("0x1d2b0bda21d56b8bd12d4f94ebacffdfb35f5e226f84b461103bb8beab6353be");
+
+/// The version 0 of the config update event, the only version in use.
+pub const CONFIG_UPDATE_EVENT_VERSION_0: B256 = B256::ZERO;
+
+/// The system config parameters used by derivation.
+///
+/// The serde representation matches the `genesis.system_config` object of op-node's rollup
+/// configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct SystemConfig {
+    /// The address allowed to submit batches.
+    #[cfg_attr(feature = "serde", serde(rename = "batcherAddr"))]
+    pub batcher_address: Address,
+    /// The L1 fee overhead, zeroed from Ecotone onwards.
+    pub overhead: B256,
+    /// The L1 fee scalar. From Ecotone onwards, a versioned encoding of the base fee scalar and
+    /// the blob base fee scalar, see [`SystemConfig::ecotone_scalars`].
+    pub scalar: B256,
+    /// The L2 block gas limit.
+    pub gas_limit: u64,
+    /// The EIP-1559 denominator and elasticity, used from Holocene onwards.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_default"))]
+    pub eip1559_params: B64,
+    /// The operator fee scalar and constant, used from Isthmus onwards.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_default"))]
+    pub operator_fee_params: B256,
+    /// The minimum base fee, used from Jovian onwards.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_default"))]
+    pub min_base_fee: u64,
+    /// The DA footprint gas scalar, used from Jovian onwards.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_default"))]
+    pub da_footprint_gas_scalar: u16,
+}
+
+/// Returns whether `value` is the default value of its type, so that post-Holocene fields are only
+/// serialized when set, like op-node does.
+#[cfg(feature = "serde")]
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+impl SystemConfig {
+    /// Returns the Ecotone `(blob_base_fee_scalar, base_fee_scalar)` encoded in the scalar, or
+    /// `None` if its version is unknown.
+    ///
+    /// Version 0 scalars only carry a base fee scalar, in their last 4 bytes. Version 1 scalars
+    /// carry the blob base fee scalar in bytes 24 to 28 and the base fee scalar in their last 4
+    /// bytes.
+    pub fn ecotone_scalars(&self) -> Option<(u32, u32)> {
+        let base_fee_scalar = u32::from_be_bytes(self.scalar[28..].try_into().unwrap());
+        match self.scalar[0] {
+            0 => Some((0, base_fee_scalar)),
+            1 => {
+                Some((u32::from_be_bytes(self.scalar[24..28].try_into().unwrap()), base_fee_scalar))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the EIP-1559 `(elasticity, denominator)`, see [`decode_eip_1559_params`].
+    pub fn eip1559_elasticity_and_denominator(&self) -> (u32, u32) {
+        decode_eip_1559_params(self.eip1559_params)
+    }
+
+    /// Returns the operator fee scalar, encoded in bytes 20 to 24 of the operator fee params.
+    pub fn operator_fee_scalar(&self) -> u32 {
+        u32::from_be_bytes(self.operator_fee_params[20..24].try_into().unwrap())
+    }
+
+    /// Returns the operator fee constant, encoded in the last 8 bytes of the operator fee params.
+    pub fn operator_fee_constant(&self) -> u64 {
+        u64::from_be_bytes(self.operator_fee_params[24..].try_into().unwrap())
+    }
+
+    /// Applies an update.
+    ///
+    /// From Ecotone onwards, gas config updates with an invalid scalar are ignored, and the
+    /// overhead is zeroed. Unsafe block signer updates are ignored, as the signer is not part of
+    /// the derivation config.
+    pub fn apply_update(&mut self, update: &ConfigUpdate, ecotone_active: bool) {
+        match *update {
+            ConfigUpdate::Batcher(batcher_address) => self.batcher_address = batcher_address,
+            ConfigUpdate::GasConfig { overhead, scalar } => {
+                if ecotone_active {
+                    if !is_valid_ecotone_scalar(scalar) {
+                        return;
+                    }
+                    self.overhead = B256::ZERO;
+                } else {
+                    self.overhead = overhead;
+                }
+                self.scalar = scalar;
+            }
+            ConfigUpdate::GasLimit(gas_limit) => self.gas_limit = gas_limit,
+            ConfigUpdate::UnsafeBlockSigner(_) => {}
+            ConfigUpdate::Eip1559Params(params) => self.eip1559_params = params,
+            ConfigUpdate::OperatorFeeParams(params) => self.operator_fee_params = params,
+            ConfigUpdate::MinBaseFee(min_base_fee) => self.min_base_fee = min_base_fee,
+            ConfigUpdate::DaFootprintGasScalar(scalar) => self.da_footprint_gas_scalar = scalar,
+        }
+    }
+
+    /// Applies the config updates emitted by `system_config_address` in the successful
+    /// `receipts` of an L1 block, in order.
+    ///
+    /// Returns `true` if any update was applied. On error, the updates before the invalid log
+    /// remain applied.
+    pub fn update_with_receipts<'a, R>(
+        &mut self,
+        receipts: impl IntoIterator<Item = &'a R>,
+        system_config_address: Address,
+        ecotone_active: bool,
+    ) -> Result<bool, ConfigUpdateError>
+    where
+        R: TxReceipt<Log = Log> + 'a,
+    {
+        let mut updated = false;
+        for receipt in receipts.into_iter().filter(|receipt| receipt.status()) {
+            for log in receipt.logs() {
+                if log.address != system_config_address
+                    || log.topics().first() != Some(&CONFIG_UPDATE_EVENT_ABI_HASH)
+                {
+                    continue;
+                }
+                self.apply_update(&ConfigUpdate::decode_log(log)?, ecotone_active);
+                updated = true;
+            }
+        }
+        Ok(updated)
+    }
+}
+
+/// Returns `true` if `scalar` is a valid Ecotone scalar: version 0 with only its last 4 bytes
+/// set, or version 1 with only its last 8 bytes set.
+fn is_valid_ecotone_scalar(scalar: B256) -> bool {
+    match scalar[0] {
+        0 => scalar[1..28].iter().all(|b| *b == 0),
+        1 => scalar[1..24].iter().all(|b| *b == 0),
+        _ => false,
+    }
+}
+
+/// An update of the system config, decoded from a `ConfigUpdate` log:
+///
+/// ```solidity
+/// event ConfigUpdate(uint256 indexed version, UpdateType indexed updateType, bytes data);
+/// ```
+///
+/// The data is the ABI encoding of the `abi.encode` of the new values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConfigUpdate {
+    /// A new batcher address, update type 0.
+    Batcher(Address),
+    /// A new L1 fee overhead and scalar, update type 1.
+    GasConfig {
+        /// The L1 fee overhead.
+        overhead: B256,
+        /// The L1 fee scalar.
+        scalar: B256,
+    },
+    /// A new gas limit, update type 2.
+    GasLimit(u64),
+    /// A new unsafe block signer, update type 3.
+    UnsafeBlockSigner(Address),
+    /// New EIP-1559 params, update type 4.
+    Eip1559Params(B64),
+    /// New operator fee params, update type 5.
+    OperatorFeeParams(B256),
+    /// A new minimum base fee, update type 6.
+    MinBaseFee(u64),
+    /// A new DA footprint gas scalar, update type 7.
+    DaFootprintGasScalar(u16),
+}
+
+impl ConfigUpdate {
+    /// Returns the update type of the update.
+    pub const fn update_type(&self) -> u8 {
+        match self {
+            Self::Batcher(_) => 0,
+            Self::GasConfig { .. } => 1,
+            Self::GasLimit(_) => 2,
+            Self::UnsafeBlockSigner(_) => 3,
+            Self::Eip1559Params(_) => 4,
+            Self::OperatorFeeParams(_) => 5,
+            Self::MinBaseFee(_) => 6,
+            Self::DaFootprintGasScalar(_) => 7,
+        }
+    }
+
+    /// Decodes an update from a `ConfigUpdate` log.
+    pub fn decode_log(log: &Log) -> Result<Self, ConfigUpdateError> {
+        let topics = log.topics();
+        if topics.len() != 3 {
+            return Err(ConfigUpdateError::UnexpectedTopicsLen(topics.len()));
+        }
+        if topics[0] != CONFIG_UPDATE_EVENT_ABI_HASH {
+            return Err(ConfigUpdateError::InvalidSelector(topics[0]));
+        }
+        if topics[1] != CONFIG_UPDATE_EVENT_VERSION_0 {
+            return Err(ConfigUpdateError::UnsupportedVersion(topics[1]));
+        }
+        let update_type = U256::from_be_bytes(topics[2].0);
+        let words = match u8::try_from(update_type) {
+            Ok(1) => decode_words::<2>(&log.data.data)?,
+            Ok(0 | 2..=7) => {
+                let [word] = decode_words::<1>(&log.data.data)?;
+                [word, B256::ZERO]
+            }
+            _ => return Err(ConfigUpdateError::UnknownUpdateType(update_type)),
+        };
+        let [word, second] = words;
+        Ok(match update_type.to::<u8>() {
+            0 => Self::Batcher(read_address(word)?),
+            1 => Self::GasConfig { overhead: word, scalar: second },
+            2 => Self::GasLimit(read_uint(word)?),
+            3 => Self::UnsafeBlockSigner(read_address(word)?),
+            4 => Self::Eip1559Params(B64::from(read_uint::<u64>(word)?.to_be_bytes())),
+            5 => Self::OperatorFeeParams(word),
+            6 => Self::MinBaseFee(read_uint(word)?),
+            _ => Self::DaFootprintGasScalar(read_uint(word)?),
+        })
+    }
+
+    /// Encodes the update into the `ConfigUpdate` log that `system_config` emits for it.
+    ///
+    /// This is the inverse of [`ConfigUpdate::decode_log`].
+    pub fn encode_log(&self, system_config: Address) -> Log {
+        let words: &[B256] = match *self {
+            Self::Batcher(address) | Self::UnsafeBlockSigner(address) => &[address.into_word()],
+            Self::GasConfig { overhead, scalar } => &[overhead, scalar],
+            Self::GasLimit(value) | Self::MinBaseFee(value) => &[U256::from(value).into()],
+            Self::Eip1559Params(params) => &[U256::from(u64::from_be_bytes(params.0)).into()],
+            Self::OperatorFeeParams(params) => &[params],
+            Self::DaFootprintGasScalar(scalar) => &[U256::from(scalar).into()],
+        };
+
+        let mut data = Vec::with_capacity(64 + 32 * words.len());
+        data.extend_from_slice(&U256::from(32).to_be_bytes::<32>());
+        data.extend_from_slice(&U256::from(32 * words.len()).to_be_bytes::<32>());
+        for word in words {
+            data.extend_from_slice(word.as_slice());
+        }
+
+        Log {
+            address: system_config,
+            data: LogData::new_unchecked(
+                alloc::vec![
+                    CONFIG_UPDATE_EVENT_ABI_HASH,
+                    CONFIG_UPDATE_EVENT_VERSION_0,
+                    U256::from(self.update_type()).into(),
+                ],
+                Bytes::from(data),
+            ),
+        }
+    }
+}
+
+/// Extracts the `N` words of the `bytes data` argument from the ABI encoded event data, checking
+/// its offset and length.
+fn decode_words<const N: usize>(data: &[u8]) -> Result<[B256; N], ConfigUpdateError> {
+    if data.len() != 64 + 32 * N {
+        return Err(ConfigUpdateError::InvalidDataLength(data.len()));
+    }
+    if U256::from_be_slice(&data[..32]) != U256::from(32) {
+        return Err(ConfigUpdateError::InvalidDataOffset);
+    }
+    if U256::from_be_slice(&data[32..64]) != U256::from(32 * N) {
+        return Err(ConfigUpdateError::InvalidDataOffset);
+    }
+    Ok(core::array::from_fn(|i| B256::from_slice(&data[64 + 32 * i..96 + 32 * i])))
+}
+
+/// Reads an address from an ABI word, checking that it is left-padded with zeros.
+fn read_address(word: B256) -> Result<Address, ConfigUpdateError> {
+    if word[..12].iter().any(|b| *b != 0) {
+        return Err(ConfigUpdateError::InvalidValue(word));
+    }
+    Ok(Address::from_word(word))
+}
+
+/// Reads an unsigned integer from an ABI word, checking that it fits in `T`.
+fn read_uint<T: TryFrom<U256>>(word: B256) -> Result<T, ConfigUpdateError> {
+    T::try_from(U256::from_be_bytes(word.0)).map_err(|_| ConfigUpdateError::InvalidValue(word))
+}
+
+/// The system configs in effect at each L1 block, from the L1 origin of the L2 genesis onwards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemConfigHistory {
+    configs: BTreeMap<u64, SystemConfig>,
+    latest_block: u64,
+}
+
+impl SystemConfigHistory {
+    /// Creates a new history starting with the genesis config, in effect at the L1 block
+    /// `genesis_block`.
+    pub fn new(genesis_block: u64, genesis: SystemConfig) -> Self {
+        Self { configs: BTreeMap::from([(genesis_block, genesis)]), latest_block: genesis_block }
+    }
+
+    /// Returns the number of the latest L1 block whose receipts were applied.
+    pub const fn latest_block(&self) -> u64 {
+        self.latest_block
+    }
+
+    /// Returns the latest config.
+    pub fn latest(&self) -> &SystemConfig {
+        self.configs.values().next_back().expect("the genesis config is always present")
+    }
+
+    /// Returns the config in effect at the L1 block `block_number`, i.e. with the updates of the
+    /// block applied, or `None` if the block precedes the genesis.
+    ///
+    /// Blocks after the latest block get the latest config.
+    pub fn config_at(&self, block_number: u64) -> Option<&SystemConfig> {
+        self.configs.range(..=block_number).next_back().map(|(_, config)| config)
+    }
+
+    /// Applies the config updates of the L1 block `block_number`, see
+    /// [`SystemConfig::update_with_receipts`].
+    ///
+    /// Blocks must be applied in increasing order. Returns `true` if any update was applied.
+    pub fn apply_block<'a, R>(
+        &mut self,
+        block_number: u64,
+        receipts: impl IntoIterator<Item = &'a R>,
+        system_config_address: Address,
+        ecotone_active: bool,
+    ) -> Result<bool, ConfigUpdateError>
+    where
+        R: TxReceipt<Log = Log> + 'a,
+    {
+        if block_number <= self.latest_block {
+            return Err(ConfigUpdateError::BlockNotAfterLatest {
+                block: block_number,
+                latest: self.latest_block,
+            });
+        }
+        let mut config = *self.latest();
+        let updated =
+            config.update_with_receipts(receipts, system_config_address, ecotone_active)?;
+        if updated {
+            self.configs.insert(block_number, config);
+        }
+        self.latest_block = block_number;
+        Ok(updated)
+    }
+}
+
+/// Errors that can occur when decoding or applying `ConfigUpdate` logs.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigUpdateError {
+    /// The log does not have exactly three topics.
+    #[error("Unexpected number of config update topics: {0}")]
+    UnexpectedTopicsLen(usize),
+    /// The first topic is not the config update event selector.
+    #[error("Invalid config update selector: {0}")]
+    InvalidSelector(B256),
+    /// The config update event version is not supported.
+    #[error("Unsupported config update version: {0}")]
+    UnsupportedVersion(B256),
+    /// The update type is unknown.
+    #[error("Unknown config update type: {0}")]
+    UnknownUpdateType(U256),
+    /// The event data length does not match the update type.
+    #[error("Invalid config update data length: {0}")]
+    InvalidDataLength(usize),
+    /// The offset or the length of the `bytes data` argument is invalid.
+    #[error("Invalid config update data offset or length")]
+    InvalidDataOffset,
+    /// A value does not fit in its type.
+    #[error("Invalid config update value: {0}")]
+    InvalidValue(B256),
+    /// The L1 block does not come after the latest applied block.
+    #[error("L1 block {block} is not after the latest block {latest}")]
+    BlockNotAfterLatest {
+        /// The number of the block.
+        block: u64,
+        /// The number of the latest applied block.
+        latest: u64,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{Eip658Value, Receipt};
+    use alloy_primitives::{address, hex, keccak256};
+
+    const SYSTEM_CONFIG: Address = address!("0x229047fed2591dbec1eF1118d64F7aF3dB9EB290");
+
+    fn receipt(status: bool, logs: Vec<Log>) -> Receipt {
+        Receipt { status: Eip658Value::Eip658(status), cumulative_gas_used: 0, logs }
+    }
+
+    #[test]
+    fn test_config_update_event_abi_hash() {
+        assert_eq!(keccak256(CONFIG_UPDATE_EVENT_ABI), CONFIG_UPDATE_EVENT_ABI_HASH);
+    }
+
+    #[test]
+    fn test_config_update_roundtrip() {
+        let updates = [
+            ConfigUpdate::Batcher(Address::repeat_byte(1)),
+            ConfigUpdate::GasConfig {
+                overhead: B256::repeat_byte(2),
+                scalar: B256::repeat_byte(3),
+            },
+            ConfigUpdate::GasLimit(30_000_000),
+            ConfigUpdate::UnsafeBlockSigner(Address::repeat_byte(4)),
+            ConfigUpdate::Eip1559Params(B64::from(hex!("000000fa00000006"))),
+            ConfigUpdate::OperatorFeeParams(B256::repeat_byte(5)),
+            ConfigUpdate::MinBaseFee(1_000_000),
+            ConfigUpdate::DaFootprintGasScalar(400),
+        ];
+        for (i, update) in updates.iter().enumerate() {
+            assert_eq!(update.update_type() as usize, i);
+            let log = update.encode_log(SYSTEM_CONFIG);
+            assert_eq!(ConfigUpdate::decode_log(&log), Ok(*update));
+        }
+    }
+
+    #[test]
+    fn test_decode_batcher_update() {
+        let log = Log {
+            address: SYSTEM_CONFIG,
+            data: LogData::new_unchecked(
+                alloc::vec![
+                    CONFIG_UPDATE_EVENT_ABI_HASH,
+                    CONFIG_UPDATE_EVENT_VERSION_0,
+                    B256::ZERO,
+                ],
+                hex!(
+                    "00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000beef"
+                )
+                .into(),
+            ),
+        };
+        assert_eq!(
+            ConfigUpdate::decode_log(&log),
+            Ok(ConfigUpdate::Batcher(address!("0x000000000000000000000000000000000000beef")))
+        );
+    }
+
+    #[test]
+    fn test_decode_config_update_invalid() {
+        let log = ConfigUpdate::GasLimit(1).encode_log(SYSTEM_CONFIG);
+        let with = |topics: Vec<B256>, data: &[u8]| Log {
+            address: SYSTEM_CONFIG,
+            data: LogData::new_unchecked(topics, Bytes::copy_from_slice(data)),
+        };
+        let topics = log.topics().to_vec();
+        let data = log.data.data;
+
+        assert_eq!(
+            ConfigUpdate::decode_log(&with(topics[..2].to_vec(), &data)),
+            Err(ConfigUpdateError::UnexpectedTopicsLen(2))
+        );
+        let mut invalid = topics.clone();
+        invalid[1] = B256::with_last_byte(1);
+        assert_eq!(
+            ConfigUpdate::decode_log(&with(invalid, &data)),
+            Err(ConfigUpdateError::UnsupportedVersion(B256::with_last_byte(1)))
+        );
+        let mut invalid = topics.clone();
+        invalid[2] = B256::with_last_byte(8);
+        assert_eq!(
+            ConfigUpdate::decode_log(&with(invalid, &data)),
+            Err(ConfigUpdateError::UnknownUpdateType(U256::from(8)))
+        );
+        assert_eq!(
+            ConfigUpdate::decode_log(&with(topics.clone(), &data[..64])),
+            Err(ConfigUpdateError::InvalidDataLength(64))
+        );
+        let mut invalid = data.to_vec();
+        invalid[63] = 64;
+        assert_eq!(
+            ConfigUpdate::decode_log(&with(topics.clone(), &invalid)),
+            Err(ConfigUpdateError::InvalidDataOffset)
+        );
+        let mut invalid = data.to_vec();
+        invalid[64] = 1;
+        assert!(matches!(
+            ConfigUpdate::decode_log(&with(topics, &invalid)),
+            Err(ConfigUpdateError::InvalidValue(_))
+        ));
+    }
+
+    #[test]
+    fn test_apply_gas_config_update() {
+        let bedrock = ConfigUpdate::GasConfig {
+            overhead: B256::with_last_byte(0xbc),
+            scalar: B256::from(U256::from(684_000)),
+        };
+        let mut config = SystemConfig::default();
+        config.apply_update(&bedrock, false);
+        assert_eq!(config.overhead, B256::with_last_byte(0xbc));
+        assert_eq!(config.ecotone_scalars(), Some((0, 684_000)));
+
+        let mut scalar = B256::ZERO;
+        scalar[0] = 1;
+        scalar[24..28].copy_from_slice(&810_949u32.to_be_bytes());
+        scalar[28..].copy_from_slice(&1368u32.to_be_bytes());
+        let ecotone = ConfigUpdate::GasConfig { overhead: B256::repeat_byte(1), scalar };
+        config.apply_update(&ecotone, true);
+        assert_eq!(config.overhead, B256::ZERO);
+        assert_eq!(config.ecotone_scalars(), Some((810_949, 1368)));
+
+        // Invalid Ecotone scalars are ignored.
+        let invalid =
+            ConfigUpdate::GasConfig { overhead: B256::ZERO, scalar: B256::repeat_byte(1) };
+        config.apply_update(&invalid, true);
+        assert_eq!(config.scalar, scalar);
+    }
+
+    #[test]
+    fn test_system_config_accessors() {
+        let mut config = SystemConfig::default();
+        config
+            .apply_update(&ConfigUpdate::Eip1559Params(B64::from(hex!("000000fa00000006"))), true);
+        assert_eq!(config.eip1559_elasticity_and_denominator(), (6, 250));
+
+        let mut params = B256::ZERO;
+        params[20..24].copy_from_slice(&7u32.to_be_bytes());
+        params[24..].copy_from_slice(&9u64.to_be_bytes());
+        config.apply_update(&ConfigUpdate::OperatorFeeParams(params), true);
+        assert_eq!(config.operator_fee_scalar(), 7);
+        assert_eq!(config.operator_fee_constant(), 9);
+
+        config.apply_update(&ConfigUpdate::UnsafeBlockSigner(Address::repeat_byte(1)), true);
+        config.apply_update(&ConfigUpdate::MinBaseFee(5), true);
+        config.apply_update(&ConfigUpdate::DaFootprintGasScalar(6), true);
+        assert_eq!((config.min_base_fee, config.da_footprint_gas_scalar), (5, 6));
+    }
+
+    #[test]
+    fn test_system_config_history() {
+        let genesis = SystemConfig { gas_limit: 30_000_000, ..Default::default() };
+        let mut history = SystemConfigHistory::new(100, genesis);
+
+        let batcher = ConfigUpdate::Batcher(Address::repeat_byte(0xba)).encode_log(SYSTEM_CONFIG);
+        let gas_limit = ConfigUpdate::GasLimit(60_000_000).encode_log(SYSTEM_CONFIG);
+        let other = ConfigUpdate::GasLimit(1).encode_log(Address::ZERO);
+        let receipts = [
+            receipt(true, alloc::vec![batcher, other]),
+            receipt(false, alloc::vec![gas_limit.clone()]),
+        ];
+        assert_eq!(history.apply_block(102, &receipts, SYSTEM_CONFIG, true), Ok(true));
+        assert_eq!(
+            history.apply_block(103, &[receipt(true, Vec::new())], SYSTEM_CONFIG, true),
+            Ok(false)
+        );
+        assert_eq!(
+            history.apply_block(105, &[receipt(true, alloc::vec![gas_limit])], SYSTEM_CONFIG, true),
+            Ok(true)
+        );
+        assert_eq!(
+            history.apply_block(104, &[receipt(true, Vec::new())], SYSTEM_CONFIG, true),
+            Err(ConfigUpdateError::BlockNotAfterLatest { block: 104, latest: 105 })
+        );
+
+        assert_eq!(history.config_at(99), None);
+        assert_eq!(history.config_at(101), Some(&genesis));
+        let at_102 = history.config_at(103).unwrap();
+        assert_eq!(at_102.batcher_address, Address::repeat_byte(0xba));
+        assert_eq!(at_102.gas_limit, 30_000_000);
+        assert_eq!(history.config_at(105).unwrap().gas_limit, 60_000_000);
+        assert_eq!(history.latest(), history.config_at(1000).unwrap());
+        assert_eq!(history.latest_block(), 105);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_system_config_serde() {
+        // The `genesis.system_config` of the OP Mainnet rollup configuration.
+        let json = r#"{
+            "batcherAddr": "0x6887246668a3b87f54deb3b94ba47a6f63f32985",
+            "overhead": "0x00000000000000000000000000000000000000000000000000000000000000bc",
+            "scalar": "0x00000000000000000000000000000000000000000000000000000000000a6fe0",
+            "gasLimit": 30000000
+        }"#;
+        let config: SystemConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.batcher_address, address!("0x6887246668a3b87f54deb3b94ba47a6f63f32985"));
+        assert_eq!(config.ecotone_scalars(), Some((0, 684_000)));
+        assert_eq!(config.eip1559_params, B64::ZERO);
+
+        // Unset post-Holocene fields are not serialized.
+        assert_eq!(
+            serde_json::to_value(config).unwrap(),
+            serde_json::from_str::<serde_json::Value>(json).unwrap()
+        );
+
+        let config = SystemConfig { min_base_fee: 1, ..config };
+        let roundtrip: SystemConfig =
+            serde_json::from_str(&serde_json::to_string(&config).unwrap()).unwrap();
+        assert_eq!(roundtrip, config);
+    }
+}
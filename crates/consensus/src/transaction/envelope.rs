@@ -230,6 +230,15 @@ impl OpTxEnvelope {
         matches!(self, Self::Eip1559(_))
     }
 
+    /// Returns the Fjord estimated compressed size of the EIP-2718 encoded transaction, in bytes.
+    ///
+    /// This is the `estimatedSize` used by the Fjord L1 cost function, derived from the FastLZ
+    /// compressed length of the encoding. See [`crate::fee::fjord_estimated_size_scaled`].
+    pub fn fjord_estimated_size(&self) -> u64 {
+        let flz_len = crate::fee::flz_compress_len(&self.encoded_2718());
+        crate::fee::fjord_estimated_size_scaled(flz_len) / 1_000_000
+    }
+
     /// Returns true if the transaction is a system transaction.
     #[inline]
     pub const fn is_system_transaction(&self) -> bool {
@@ -676,8 +685,10 @@ pub mod serde_bincode_compat {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{L1FeeCalculator, L1FeeParams, OpHardfork};
     use alloc::vec;
     use alloy_consensus::{SignableTransaction, Transaction};
+    use alloy_eips::eip2718::Decodable2718;
     use alloy_primitives::{Address, B256, Bytes, Signature, TxKind, U256, hex};
 
     #[test]
@@ -699,6 +710,30 @@ mod tests {
         assert!(!tx_envelope.is_system_transaction());
     }
 
+    // Second transaction of OP Mainnet Fjord block 124665056:
+    // <https://optimistic.etherscan.io/tx/0x1059e8004daff32caa1f1b1ef97fe3a07a8cf40508f5b835b66d9420d87c4a4a>
+    #[test]
+    fn test_fjord_estimated_size() {
+        let raw = hex!(
+            "02f904940a8303fba78401d6d2798401db2b6d830493e0943e6f4f7866654c18f536170780344aa8772950b680b904246a761202000000000000000000000000087000a300de7200382b55d40045000000e5d60e0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000014000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000003a0000000000000000000000000000000000000000000000000000000000000022482ad56cb0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000120000000000000000000000000dc6ff44d5d932cbd77b52e5612ba0529dc6226f1000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000044095ea7b300000000000000000000000021c4928109acb0659a88ae5329b5374a3024694c0000000000000000000000000000000000000000000000049b9ca9a6943400000000000000000000000000000000000000000000000000000000000000000000000000000000000021c4928109acb0659a88ae5329b5374a3024694c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000024b6b55f250000000000000000000000000000000000000000000000049b9ca9a694340000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000415ec214a3950bea839a7e6fbb0ba1540ac2076acd50820e2d5ef83d0902cdffb24a47aff7de5190290769c4f0a9c6fabf63012986a0d590b1b571547a8c7050ea1b00000000000000000000000000000000000000000000000000000000000000c080a06db770e6e25a617fe9652f0958bd9bd6e49281a53036906386ed39ec48eadf63a07f47cf51a4a40b4494cf26efc686709a9b03939e20ee27e59682f5faa536667e"
+        );
+        let tx = OpTxEnvelope::decode_2718(&mut &raw[..]).unwrap();
+        assert_eq!(crate::fee::flz_compress_len(&raw), 385);
+        assert_eq!(tx.fjord_estimated_size(), 279);
+
+        let params = L1FeeParams {
+            l1_base_fee: U256::from(1055991687),
+            l1_base_fee_scalar: U256::from(5227),
+            l1_blob_base_fee: U256::from(1),
+            l1_blob_base_fee_scalar: U256::from(1014213),
+            ..Default::default()
+        };
+        let fee = L1FeeCalculator::new(OpHardfork::Fjord, params).calculate(&tx);
+        // `l1GasUsed` and `l1Fee` as reported by the node.
+        assert_eq!(fee.l1_gas_used, U256::from(4471));
+        assert_eq!(fee.fee, U256::from(0x5bf1ab43du64));
+    }
+
     #[test]
     fn test_system_transaction() {
         let mut tx = TxDeposit { is_system_transaction: true, ..Default::default() };
@@ -0,0 +1,125 @@
+//! Ecotone network upgrade transactions.
+//!
+//! See: <https://specs.optimism.io/protocol/ecotone/derivation.html#network-upgrade-automation-transactions>
+
+use super::{DEPOSITOR_ACCOUNT, UpgradeTransaction};
+use crate::predeploys::{GAS_PRICE_ORACLE_ADDRESS, L1_BLOCK_ADDRESS};
+use alloc::{vec, vec::Vec};
+use alloy_primitives::{Address, TxKind, address, hex};
+
+/// The deployer of the Ecotone `L1Block` implementation.
+const L1_BLOCK_DEPLOYER: Address = address!("0x4210000000000000000000000000000000000000");
+
+/// The deployer of the Ecotone `GasPriceOracle` implementation.
+const GAS_PRICE_ORACLE_DEPLOYER: Address = address!("0x4210000000000000000000000000000000000001");
+
+/// The Ecotone `L1Block` implementation, created by [`L1_BLOCK_DEPLOYER`] with nonce 0.
+const NEW_L1_BLOCK: Address = address!("0x07dbe8500fc591d1852b76fee44d5a05e13097ff");
+
+/// The Ecotone `GasPriceOracle` implementation, created by [`GAS_PRICE_ORACLE_DEPLOYER`] with
+/// nonce 0.
+const NEW_GAS_PRICE_ORACLE: Address = address!("0xb528d11cc114e026f138fe568744c6d45ce6da7a");
+
+/// The sender of the EIP-4788 beacon block roots contract deployment, see
+/// <https://eips.ethereum.org/EIPS/eip-4788#deployment>.
+const EIP4788_FROM: Address = address!("0x0B799C86a49DEeb90402691F1041aa3AF2d3C875");
+
+/// The selector of `setEcotone()`.
+const SET_ECOTONE_INPUT: [u8; 4] = hex!("22b90ab3");
+
+/// Returns the Ecotone upgrade transactions.
+pub(super) fn transactions() -> Vec<UpgradeTransaction> {
+    vec![
+        UpgradeTransaction::deploy(
+            "Ecotone: L1 Block Deployment",
+            L1_BLOCK_DEPLOYER,
+            375_000,
+            include_bytes!("bytecode/l1_block_ecotone.bin"),
+        ),
+        UpgradeTransaction::deploy(
+            "Ecotone: Gas Price Oracle Deployment",
+            GAS_PRICE_ORACLE_DEPLOYER,
+            1_000_000,
+            include_bytes!("bytecode/gpo_ecotone.bin"),
+        ),
+        UpgradeTransaction::upgrade_proxy(
+            "Ecotone: L1 Block Proxy Update",
+            L1_BLOCK_ADDRESS,
+            NEW_L1_BLOCK,
+        ),
+        UpgradeTransaction::upgrade_proxy(
+            "Ecotone: Gas Price Oracle Proxy Update",
+            GAS_PRICE_ORACLE_ADDRESS,
+            NEW_GAS_PRICE_ORACLE,
+        ),
+        UpgradeTransaction::new(
+            "Ecotone: Gas Price Oracle Set Ecotone",
+            DEPOSITOR_ACCOUNT,
+            TxKind::Call(GAS_PRICE_ORACLE_ADDRESS),
+            80_000,
+            SET_ECOTONE_INPUT.to_vec(),
+        ),
+        UpgradeTransaction::deploy(
+            "Ecotone: beacon block roots contract deployment",
+            EIP4788_FROM,
+            250_000,
+            include_bytes!("bytecode/eip4788_ecotone.bin"),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OpHardfork;
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::b256;
+
+    #[test]
+    fn test_ecotone_implementation_addresses() {
+        assert_eq!(L1_BLOCK_DEPLOYER.create(0), NEW_L1_BLOCK);
+        assert_eq!(GAS_PRICE_ORACLE_DEPLOYER.create(0), NEW_GAS_PRICE_ORACLE);
+    }
+
+    #[test]
+    fn test_ecotone_source_hashes() {
+        let txs = transactions();
+        assert_eq!(
+            txs[0].tx.source_hash,
+            b256!("0x877a6077205782ea15a6dc8699fa5ebcec5e0f4389f09cb8eda09488231346f8")
+        );
+        assert_eq!(
+            txs[5].tx.source_hash,
+            b256!("0x69b763c48478b9dc2f65ada09b3d92133ec592ea715ec65ad6e7f3dc519dc00c")
+        );
+    }
+
+    #[test]
+    fn test_ecotone_transactions() {
+        let txs = transactions();
+        assert_eq!(
+            txs[2].tx.encoded_2718(),
+            hex!(
+                "7ef876a018acb38c5ff1c238a7460ebc1b421fa49ec4874bdf1e0a530d234104e5e67dbc940000000000000000000000000000000000000000944200000000000000000000000000000000000015808082c35080a43659cfe600000000000000000000000007dbe8500fc591d1852b76fee44d5a05e13097ff"
+            )
+        );
+        assert_eq!(
+            txs[4].tx.encoded_2718(),
+            hex!(
+                "7ef857a00c1cb38e99dbc9cbfab3bb80863380b0905290b37eb3d6ab18dc01c1f3e75f9394deaddeaddeaddeaddeaddeaddeaddeaddead000194420000000000000000000000000000000000000f808083013880808422b90ab3"
+            )
+        );
+
+        super::super::tests::assert_encoded_hashes(
+            OpHardfork::Ecotone,
+            &[
+                b256!("0x50549bed9a83ba7238893970780b458ed3d543f299d6f69f7a5d0b32d7cd71a7"),
+                b256!("0xf8e8dd83c100c9eb137fa372cdda2cf854f79b6e8cfc604c5e54d970c05cb6dc"),
+                b256!("0x9f2b2d34dfa2cb55cceb9860cade0cb03cfbd7ff1dd07d48b4708b29a46b4a24"),
+                b256!("0xedbfdad1f44ea830b863eac45eca4408398351b30511826012882059625963d2"),
+                b256!("0x91f762393442ac507c6852211b62445d82c9b58bae34726ecef2f10b4156f027"),
+                b256!("0x5b7915f2a55ce67519e6d8f65248918f4e62f545706af2bf02549bb9782c03f3"),
+            ],
+        );
+    }
+}
@@ -0,0 +1,85 @@
+//! Fjord network upgrade transactions.
+//!
+//! See: <https://specs.optimism.io/protocol/fjord/derivation.html#network-upgrade-automation-transactions>
+
+use super::{DEPOSITOR_ACCOUNT, UpgradeTransaction};
+use crate::predeploys::GAS_PRICE_ORACLE_ADDRESS;
+use alloc::{vec, vec::Vec};
+use alloy_primitives::{Address, TxKind, address, hex};
+
+/// The deployer of the Fjord `GasPriceOracle` implementation.
+const GAS_PRICE_ORACLE_DEPLOYER: Address = address!("0x4210000000000000000000000000000000000002");
+
+/// The Fjord `GasPriceOracle` implementation, created by [`GAS_PRICE_ORACLE_DEPLOYER`] with
+/// nonce 0.
+const NEW_GAS_PRICE_ORACLE: Address = address!("0xa919894851548179a0750865e7974da599c0fac7");
+
+/// The selector of `setFjord()`.
+const SET_FJORD_INPUT: [u8; 4] = hex!("8e98b106");
+
+/// Returns the Fjord upgrade transactions.
+pub(super) fn transactions() -> Vec<UpgradeTransaction> {
+    vec![
+        UpgradeTransaction::deploy(
+            "Fjord: Gas Price Oracle Deployment",
+            GAS_PRICE_ORACLE_DEPLOYER,
+            1_450_000,
+            include_bytes!("bytecode/gpo_fjord.bin"),
+        ),
+        UpgradeTransaction::upgrade_proxy(
+            "Fjord: Gas Price Oracle Proxy Update",
+            GAS_PRICE_ORACLE_ADDRESS,
+            NEW_GAS_PRICE_ORACLE,
+        ),
+        UpgradeTransaction::new(
+            "Fjord: Gas Price Oracle Set Fjord",
+            DEPOSITOR_ACCOUNT,
+            TxKind::Call(GAS_PRICE_ORACLE_ADDRESS),
+            90_000,
+            SET_FJORD_INPUT.to_vec(),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OpHardfork;
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::b256;
+
+    #[test]
+    fn test_fjord_implementation_addresses() {
+        assert_eq!(GAS_PRICE_ORACLE_DEPLOYER.create(0), NEW_GAS_PRICE_ORACLE);
+    }
+
+    #[test]
+    fn test_fjord_transactions() {
+        let txs = transactions();
+        assert_eq!(
+            txs[0].tx.source_hash,
+            b256!("0x86122c533fdcb89b16d8713174625e44578a89751d96c098ec19ab40a51a8ea3")
+        );
+        assert_eq!(
+            txs[1].tx.encoded_2718(),
+            hex!(
+                "7ef876a01e6bb0c28bfab3dc9b36ffb0f721f00d6937f33577606325692db0965a7d58c694000000000000000000000000000000000000000094420000000000000000000000000000000000000f808082c35080a43659cfe6000000000000000000000000a919894851548179a0750865e7974da599c0fac7"
+            )
+        );
+        assert_eq!(
+            txs[2].tx.encoded_2718(),
+            hex!(
+                "7ef857a0bac7bb0d5961cad209a345408b0280a0d4686b1b20665e1b0f9cdafd73b19b6b94deaddeaddeaddeaddeaddeaddeaddeaddead000194420000000000000000000000000000000000000f808083015f9080848e98b106"
+            )
+        );
+
+        super::super::tests::assert_encoded_hashes(
+            OpHardfork::Fjord,
+            &[
+                b256!("0xaafd55b14e021a2c04628606e42dbe58cc9c86b6ed695a8b9ccf258e28963e5e"),
+                b256!("0x2f63bbe08a66796dde569281a58a53e0b0f64b7aa067297e9c70455fb8e375bc"),
+                b256!("0x7cedc4f1c428928cdfb9b340bda3060102c71ad23f982678471b222ba8a756e1"),
+            ],
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//! Interop network upgrade transactions.
+//!
+//! See: <https://specs.optimism.io/interop/derivation.html#network-upgrade-transactions>
+
+use super::UpgradeTransaction;
+use crate::predeploys::{CROSS_L2_INBOX_ADDRESS, L2_TO_L2_CROSS_DOMAIN_MESSENGER_ADDRESS};
+use alloc::{vec, vec::Vec};
+use alloy_primitives::{Address, address};
+
+/// The deployer of the `CrossL2Inbox` implementation.
+const CROSS_L2_INBOX_DEPLOYER: Address = address!("0x4220000000000000000000000000000000000000");
+
+/// The deployer of the `L2ToL2CrossDomainMessenger` implementation.
+const L2_TO_L2_XDM_DEPLOYER: Address = address!("0x4220000000000000000000000000000000000001");
+
+/// The `CrossL2Inbox` implementation, created by [`CROSS_L2_INBOX_DEPLOYER`] with nonce 0.
+const NEW_CROSS_L2_INBOX: Address = address!("0x691300f512e48B463C2617b34Eef1A9f82EE7dBf");
+
+/// The `L2ToL2CrossDomainMessenger` implementation, created by [`L2_TO_L2_XDM_DEPLOYER`] with
+/// nonce 0.
+const NEW_L2_TO_L2_XDM: Address = address!("0x0D0eDd0ebd0e94d218670a8De867Eb5C4d37cadD");
+
+/// Returns the Interop upgrade transactions.
+pub(super) fn transactions() -> Vec<UpgradeTransaction> {
+    vec![
+        UpgradeTransaction::deploy(
+            "Interop: CrossL2Inbox Deployment",
+            CROSS_L2_INBOX_DEPLOYER,
+            420_000,
+            include_bytes!("bytecode/crossl2inbox_interop.bin"),
+        ),
+        UpgradeTransaction::upgrade_proxy(
+            "Interop: CrossL2Inbox Proxy Update",
+            CROSS_L2_INBOX_ADDRESS,
+            NEW_CROSS_L2_INBOX,
+        ),
+        UpgradeTransaction::deploy(
+            "Interop: L2ToL2CrossDomainMessenger Deployment",
+            L2_TO_L2_XDM_DEPLOYER,
+            1_100_000,
+            include_bytes!("bytecode/l2tol2_xdm_interop.bin"),
+        ),
+        UpgradeTransaction::upgrade_proxy(
+            "Interop: L2ToL2CrossDomainMessenger Proxy Update",
+            L2_TO_L2_CROSS_DOMAIN_MESSENGER_ADDRESS,
+            NEW_L2_TO_L2_XDM,
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OpHardfork;
+    use alloy_primitives::b256;
+
+    #[test]
+    fn test_interop_implementation_addresses() {
+        assert_eq!(CROSS_L2_INBOX_DEPLOYER.create(0), NEW_CROSS_L2_INBOX);
+        assert_eq!(L2_TO_L2_XDM_DEPLOYER.create(0), NEW_L2_TO_L2_XDM);
+    }
+
+    #[test]
+    fn test_interop_transactions() {
+        super::super::tests::assert_encoded_hashes(
+            OpHardfork::Interop,
+            &[
+                b256!("0x8cdc90c426f7e1e0e2883a26dc0ce5852ad3ed0c80cbbbf47e9b0db9f03df2f2"),
+                b256!("0x64f6708c0b1a8c2b0d36ffd05ab0417503a0d2685e95b2a04b30030438d7b31d"),
+                b256!("0x72e4ff53a248bdaf6ed6823a02c2abc36358d3a4d10ec3b632b1937f1ea215a3"),
+                b256!("0x6478a69a598ce7dd39b32a2d9bfe02eebb94e30d898eac79b2aa51acaf5cc9cf"),
+            ],
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Isthmus network upgrade transactions.
+//!
+//! See: <https://specs.optimism.io/protocol/isthmus/derivation.html#network-upgrade-automation-transactions>
+
+use super::{DEPOSITOR_ACCOUNT, UpgradeTransaction};
+use crate::predeploys::{GAS_PRICE_ORACLE_ADDRESS, L1_BLOCK_ADDRESS, OPERATOR_FEE_VAULT_ADDRESS};
+use alloc::{vec, vec::Vec};
+use alloy_primitives::{Address, TxKind, address, hex};
+
+/// The deployer of the Isthmus `L1Block` implementation.
+const L1_BLOCK_DEPLOYER: Address = address!("0x4210000000000000000000000000000000000003");
+
+/// The deployer of the Isthmus `GasPriceOracle` implementation.
+const GAS_PRICE_ORACLE_DEPLOYER: Address = address!("0x4210000000000000000000000000000000000004");
+
+/// The deployer of the `OperatorFeeVault` implementation.
+const OPERATOR_FEE_VAULT_DEPLOYER: Address = address!("0x4210000000000000000000000000000000000005");
+
+/// The Isthmus `L1Block` implementation, created by [`L1_BLOCK_DEPLOYER`] with nonce 0.
+const NEW_L1_BLOCK: Address = address!("0xff256497d61dcd71a9e9ff43967c13fde1f72d12");
+
+/// The Isthmus `GasPriceOracle` implementation, created by [`GAS_PRICE_ORACLE_DEPLOYER`] with
+/// nonce 0.
+const NEW_GAS_PRICE_ORACLE: Address = address!("0x93e57a196454cb919193fa9946f14943cf733845");
+
+/// The `OperatorFeeVault` implementation, created by [`OPERATOR_FEE_VAULT_DEPLOYER`] with nonce 0.
+const NEW_OPERATOR_FEE_VAULT: Address = address!("0x4fa2be8cd41504037f1838bce3bcc93bc68ff537");
+
+/// The sender of the EIP-2935 block hash history contract deployment, see
+/// <https://eips.ethereum.org/EIPS/eip-2935#deployment>.
+const EIP2935_FROM: Address = address!("0x3462413Af4609098e1E27A490f554f260213D685");
+
+/// The selector of `setIsthmus()`.
+const SET_ISTHMUS_INPUT: [u8; 4] = hex!("291b0383");
+
+/// Returns the Isthmus upgrade transactions.
+pub(super) fn transactions() -> Vec<UpgradeTransaction> {
+    vec![
+        UpgradeTransaction::deploy(
+            "Isthmus: L1 Block Deployment",
+            L1_BLOCK_DEPLOYER,
+            425_000,
+            include_bytes!("bytecode/l1_block_isthmus.bin"),
+        ),
+        UpgradeTransaction::deploy(
+            "Isthmus: Gas Price Oracle Deployment",
+            GAS_PRICE_ORACLE_DEPLOYER,
+            1_625_000,
+            include_bytes!("bytecode/gpo_isthmus.bin"),
+        ),
+        UpgradeTransaction::deploy(
+            "Isthmus: Operator Fee Vault Deployment",
+            OPERATOR_FEE_VAULT_DEPLOYER,
+            500_000,
+            include_bytes!("bytecode/ofv_isthmus.bin"),
+        ),
+        UpgradeTransaction::upgrade_proxy(
+            "Isthmus: L1 Block Proxy Update",
+            L1_BLOCK_ADDRESS,
+            NEW_L1_BLOCK,
+        ),
+        UpgradeTransaction::upgrade_proxy(
+            "Isthmus: Gas Price Oracle Proxy Update",
+            GAS_PRICE_ORACLE_ADDRESS,
+            NEW_GAS_PRICE_ORACLE,
+        ),
+        UpgradeTransaction::upgrade_proxy(
+            "Isthmus: Operator Fee Vault Proxy Update",
+            OPERATOR_FEE_VAULT_ADDRESS,
+            NEW_OPERATOR_FEE_VAULT,
+        ),
+        UpgradeTransaction::new(
+            "Isthmus: Gas Price Oracle Set Isthmus",
+            DEPOSITOR_ACCOUNT,
+            TxKind::Call(GAS_PRICE_ORACLE_ADDRESS),
+            90_000,
+            SET_ISTHMUS_INPUT.to_vec(),
+        ),
+        UpgradeTransaction::deploy(
+            "Isthmus: EIP-2935 Contract Deployment",
+            EIP2935_FROM,
+            250_000,
+            include_bytes!("bytecode/eip2935_isthmus.bin"),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OpHardfork;
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::b256;
+
+    #[test]
+    fn test_isthmus_implementation_addresses() {
+        assert_eq!(L1_BLOCK_DEPLOYER.create(0), NEW_L1_BLOCK);
+        assert_eq!(GAS_PRICE_ORACLE_DEPLOYER.create(0), NEW_GAS_PRICE_ORACLE);
+        assert_eq!(OPERATOR_FEE_VAULT_DEPLOYER.create(0), NEW_OPERATOR_FEE_VAULT);
+    }
+
+    #[test]
+    fn test_isthmus_transactions() {
+        let txs = transactions();
+        assert_eq!(
+            txs[5].tx.encoded_2718(),
+            hex!(
+                "7ef876a0ad74e1adb877ccbe176b8fa1cc559388a16e090ddbe8b512f5b37d07d887a92794000000000000000000000000000000000000000094420000000000000000000000000000000000001b808082c35080a43659cfe60000000000000000000000004fa2be8cd41504037f1838bce3bcc93bc68ff537"
+            )
+        );
+        assert_eq!(
+            txs[6].tx.encoded_2718(),
+            hex!(
+                "7ef857a03ddf4b1302548dd92939826e970f260ba36167f4c25f18390a5e8b194b29531994deaddeaddeaddeaddeaddeaddeaddeaddead000194420000000000000000000000000000000000000f808083015f908084291b0383"
+            )
+        );
+
+        super::super::tests::assert_encoded_hashes(
+            OpHardfork::Isthmus,
+            &[
+                b256!("0x4557f1142fedd52d571b070a290aa8683a03f7aac7929967858af7aab48cd4cc"),
+                b256!("0x9412bd3fa72eb1dbec94fd2297d44a8b86cb1f55966324e6cc7d57decc92c1f6"),
+                b256!("0xa9e6286eadd53ecf7bb2bff668c4fcb62305a8b3e4b584c30f7d4f90046ee75e"),
+                b256!("0xe992e00998b34075506d2726a274db07a62af6cdd9d527bfda9128114603cfbd"),
+                b256!("0xb6560306ccb0e772b132a8a6dd78244c0d7ac270c80baba40f95006184926c30"),
+                b256!("0xf162acc8ebbaf9237755736b62331a4817fe27bbbe3712f473ecfa8fee499f7c"),
+                b256!("0xbf2fef09f73279373fce8acd2968ca4fd1d10ba88bf10993b6a69c33d913f01b"),
+                b256!("0xf3ddde30514a8d00b0b92f14b9ce6b3170ea30f5f45b1afdc0905801134ffefe"),
+            ],
+        );
+    }
+}
@@ -0,0 +1,135 @@
+//! Network upgrade transactions.
+//!
+//! Some hardforks deploy or upgrade predeploys with a fixed list of deposit transactions that are
+//! inserted in the first block in which the hardfork is active, after the L1 info deposit and the
+//! user deposits. Each of these transactions is identified by its intent, from which its source
+//! hash is derived, see [`UpgradeDepositSource`].
+
+use crate::{OpHardfork, TxDeposit, UpgradeDepositSource};
+use alloc::{string::String, vec::Vec};
+use alloy_primitives::{Address, Bytes, TxKind, U256, address, hex};
+
+mod ecotone;
+mod fjord;
+mod interop;
+mod isthmus;
+
+/// The depositor account of the L1 info deposits, which also sends the upgrade transactions that
+/// enable a hardfork in the `GasPriceOracle`.
+const DEPOSITOR_ACCOUNT: Address = address!("0xDeaDDEaDDeAdDeAdDEAdDEaddeAddEAdDEAd0001");
+
+/// The selector of `upgradeTo(address)`, used to point a predeploy proxy to a new implementation.
+const UPGRADE_TO_SELECTOR: [u8; 4] = hex!("3659cfe6");
+
+/// A deposit transaction that is part of a network upgrade.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UpgradeTransaction {
+    /// The human-readable intent of the transaction, from which its source hash is derived.
+    pub intent: &'static str,
+    /// The deposit transaction.
+    pub tx: TxDeposit,
+}
+
+impl UpgradeTransaction {
+    /// Creates a new upgrade transaction. The source hash is derived from `intent`.
+    fn new(
+        intent: &'static str,
+        from: Address,
+        to: TxKind,
+        gas_limit: u64,
+        input: impl Into<Bytes>,
+    ) -> Self {
+        let source_hash = UpgradeDepositSource::new(String::from(intent)).source_hash();
+        let tx = TxDeposit {
+            source_hash,
+            from,
+            to,
+            mint: 0,
+            value: U256::ZERO,
+            gas_limit,
+            is_system_transaction: false,
+            input: input.into(),
+        };
+        Self { intent, tx }
+    }
+
+    /// Creates a new upgrade transaction that deploys a contract with the creation `bytecode`.
+    fn deploy(
+        intent: &'static str,
+        deployer: Address,
+        gas_limit: u64,
+        bytecode: &'static [u8],
+    ) -> Self {
+        Self::new(intent, deployer, TxKind::Create, gas_limit, Bytes::from_static(bytecode))
+    }
+
+    /// Creates a new upgrade transaction that points the `proxy` predeploy to `implementation`.
+    fn upgrade_proxy(intent: &'static str, proxy: Address, implementation: Address) -> Self {
+        let mut input = UPGRADE_TO_SELECTOR.to_vec();
+        input.extend_from_slice(implementation.into_word().as_slice());
+        Self::new(intent, Address::ZERO, TxKind::Call(proxy), 50_000, input)
+    }
+}
+
+/// Returns the ordered upgrade transactions of `hardfork`.
+///
+/// Hardforks that do not upgrade any contract return an empty list. Returns `None` for Jovian,
+/// whose upgrade transactions are not included in this crate yet.
+pub fn upgrade_transactions(hardfork: OpHardfork) -> Option<Vec<UpgradeTransaction>> {
+    match hardfork {
+        OpHardfork::Ecotone => Some(ecotone::transactions()),
+        OpHardfork::Fjord => Some(fjord::transactions()),
+        OpHardfork::Isthmus => Some(isthmus::transactions()),
+        OpHardfork::Interop => Some(interop::transactions()),
+        OpHardfork::Jovian => None,
+        OpHardfork::Bedrock
+        | OpHardfork::Regolith
+        | OpHardfork::Canyon
+        | OpHardfork::Delta
+        | OpHardfork::Granite
+        | OpHardfork::Holocene => Some(Vec::new()),
+    }
+}
+
+/// Returns the ordered upgrade deposits of `hardfork`.
+///
+/// See [`upgrade_transactions`].
+pub fn upgrade_deposits(hardfork: OpHardfork) -> Option<Vec<TxDeposit>> {
+    upgrade_transactions(hardfork).map(|txs| txs.into_iter().map(|tx| tx.tx).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::{B256, keccak256};
+
+    /// Checks the encoded upgrade transactions of `hardfork` against the keccak hashes of their
+    /// reference encodings.
+    pub(super) fn assert_encoded_hashes(hardfork: OpHardfork, expected: &[B256]) {
+        let txs = upgrade_deposits(hardfork).unwrap();
+        assert_eq!(txs.len(), expected.len());
+        for (tx, expected) in txs.iter().zip(expected) {
+            assert_eq!(keccak256(tx.encoded_2718()), *expected);
+        }
+    }
+
+    #[test]
+    fn test_upgrade_transactions_without_upgrades() {
+        for hardfork in [OpHardfork::Bedrock, OpHardfork::Canyon, OpHardfork::Holocene] {
+            assert_eq!(upgrade_transactions(hardfork), Some(Vec::new()));
+        }
+        assert_eq!(upgrade_transactions(OpHardfork::Jovian), None);
+    }
+
+    #[test]
+    fn test_upgrade_transaction_intents() {
+        for hardfork in OpHardfork::VARIANTS {
+            for tx in upgrade_transactions(hardfork).unwrap_or_default() {
+                // Intents start with the capitalized name of the hardfork.
+                let (name, _) = tx.intent.split_once(": ").unwrap();
+                assert!(name.eq_ignore_ascii_case(hardfork.as_str()));
+            }
+        }
+    }
+}
@@ -0,0 +1,370 @@
+//! Withdrawals initiated on L2 through the `L2ToL1MessagePasser`.
+//!
+//! See also: <https://specs.optimism.io/protocol/withdrawals.html>
+
+use crate::{
+    OpReceipt, deposit_event::read_address_topic, predeploys::L2_TO_L1_MESSAGE_PASSER_ADDRESS,
+};
+use alloc::vec::Vec;
+use alloy_primitives::{Address, B256, Bytes, Log, LogData, U256, b256, keccak256};
+
+/// The signature of the event emitted by the `L2ToL1MessagePasser` for each withdrawal.
+pub const MESSAGE_PASSED_EVENT_ABI: &str =
+    "MessagePassed(uint256,address,address,uint256,uint256,bytes,bytes32)";
+
+/// `keccak256("MessagePassed(uint256,address,address,uint256,uint256,bytes,bytes32)")`
+pub const MESSAGE_PASSED_EVENT_ABI_HASH: B256 =
+    b256!("0x02a52367d10742d8032712c1bb8e0144ff1ec5ffda1ed7d70bb05a2744955054");
+
+/// The storage slot of the `sentMessages` mapping of the `L2ToL1MessagePasser`.
+const SENT_MESSAGES_SLOT: U256 = U256::ZERO;
+
+/// The length of the head of the `MessagePassed` event data: `value`, `gasLimit`, the offset of
+/// `data` and `withdrawalHash`.
+const EVENT_DATA_HEAD_LEN: usize = 4 * 32;
+
+/// A withdrawal, as sent by the `L2ToL1MessagePasser` and proven on L1 by the `OptimismPortal`.
+///
+/// This mirrors `Types.WithdrawalTransaction` of the contracts.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct WithdrawalTransaction {
+    /// The nonce of the withdrawal, which includes the message version in its upper two bytes.
+    pub nonce: U256,
+    /// The address that initiated the withdrawal on L2.
+    pub sender: Address,
+    /// The address called on L1.
+    pub target: Address,
+    /// The ETH value sent to the target.
+    pub value: U256,
+    /// The gas limit of the call on L1.
+    pub gas_limit: U256,
+    /// The calldata of the call on L1.
+    pub data: Bytes,
+}
+
+impl WithdrawalTransaction {
+    /// Returns the withdrawal hash, as computed by `Hashing.hashWithdrawal`:
+    ///
+    /// `keccak256(abi.encode(nonce, sender, target, value, gasLimit, data))`
+    pub fn hash(&self) -> B256 {
+        let padded_len = self.data.len().div_ceil(32) * 32;
+        let mut encoded = Vec::with_capacity(8 * 32 + padded_len);
+        encoded.extend_from_slice(&self.nonce.to_be_bytes::<32>());
+        encoded.extend_from_slice(self.sender.into_word().as_slice());
+        encoded.extend_from_slice(self.target.into_word().as_slice());
+        encoded.extend_from_slice(&self.value.to_be_bytes::<32>());
+        encoded.extend_from_slice(&self.gas_limit.to_be_bytes::<32>());
+        encoded.extend_from_slice(&U256::from(6 * 32).to_be_bytes::<32>());
+        encode_bytes(&mut encoded, &self.data);
+        keccak256(encoded)
+    }
+
+    /// Returns the storage slot of the `L2ToL1MessagePasser` that records the withdrawal.
+    ///
+    /// The message passer sets `sentMessages[withdrawalHash] = true`, so the slot is
+    /// `keccak256(abi.encode(withdrawalHash, 0))`. Proving the withdrawal on L1 requires a storage
+    /// proof of this slot.
+    pub fn storage_slot(&self) -> B256 {
+        let mut preimage = [0u8; 64];
+        preimage[..32].copy_from_slice(self.hash().as_slice());
+        preimage[32..].copy_from_slice(&SENT_MESSAGES_SLOT.to_be_bytes::<32>());
+        keccak256(preimage)
+    }
+
+    /// Decodes a withdrawal from a `MessagePassed` log of the `L2ToL1MessagePasser`:
+    ///
+    /// ```solidity
+    /// event MessagePassed(
+    ///     uint256 indexed nonce,
+    ///     address indexed sender,
+    ///     address indexed target,
+    ///     uint256 value,
+    ///     uint256 gasLimit,
+    ///     bytes data,
+    ///     bytes32 withdrawalHash
+    /// );
+    /// ```
+    ///
+    /// The withdrawal hash of the event must match the hash of the decoded withdrawal.
+    pub fn decode_log(log: &Log) -> Result<Self, WithdrawalError> {
+        if log.address != L2_TO_L1_MESSAGE_PASSER_ADDRESS {
+            return Err(WithdrawalError::InvalidAddress(log.address));
+        }
+        let topics = log.topics();
+        if topics.len() != 4 {
+            return Err(WithdrawalError::UnexpectedTopicsLen(topics.len()));
+        }
+        if topics[0] != MESSAGE_PASSED_EVENT_ABI_HASH {
+            return Err(WithdrawalError::InvalidSelector(topics[0]));
+        }
+        let sender =
+            read_address_topic(topics[2]).ok_or(WithdrawalError::InvalidSender(topics[2]))?;
+        let target =
+            read_address_topic(topics[3]).ok_or(WithdrawalError::InvalidTarget(topics[3]))?;
+
+        let data = &log.data.data;
+        if data.len() < EVENT_DATA_HEAD_LEN + 32 || !data.len().is_multiple_of(32) {
+            return Err(WithdrawalError::InvalidDataLength(data.len()));
+        }
+        if U256::from_be_slice(&data[64..96]) != U256::from(EVENT_DATA_HEAD_LEN) {
+            return Err(WithdrawalError::InvalidDataOffset);
+        }
+        let available = data.len() - EVENT_DATA_HEAD_LEN - 32;
+        let len = U256::from_be_slice(&data[EVENT_DATA_HEAD_LEN..EVENT_DATA_HEAD_LEN + 32]);
+        let declared = usize::try_from(len)
+            .ok()
+            .filter(|len| *len <= available && available < len + 32)
+            .ok_or(WithdrawalError::DataLengthMismatch { declared: len, available })?;
+        let start = EVENT_DATA_HEAD_LEN + 32;
+
+        let withdrawal = Self {
+            nonce: U256::from_be_bytes(topics[1].0),
+            sender,
+            target,
+            value: U256::from_be_slice(&data[..32]),
+            gas_limit: U256::from_be_slice(&data[32..64]),
+            data: Bytes::copy_from_slice(&data[start..start + declared]),
+        };
+
+        let emitted = B256::from_slice(&data[96..128]);
+        let computed = withdrawal.hash();
+        if emitted != computed {
+            return Err(WithdrawalError::HashMismatch { emitted, computed });
+        }
+        Ok(withdrawal)
+    }
+
+    /// Decodes all the withdrawals initiated in a transaction from its receipt.
+    ///
+    /// Logs that are not `MessagePassed` events of the `L2ToL1MessagePasser` are skipped.
+    pub fn from_receipt(receipt: &OpReceipt) -> Result<Vec<Self>, WithdrawalError> {
+        receipt
+            .as_receipt()
+            .logs
+            .iter()
+            .filter(|log| {
+                log.address == L2_TO_L1_MESSAGE_PASSER_ADDRESS
+                    && log.topics().first() == Some(&MESSAGE_PASSED_EVENT_ABI_HASH)
+            })
+            .map(Self::decode_log)
+            .collect()
+    }
+
+    /// Encodes the withdrawal into the `MessagePassed` log that the `L2ToL1MessagePasser` emits
+    /// for it.
+    ///
+    /// This is the inverse of [`WithdrawalTransaction::decode_log`].
+    pub fn to_log(&self) -> Log {
+        let padded_len = self.data.len().div_ceil(32) * 32;
+        let mut data = Vec::with_capacity(EVENT_DATA_HEAD_LEN + 32 + padded_len);
+        data.extend_from_slice(&self.value.to_be_bytes::<32>());
+        data.extend_from_slice(&self.gas_limit.to_be_bytes::<32>());
+        data.extend_from_slice(&U256::from(EVENT_DATA_HEAD_LEN).to_be_bytes::<32>());
+        data.extend_from_slice(self.hash().as_slice());
+        encode_bytes(&mut data, &self.data);
+
+        Log {
+            address: L2_TO_L1_MESSAGE_PASSER_ADDRESS,
+            data: LogData::new_unchecked(
+                alloc::vec![
+                    MESSAGE_PASSED_EVENT_ABI_HASH,
+                    B256::from(self.nonce),
+                    self.sender.into_word(),
+                    self.target.into_word(),
+                ],
+                Bytes::from(data),
+            ),
+        }
+    }
+}
+
+/// Appends the tail of the ABI encoding of a dynamic `bytes` value to `out`: its length and its
+/// content right-padded to 32 bytes.
+fn encode_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(&U256::from(bytes.len()).to_be_bytes::<32>());
+    out.extend_from_slice(bytes);
+    out.resize(out.len() + bytes.len().next_multiple_of(32) - bytes.len(), 0);
+}
+
+/// Errors that can occur when decoding a `MessagePassed` log.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum WithdrawalError {
+    /// The log was not emitted by the `L2ToL1MessagePasser`.
+    #[error("Log not emitted by the L2ToL1MessagePasser: {0}")]
+    InvalidAddress(Address),
+    /// The log does not have exactly four topics.
+    #[error("Unexpected number of MessagePassed event topics: {0}")]
+    UnexpectedTopicsLen(usize),
+    /// The first topic is not the `MessagePassed` event selector.
+    #[error("Invalid MessagePassed event selector: {0}")]
+    InvalidSelector(B256),
+    /// The `sender` topic is not a padded address.
+    #[error("Invalid withdrawal `sender` topic: {0}")]
+    InvalidSender(B256),
+    /// The `target` topic is not a padded address.
+    #[error("Invalid withdrawal `target` topic: {0}")]
+    InvalidTarget(B256),
+    /// The event data is not a sequence of 32 byte words holding at least the head and the
+    /// length of `data`.
+    #[error("Invalid MessagePassed event data length: {0}")]
+    InvalidDataLength(usize),
+    /// The offset of `data` does not point right after the head.
+    #[error("Invalid withdrawal data offset")]
+    InvalidDataOffset,
+    /// The declared length of `data` does not match the event data.
+    #[error("Invalid withdrawal data length {declared}, {available} bytes available")]
+    DataLengthMismatch {
+        /// The length declared in the event data.
+        declared: U256,
+        /// The number of bytes following the length.
+        available: usize,
+    },
+    /// The withdrawal hash of the event does not match the decoded withdrawal.
+    #[error("Withdrawal hash mismatch: emitted {emitted}, computed {computed}")]
+    HashMismatch {
+        /// The withdrawal hash of the event.
+        emitted: B256,
+        /// The hash of the decoded withdrawal.
+        computed: B256,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{Eip658Value, Receipt};
+    use alloy_primitives::{address, hex};
+
+    fn withdrawal() -> WithdrawalTransaction {
+        WithdrawalTransaction {
+            nonce: U256::from(1) << 240 | U256::from(42),
+            sender: address!("0x4200000000000000000000000000000000000007"),
+            target: address!("0x25ace71c97B33Cc4729CF772ae268934F7ab5fA1"),
+            value: U256::from(1_000_000_000_000_000u64),
+            gas_limit: U256::from(200_000),
+            data: Bytes::from_static(&hex!("d764ad0b0001")),
+        }
+    }
+
+    #[test]
+    fn test_message_passed_event_abi_hash() {
+        assert_eq!(keccak256(MESSAGE_PASSED_EVENT_ABI), MESSAGE_PASSED_EVENT_ABI_HASH);
+    }
+
+    #[test]
+    fn test_withdrawal_hash() {
+        let withdrawal = withdrawal();
+        let encoded = [
+            &withdrawal.nonce.to_be_bytes::<32>()[..],
+            withdrawal.sender.into_word().as_slice(),
+            withdrawal.target.into_word().as_slice(),
+            &withdrawal.value.to_be_bytes::<32>(),
+            &withdrawal.gas_limit.to_be_bytes::<32>(),
+            &U256::from(0xc0).to_be_bytes::<32>(),
+            &U256::from(6).to_be_bytes::<32>(),
+            &hex!("d764ad0b00010000000000000000000000000000000000000000000000000000"),
+        ]
+        .concat();
+        assert_eq!(withdrawal.hash(), keccak256(encoded));
+
+        let slot = keccak256([withdrawal.hash().as_slice(), &[0; 32]].concat());
+        assert_eq!(withdrawal.storage_slot(), slot);
+    }
+
+    #[test]
+    fn test_withdrawal_log_roundtrip() {
+        for data in [Bytes::new(), Bytes::from(alloc::vec![0xab; 64]), withdrawal().data] {
+            let withdrawal = WithdrawalTransaction { data, ..withdrawal() };
+            let log = withdrawal.to_log();
+            assert_eq!(WithdrawalTransaction::decode_log(&log), Ok(withdrawal));
+        }
+    }
+
+    #[test]
+    fn test_withdrawals_from_receipt() {
+        let first = withdrawal();
+        let second = WithdrawalTransaction { nonce: first.nonce + U256::from(1), ..withdrawal() };
+        let other = Log { address: Address::repeat_byte(1), data: first.to_log().data };
+        let receipt = OpReceipt::Eip1559(Receipt {
+            status: Eip658Value::Eip658(true),
+            cumulative_gas_used: 100_000,
+            logs: alloc::vec![first.to_log(), other, second.to_log()],
+        });
+        assert_eq!(WithdrawalTransaction::from_receipt(&receipt), Ok(alloc::vec![first, second]));
+    }
+
+    #[test]
+    fn test_decode_withdrawal_log_invalid() {
+        let log = withdrawal().to_log();
+
+        let err = WithdrawalTransaction::decode_log(&Log { address: Address::ZERO, ..log.clone() });
+        assert_eq!(err, Err(WithdrawalError::InvalidAddress(Address::ZERO)));
+
+        let mut topics = log.topics().to_vec();
+        topics[2] = B256::repeat_byte(0xff);
+        let err = WithdrawalTransaction::decode_log(&Log {
+            address: log.address,
+            data: LogData::new_unchecked(topics, log.data.data.clone()),
+        });
+        assert_eq!(err, Err(WithdrawalError::InvalidSender(B256::repeat_byte(0xff))));
+
+        let with_data = |data: Vec<u8>| Log {
+            address: log.address,
+            data: LogData::new_unchecked(log.topics().to_vec(), data.into()),
+        };
+        let data = log.data.data.to_vec();
+
+        let err = WithdrawalTransaction::decode_log(&with_data(data[..128].to_vec()));
+        assert_eq!(err, Err(WithdrawalError::InvalidDataLength(128)));
+
+        let mut bad_offset = data.clone();
+        bad_offset[95] = 0x60;
+        let err = WithdrawalTransaction::decode_log(&with_data(bad_offset));
+        assert_eq!(err, Err(WithdrawalError::InvalidDataOffset));
+
+        let mut extra_word = data.clone();
+        extra_word.extend_from_slice(&[0; 32]);
+        let err = WithdrawalTransaction::decode_log(&with_data(extra_word));
+        assert_eq!(
+            err,
+            Err(WithdrawalError::DataLengthMismatch { declared: U256::from(6), available: 64 })
+        );
+
+        let mut bad_hash = data;
+        bad_hash[96] ^= 1;
+        let err = WithdrawalTransaction::decode_log(&with_data(bad_hash));
+        assert!(matches!(err, Err(WithdrawalError::HashMismatch { .. })));
+    }
+
+    #[cfg(feature = "bindings")]
+    #[test]
+    fn test_withdrawal_matches_bindings() {
+        use crate::predeploys::bindings::L2ToL1MessagePasser::MessagePassed;
+        use alloy_sol_types::{SolEvent, SolValue};
+
+        let withdrawal = withdrawal();
+        let encoded = (
+            withdrawal.nonce,
+            withdrawal.sender,
+            withdrawal.target,
+            withdrawal.value,
+            withdrawal.gas_limit,
+            withdrawal.data.clone(),
+        )
+            .abi_encode_params();
+        assert_eq!(withdrawal.hash(), keccak256(encoded));
+
+        let event = MessagePassed {
+            nonce: withdrawal.nonce,
+            sender: withdrawal.sender,
+            target: withdrawal.target,
+            value: withdrawal.value,
+            gasLimit: withdrawal.gas_limit,
+            data: withdrawal.data.clone(),
+            withdrawalHash: withdrawal.hash(),
+        };
+        assert_eq!(event.encode_log_data(), withdrawal.to_log().data);
+    }
+}
@@ -0,0 +1,336 @@
+//! Compression of channel data.
+//!
+//! The data of a channel is either a raw zlib stream, or from the Fjord hardfork a version byte
+//! followed by the compressed data. The only versioned format is brotli, see
+//! [`CHANNEL_VERSION_BROTLI`].
+//!
+//! See also: <https://specs.optimism.io/protocol/fjord/derivation.html#brotli-channel-compression>
+
+use alloc::vec::Vec;
+use op_alloy_consensus::{OpHardfork, channel::max_rlp_bytes_per_channel};
+use std::io::{Read, Write};
+
+/// The version byte of brotli compressed channel data.
+pub const CHANNEL_VERSION_BROTLI: u8 = 0x01;
+
+/// The default zlib compression level, the best compression.
+pub const DEFAULT_ZLIB_LEVEL: u8 = 9;
+
+/// The default brotli compression quality.
+pub const DEFAULT_BROTLI_QUALITY: u8 = 10;
+
+/// The base two logarithm of the brotli window size.
+const BROTLI_WINDOW_BITS: u32 = 22;
+
+/// The buffer size of the brotli encoder and decoder.
+const BROTLI_BUFFER_SIZE: usize = 4096;
+
+/// The compression algorithm of channel data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompressionType {
+    /// Zlib compression, the only one available before Fjord.
+    Zlib,
+    /// Brotli compression, available from Fjord onwards.
+    Brotli,
+}
+
+impl CompressionType {
+    /// Detects the compression type of channel data from its first byte.
+    ///
+    /// Zlib streams are recognized by their compression method, the lower 4 bits of their first
+    /// byte, which is either 8 (deflate) or 15 (reserved).
+    pub fn detect(data: &[u8]) -> Result<Self, ChannelCompressionError> {
+        match data.first() {
+            None => Err(ChannelCompressionError::EmptyData),
+            Some(byte) if byte & 0x0f == 0x08 || byte & 0x0f == 0x0f => Ok(Self::Zlib),
+            Some(&CHANNEL_VERSION_BROTLI) => Ok(Self::Brotli),
+            Some(byte) => Err(ChannelCompressionError::UnknownVersion(*byte)),
+        }
+    }
+}
+
+/// Statistics of the data processed by a [`ChannelCompressor`] or a [`ChannelDecompressor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CompressionStats {
+    /// The total length of the uncompressed data.
+    pub uncompressed_bytes: u64,
+    /// The total length of the compressed data, including version bytes.
+    pub compressed_bytes: u64,
+}
+
+impl CompressionStats {
+    /// Returns the ratio of the compressed length to the uncompressed length, or `None` if no data
+    /// was processed.
+    pub fn ratio(&self) -> Option<f64> {
+        (self.uncompressed_bytes != 0)
+            .then(|| self.compressed_bytes as f64 / self.uncompressed_bytes as f64)
+    }
+
+    const fn record(&mut self, uncompressed: usize, compressed: usize) {
+        self.uncompressed_bytes += uncompressed as u64;
+        self.compressed_bytes += compressed as u64;
+    }
+}
+
+/// Compresses channel data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelCompressor {
+    compression_type: CompressionType,
+    level: u8,
+    stats: CompressionStats,
+}
+
+impl ChannelCompressor {
+    /// Creates a new compressor with the default level of `compression_type`.
+    pub const fn new(compression_type: CompressionType) -> Self {
+        let level = match compression_type {
+            CompressionType::Zlib => DEFAULT_ZLIB_LEVEL,
+            CompressionType::Brotli => DEFAULT_BROTLI_QUALITY,
+        };
+        Self {
+            compression_type,
+            level,
+            stats: CompressionStats { uncompressed_bytes: 0, compressed_bytes: 0 },
+        }
+    }
+
+    /// Sets the compression level, from 0 to 9 for zlib and from 0 to 11 for brotli.
+    pub const fn with_level(mut self, level: u8) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Returns the compression type of the compressor.
+    pub const fn compression_type(&self) -> CompressionType {
+        self.compression_type
+    }
+
+    /// Returns the statistics of the data compressed so far.
+    pub const fn stats(&self) -> CompressionStats {
+        self.stats
+    }
+
+    /// Compresses channel data, prefixing it with its version byte if the compression type is
+    /// versioned.
+    pub fn compress(&mut self, data: &[u8]) -> Vec<u8> {
+        let compressed = match self.compression_type {
+            CompressionType::Zlib => miniz_oxide::deflate::compress_to_vec_zlib(data, self.level),
+            CompressionType::Brotli => {
+                let mut writer = brotli::CompressorWriter::new(
+                    alloc::vec![CHANNEL_VERSION_BROTLI],
+                    BROTLI_BUFFER_SIZE,
+                    self.level.into(),
+                    BROTLI_WINDOW_BITS,
+                );
+                writer.write_all(data).expect("writing to a vector can not fail");
+                writer.into_inner()
+            }
+        };
+        self.stats.record(data.len(), compressed.len());
+        compressed
+    }
+}
+
+/// Decompresses channel data, detecting its compression type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelDecompressor {
+    max_rlp_bytes: usize,
+    allow_brotli: bool,
+    stats: CompressionStats,
+}
+
+impl ChannelDecompressor {
+    /// Creates a new decompressor enforcing the limits of `hardfork`.
+    ///
+    /// The decompressed data can not exceed the maximum RLP bytes per channel, and brotli is only
+    /// accepted from Fjord onwards.
+    pub const fn new(hardfork: OpHardfork) -> Self {
+        Self {
+            max_rlp_bytes: max_rlp_bytes_per_channel(hardfork),
+            allow_brotli: hardfork.is_enabled_in(OpHardfork::Fjord),
+            stats: CompressionStats { uncompressed_bytes: 0, compressed_bytes: 0 },
+        }
+    }
+
+    /// Sets the maximum length of the decompressed data.
+    pub const fn with_max_rlp_bytes(mut self, max_rlp_bytes: usize) -> Self {
+        self.max_rlp_bytes = max_rlp_bytes;
+        self
+    }
+
+    /// Returns the maximum length of the decompressed data.
+    pub const fn max_rlp_bytes(&self) -> usize {
+        self.max_rlp_bytes
+    }
+
+    /// Returns the statistics of the data decompressed so far.
+    pub const fn stats(&self) -> CompressionStats {
+        self.stats
+    }
+
+    /// Decompresses channel data.
+    ///
+    /// Data decompressing to more than the maximum RLP bytes per channel is truncated to the limit,
+    /// like op-node does, and the remainder of the channel is ignored.
+    pub fn decompress(
+        &mut self,
+        data: &[u8],
+    ) -> Result<DecompressedChannel, ChannelCompressionError> {
+        let (decompressed, truncated) = match CompressionType::detect(data)? {
+            CompressionType::Zlib => {
+                match miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(
+                    data,
+                    self.max_rlp_bytes,
+                ) {
+                    Ok(decompressed) => (decompressed, false),
+                    // The output holds the data decompressed up to the limit.
+                    Err(err) if err.status == miniz_oxide::inflate::TINFLStatus::HasMoreOutput => {
+                        let mut decompressed = err.output;
+                        decompressed.truncate(self.max_rlp_bytes);
+                        (decompressed, true)
+                    }
+                    Err(_) => {
+                        return Err(ChannelCompressionError::InvalidData(CompressionType::Zlib));
+                    }
+                }
+            }
+            CompressionType::Brotli => {
+                if !self.allow_brotli {
+                    return Err(ChannelCompressionError::BrotliNotActive);
+                }
+                let mut decompressed = Vec::new();
+                brotli::Decompressor::new(&data[1..], BROTLI_BUFFER_SIZE)
+                    .take(self.max_rlp_bytes as u64 + 1)
+                    .read_to_end(&mut decompressed)
+                    .map_err(|_| ChannelCompressionError::InvalidData(CompressionType::Brotli))?;
+                let truncated = decompressed.len() > self.max_rlp_bytes;
+                decompressed.truncate(self.max_rlp_bytes);
+                (decompressed, truncated)
+            }
+        };
+        self.stats.record(decompressed.len(), data.len());
+        Ok(DecompressedChannel { data: decompressed, truncated })
+    }
+}
+
+/// The data of a channel decompressed by a [`ChannelDecompressor`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DecompressedChannel {
+    /// The decompressed data, at most the maximum RLP bytes per channel.
+    pub data: Vec<u8>,
+    /// Whether the data was truncated to the maximum RLP bytes per channel.
+    pub truncated: bool,
+}
+
+/// Errors that can occur when decompressing channel data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ChannelCompressionError {
+    /// The channel data is empty.
+    #[error("Empty channel data")]
+    EmptyData,
+    /// The channel data is neither a zlib stream nor starts with a known version byte.
+    #[error("Unknown channel version: {0}")]
+    UnknownVersion(u8),
+    /// The channel data is brotli compressed before Fjord.
+    #[error("Brotli channel compression is not active")]
+    BrotliNotActive,
+    /// The compressed data is invalid.
+    #[error("Invalid {0:?} channel data")]
+    InvalidData(CompressionType),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::hex;
+
+    #[test]
+    fn test_detect_compression_type() {
+        assert_eq!(CompressionType::detect(&[0x78, 0xda]), Ok(CompressionType::Zlib));
+        assert_eq!(CompressionType::detect(&[0x0f]), Ok(CompressionType::Zlib));
+        assert_eq!(CompressionType::detect(&[CHANNEL_VERSION_BROTLI]), Ok(CompressionType::Brotli));
+        assert_eq!(
+            CompressionType::detect(&[0x02]),
+            Err(ChannelCompressionError::UnknownVersion(2))
+        );
+        assert_eq!(CompressionType::detect(&[]), Err(ChannelCompressionError::EmptyData));
+    }
+
+    #[test]
+    fn test_compression_roundtrip() {
+        let data = [0xaa; 1000];
+        for compression_type in [CompressionType::Zlib, CompressionType::Brotli] {
+            let mut compressor = ChannelCompressor::new(compression_type);
+            let compressed = compressor.compress(&data);
+            assert_eq!(CompressionType::detect(&compressed), Ok(compression_type));
+
+            let stats = compressor.stats();
+            assert_eq!(stats.uncompressed_bytes, 1000);
+            assert_eq!(stats.compressed_bytes, compressed.len() as u64);
+            assert!(stats.ratio().unwrap() < 0.1);
+
+            let mut decompressor = ChannelDecompressor::new(OpHardfork::Fjord);
+            assert_eq!(
+                decompressor.decompress(&compressed),
+                Ok(DecompressedChannel { data: data.to_vec(), truncated: false })
+            );
+            assert_eq!(decompressor.stats(), stats);
+        }
+        assert_eq!(CompressionStats::default().ratio(), None);
+    }
+
+    #[test]
+    fn test_decompress_brotli() {
+        let compressed = hex!("018b048075ed184249e9bc19675e03");
+        let mut decompressor = ChannelDecompressor::new(OpHardfork::Fjord);
+        assert_eq!(
+            decompressor.decompress(&compressed).unwrap().data,
+            hex!("75ed184249e9bc19675e")
+        );
+
+        let mut decompressor = ChannelDecompressor::new(OpHardfork::Ecotone);
+        assert_eq!(
+            decompressor.decompress(&compressed),
+            Err(ChannelCompressionError::BrotliNotActive)
+        );
+    }
+
+    #[test]
+    fn test_decompress_invalid() {
+        let mut decompressor = ChannelDecompressor::new(OpHardfork::Fjord);
+        assert_eq!(
+            decompressor.decompress(&[0x78, 0xda, 0xff]),
+            Err(ChannelCompressionError::InvalidData(CompressionType::Zlib))
+        );
+        assert_eq!(
+            decompressor.decompress(&[CHANNEL_VERSION_BROTLI, 0xff, 0xff]),
+            Err(ChannelCompressionError::InvalidData(CompressionType::Brotli))
+        );
+        assert_eq!(decompressor.stats(), CompressionStats::default());
+    }
+
+    #[test]
+    fn test_decompress_too_large() {
+        let data: Vec<u8> = (0..1000u32).map(|i| (i * i % 251) as u8).collect();
+        for compression_type in [CompressionType::Zlib, CompressionType::Brotli] {
+            let compressed = ChannelCompressor::new(compression_type).compress(&data);
+            let mut decompressor =
+                ChannelDecompressor::new(OpHardfork::Fjord).with_max_rlp_bytes(1000);
+            assert_eq!(
+                decompressor.decompress(&compressed),
+                Ok(DecompressedChannel { data: data.clone(), truncated: false })
+            );
+
+            let mut decompressor = decompressor.with_max_rlp_bytes(999);
+            assert_eq!(
+                decompressor.decompress(&compressed),
+                Ok(DecompressedChannel { data: data[..999].to_vec(), truncated: true })
+            );
+            assert_eq!(decompressor.stats().uncompressed_bytes, 1000 + 999);
+        }
+        assert_eq!(
+            ChannelDecompressor::new(OpHardfork::Ecotone).max_rlp_bytes(),
+            op_alloy_consensus::channel::MAX_RLP_BYTES_PER_CHANNEL
+        );
+    }
+}
@@ -20,6 +20,15 @@ pub use envelope::{
     PayloadEnvelopeEncodeError, PayloadEnvelopeError, PayloadHash,
 };
 
+#[cfg(feature = "std")]
+mod compression;
+#[cfg(feature = "std")]
+pub use compression::{
+    CHANNEL_VERSION_BROTLI, ChannelCompressionError, ChannelCompressor, ChannelDecompressor,
+    CompressionStats, CompressionType, DEFAULT_BROTLI_QUALITY, DEFAULT_ZLIB_LEVEL,
+    DecompressedChannel,
+};
+
 mod sidecar;
 pub use sidecar::OpExecutionPayloadSidecar;
 
@@ -1,6 +1,7 @@
 //! OP types for genesis data.
 
 use alloy_serde::OtherFields;
+use op_alloy_consensus::{OpHardfork, OpHardforks, OpHardforksError};
 use serde::de::Error;
 
 /// Container type for all Optimism specific fields in a genesis file.
@@ -73,6 +74,41 @@ impl TryFrom<&OtherFields> for OpGenesisInfo {
     }
 }
 
+impl TryFrom<&OpGenesisInfo> for OpHardforks {
+    type Error = OpHardforksError;
+
+    /// Builds the hardfork schedule from the activation timestamps, and checks that they are
+    /// ordered.
+    ///
+    /// The Bedrock block number is not part of the timestamp schedule.
+    fn try_from(info: &OpGenesisInfo) -> Result<Self, Self::Error> {
+        let schedule = hardfork_schedule([
+            (OpHardfork::Regolith, info.regolith_time),
+            (OpHardfork::Canyon, info.canyon_time),
+            (OpHardfork::Ecotone, info.ecotone_time),
+            (OpHardfork::Fjord, info.fjord_time),
+            (OpHardfork::Granite, info.granite_time),
+            (OpHardfork::Holocene, info.holocene_time),
+            (OpHardfork::Isthmus, info.isthmus_time),
+            (OpHardfork::Jovian, info.jovian_time),
+            (OpHardfork::Interop, info.interop_time),
+        ]);
+        schedule.validate()?;
+        Ok(schedule)
+    }
+}
+
+/// Builds a hardfork schedule from optional activation timestamps, without checking their order.
+pub(crate) fn hardfork_schedule(
+    activations: impl IntoIterator<Item = (OpHardfork, Option<u64>)>,
+) -> OpHardforks {
+    let mut schedule = OpHardforks::default();
+    for (fork, time) in activations {
+        schedule.set_activation(fork, time);
+    }
+    schedule
+}
+
 /// The Optimism-specific base fee specification.
 #[derive(Default, Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -140,6 +176,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_genesis_info_hardforks() {
+        let genesis_info = OpGenesisInfo {
+            bedrock_block: Some(0),
+            regolith_time: Some(0),
+            canyon_time: Some(0),
+            ecotone_time: Some(10),
+            fjord_time: Some(20),
+            ..Default::default()
+        };
+        let schedule = OpHardforks::try_from(&genesis_info).unwrap();
+        assert_eq!(schedule.active_fork_at(0), OpHardfork::Canyon);
+        assert!(schedule.is_active_at(OpHardfork::Delta, 10));
+        assert_eq!(schedule.next_fork_after(10), Some((OpHardfork::Fjord, 20)));
+
+        let genesis_info = OpGenesisInfo { holocene_time: Some(5), ..genesis_info };
+        assert_eq!(
+            OpHardforks::try_from(&genesis_info),
+            Err(OpHardforksError::OutOfOrder {
+                fork: OpHardfork::Holocene,
+                time: 5,
+                previous: OpHardfork::Fjord,
+                previous_time: 20,
+            })
+        );
+    }
+
     #[test]
     fn test_extract_optimism_base_fee_info() {
         let base_fee_info = r#"
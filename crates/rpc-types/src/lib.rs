@@ -12,11 +12,19 @@ extern crate alloc;
 mod genesis;
 pub use genesis::{OpBaseFeeInfo, OpChainInfo, OpGenesisInfo};
 
+mod rollup;
+pub use rollup::{AltDaConfig, RollupConfig, RollupGenesis};
+
 mod receipt;
 pub use receipt::{L1BlockInfo, OpTransactionReceipt, OpTransactionReceiptFields};
 
 mod transaction;
 pub use transaction::{OpTransactionFields, OpTransactionRequest, Transaction};
 
+#[cfg(feature = "registry")]
+pub mod registry;
+#[cfg(feature = "registry")]
+pub use registry::{RegistryAddresses, RegistryChain};
+
 pub mod error;
 pub use error::SuperchainDAError;
@@ -0,0 +1,452 @@
+//! The chain configurations embedded in the registry, from the superchain registry.
+
+use super::{RegistryAddresses, RegistryChain};
+use alloy_eips::eip1559::BaseFeeParams;
+use alloy_primitives::address;
+use op_alloy_consensus::{OpHardfork, OpHardforks};
+
+/// The embedded chains, mainnets first.
+pub(super) static CHAINS: &[RegistryChain] = &[
+    RegistryChain {
+        identifier: "mainnet/op",
+        name: "OP Mainnet",
+        chain_id: 10,
+        l1_chain_id: 1,
+        bedrock_block: 105235063,
+        block_time: 2,
+        hardforks: OpHardforks::new()
+            .with_activation(OpHardfork::Regolith, 0)
+            .with_activation(OpHardfork::Canyon, 1704992401)
+            .with_activation(OpHardfork::Delta, 1708560000)
+            .with_activation(OpHardfork::Ecotone, 1710374401)
+            .with_activation(OpHardfork::Fjord, 1720627201)
+            .with_activation(OpHardfork::Granite, 1726070401)
+            .with_activation(OpHardfork::Holocene, 1736445601)
+            .with_activation(OpHardfork::Isthmus, 1746806401)
+            .with_activation(OpHardfork::Jovian, 1764691201),
+        pectra_blob_schedule_time: None,
+        base_fee_params: BaseFeeParams::new(50, 6),
+        base_fee_params_canyon: BaseFeeParams::new(250, 6),
+        batch_inbox: address!("0xFF00000000000000000000000000000000000010"),
+        batcher: address!("0x6887246668a3b87F54DeB3b94Ba47a6f63F32985"),
+        unsafe_block_signer: address!("0xAAAA45d9549EDA09E70937013520214382Ffc4A2"),
+        addresses: RegistryAddresses {
+            system_config: address!("0x229047fed2591dbec1eF1118d64F7aF3dB9EB290"),
+            optimism_portal: address!("0xbEb5Fc579115071764c7423A4f12eDde41f106Ed"),
+            l1_cross_domain_messenger: address!("0x25ace71c97B33Cc4729CF772ae268934F7ab5fA1"),
+            l1_standard_bridge: address!("0x99C9fc46f92E8a1c0deC1b1747d010903E884bE1"),
+            l1_erc721_bridge: address!("0x5a7749f83b81B301cAb5f48EB8516B986DAef23D"),
+            optimism_mintable_erc20_factory: address!("0x75505a97BD334E7BD3C476893285569C4136Fa0F"),
+            proxy_admin: address!("0x543bA4AADBAb8f9025686Bd03993043599c6fB04"),
+            l2_output_oracle: None,
+            dispute_game_factory: Some(address!("0xe5965Ab5962eDc7477C8520243A95517CD252fA9")),
+            protocol_versions: address!("0x8062AbC286f5e7D9428a0Ccb9AbD71e50d93b935"),
+            superchain_config: address!("0x95703e0982140D16f8ebA6d158FccEde42f04a4C"),
+        },
+    },
+    RegistryChain {
+        identifier: "mainnet/base",
+        name: "Base",
+        chain_id: 8453,
+        l1_chain_id: 1,
+        bedrock_block: 0,
+        block_time: 2,
+        hardforks: OpHardforks::new()
+            .with_activation(OpHardfork::Regolith, 0)
+            .with_activation(OpHardfork::Canyon, 1704992401)
+            .with_activation(OpHardfork::Delta, 1708560000)
+            .with_activation(OpHardfork::Ecotone, 1710374401)
+            .with_activation(OpHardfork::Fjord, 1720627201)
+            .with_activation(OpHardfork::Granite, 1726070401)
+            .with_activation(OpHardfork::Holocene, 1736445601)
+            .with_activation(OpHardfork::Isthmus, 1746806401)
+            .with_activation(OpHardfork::Jovian, 1764691201),
+        pectra_blob_schedule_time: None,
+        base_fee_params: BaseFeeParams::new(50, 6),
+        base_fee_params_canyon: BaseFeeParams::new(250, 6),
+        batch_inbox: address!("0xFf00000000000000000000000000000000008453"),
+        batcher: address!("0x5050F69a9786F081509234F1a7F4684b5E5b76C9"),
+        unsafe_block_signer: address!("0xAf6E19BE0F9cE7f8afd49a1824851023A8249e8a"),
+        addresses: RegistryAddresses {
+            system_config: address!("0x73a79Fab69143498Ed3712e519A88a918e1f4072"),
+            optimism_portal: address!("0x49048044D57e1C92A77f79988d21Fa8fAF74E97e"),
+            l1_cross_domain_messenger: address!("0x866E82a600A1414e583f7F13623F1aC5d58b0Afa"),
+            l1_standard_bridge: address!("0x3154Cf16ccdb4C6d922629664174b904d80F2C35"),
+            l1_erc721_bridge: address!("0x608d94945A64503E642E6370Ec598e519a2C1E53"),
+            optimism_mintable_erc20_factory: address!("0x05cc379EBD9B30BbA19C6fA282AB29218EC61D84"),
+            proxy_admin: address!("0x0475cBCAebd9CE8AfA5025828d5b98DFb67E059E"),
+            l2_output_oracle: Some(address!("0x56315b90c40730925ec5485cf004d835058518A0")),
+            dispute_game_factory: Some(address!("0x43edB88C4B80fDD2AdFF2412A7BebF9dF42cB40e")),
+            protocol_versions: address!("0x8062AbC286f5e7D9428a0Ccb9AbD71e50d93b935"),
+            superchain_config: address!("0x95703e0982140D16f8ebA6d158FccEde42f04a4C"),
+        },
+    },
+    RegistryChain {
+        identifier: "mainnet/unichain",
+        name: "Unichain",
+        chain_id: 130,
+        l1_chain_id: 1,
+        bedrock_block: 0,
+        block_time: 1,
+        hardforks: OpHardforks::new()
+            .with_activation(OpHardfork::Regolith, 0)
+            .with_activation(OpHardfork::Canyon, 0)
+            .with_activation(OpHardfork::Delta, 0)
+            .with_activation(OpHardfork::Ecotone, 0)
+            .with_activation(OpHardfork::Fjord, 0)
+            .with_activation(OpHardfork::Granite, 0)
+            .with_activation(OpHardfork::Holocene, 1736445601)
+            .with_activation(OpHardfork::Isthmus, 1746806401)
+            .with_activation(OpHardfork::Jovian, 1764691201),
+        pectra_blob_schedule_time: None,
+        base_fee_params: BaseFeeParams::new(50, 6),
+        base_fee_params_canyon: BaseFeeParams::new(250, 6),
+        batch_inbox: address!("0xFf00000000000000000000000000000000000130"),
+        batcher: address!("0x2F60A5184c63ca94f82a27100643DbAbe4F3f7Fd"),
+        unsafe_block_signer: address!("0x833C6f278474A78658af91aE8edC926FE33a230e"),
+        addresses: RegistryAddresses {
+            system_config: address!("0xc407398d063f942feBbcC6F80a156b47F3f1BDA6"),
+            optimism_portal: address!("0x0bd48f6B86a26D3a217d0Fa6FfE2B491B956A7a2"),
+            l1_cross_domain_messenger: address!("0x9A3D64E386C18Cb1d6d5179a9596A4B5736e98A6"),
+            l1_standard_bridge: address!("0x81014F44b0a345033bB2b3B21C7a1A308B35fEeA"),
+            l1_erc721_bridge: address!("0xD04D0D87E0bd4D2E50286760a3EF323FeA6849Cf"),
+            optimism_mintable_erc20_factory: address!("0xA2B597EaeAcb6F627e088cbEaD319e934ED5edad"),
+            proxy_admin: address!("0x3B73Fa8d82f511A3caE17B5a26E4E1a2d5E2f2A4"),
+            l2_output_oracle: None,
+            dispute_game_factory: Some(address!("0x2F12d621a16e2d3285929C9996f478508951dFe4")),
+            protocol_versions: address!("0x8062AbC286f5e7D9428a0Ccb9AbD71e50d93b935"),
+            superchain_config: address!("0x95703e0982140D16f8ebA6d158FccEde42f04a4C"),
+        },
+    },
+    RegistryChain {
+        identifier: "mainnet/ink",
+        name: "Ink",
+        chain_id: 57073,
+        l1_chain_id: 1,
+        bedrock_block: 0,
+        block_time: 1,
+        hardforks: OpHardforks::new()
+            .with_activation(OpHardfork::Regolith, 0)
+            .with_activation(OpHardfork::Canyon, 0)
+            .with_activation(OpHardfork::Delta, 0)
+            .with_activation(OpHardfork::Ecotone, 0)
+            .with_activation(OpHardfork::Fjord, 0)
+            .with_activation(OpHardfork::Granite, 0)
+            .with_activation(OpHardfork::Holocene, 1742396400)
+            .with_activation(OpHardfork::Isthmus, 1746806401)
+            .with_activation(OpHardfork::Jovian, 1764691201),
+        pectra_blob_schedule_time: None,
+        base_fee_params: BaseFeeParams::new(250, 6),
+        base_fee_params_canyon: BaseFeeParams::new(250, 6),
+        batch_inbox: address!("0x005969bf0EcbF6eDB6C47E5e94693b1C3651Be97"),
+        batcher: address!("0x500d7Ea63CF2E501dadaA5feeC1FC19FE2Aa72Ac"),
+        unsafe_block_signer: address!("0x7D056B99AA2021864c42E25B4F8cE3BdEAc9463C"),
+        addresses: RegistryAddresses {
+            system_config: address!("0x62C0a111929fA32ceC2F76aDba54C16aFb6E8364"),
+            optimism_portal: address!("0x5d66C1782664115999C47c9fA5cd031f495D3e4F"),
+            l1_cross_domain_messenger: address!("0x69d3Cf86B2Bf1a9e99875B7e2D9B6a84426c171f"),
+            l1_standard_bridge: address!("0x88FF1e5b602916615391F55854588EFcBB7663f0"),
+            l1_erc721_bridge: address!("0x661235a238B11191211fa95D4Dd9E423d521E0Be"),
+            optimism_mintable_erc20_factory: address!("0xA8B389A82e088b164cD03230e900980CcED34d29"),
+            proxy_admin: address!("0xd56045E68956FCe2576E680c95a4750cf8241f79"),
+            l2_output_oracle: None,
+            dispute_game_factory: Some(address!("0x10d7B35078d3baabB96Dd45a9143B94be65b12CD")),
+            protocol_versions: address!("0x8062AbC286f5e7D9428a0Ccb9AbD71e50d93b935"),
+            superchain_config: address!("0x95703e0982140D16f8ebA6d158FccEde42f04a4C"),
+        },
+    },
+    RegistryChain {
+        identifier: "mainnet/zora",
+        name: "Zora",
+        chain_id: 7777777,
+        l1_chain_id: 1,
+        bedrock_block: 0,
+        block_time: 2,
+        hardforks: OpHardforks::new()
+            .with_activation(OpHardfork::Regolith, 0)
+            .with_activation(OpHardfork::Canyon, 1704992401)
+            .with_activation(OpHardfork::Delta, 1708560000)
+            .with_activation(OpHardfork::Ecotone, 1710374401)
+            .with_activation(OpHardfork::Fjord, 1720627201)
+            .with_activation(OpHardfork::Granite, 1726070401)
+            .with_activation(OpHardfork::Holocene, 1736445601)
+            .with_activation(OpHardfork::Isthmus, 1746806401)
+            .with_activation(OpHardfork::Jovian, 1764691201),
+        pectra_blob_schedule_time: None,
+        base_fee_params: BaseFeeParams::new(50, 6),
+        base_fee_params_canyon: BaseFeeParams::new(250, 6),
+        batch_inbox: address!("0x6F54Ca6F6EdE96662024Ffd61BFd18f3f4e34DFf"),
+        batcher: address!("0x625726c858dBF78c0125436C943Bf4b4bE9d9033"),
+        unsafe_block_signer: address!("0x3Dc8Dfd0709C835cAd15a6A27e089FF4cF4C9228"),
+        addresses: RegistryAddresses {
+            system_config: address!("0xA3cAB0126d5F504B071b81a3e8A2BBBF17930d86"),
+            optimism_portal: address!("0x1a0ad011913A150f69f6A19DF447A0CfD9551054"),
+            l1_cross_domain_messenger: address!("0xdC40a14d9abd6F410226f1E6de71aE03441ca506"),
+            l1_standard_bridge: address!("0x3e2Ea9B92B7E48A52296fD261dc26fd995284631"),
+            l1_erc721_bridge: address!("0x83A4521A3573Ca87f3a971B169C5A0E1d34481c3"),
+            optimism_mintable_erc20_factory: address!("0xc52BC7344e24e39dF1bf026fe05C4e6E23CfBcFf"),
+            proxy_admin: address!("0xD4ef175B9e72cAEe9f1fe7660a6Ec19009903b49"),
+            l2_output_oracle: Some(address!("0x9E6204F750cD866b299594e2aC9eA824E2e5f95c")),
+            dispute_game_factory: None,
+            protocol_versions: address!("0x8062AbC286f5e7D9428a0Ccb9AbD71e50d93b935"),
+            superchain_config: address!("0x95703e0982140D16f8ebA6d158FccEde42f04a4C"),
+        },
+    },
+    RegistryChain {
+        identifier: "mainnet/soneium",
+        name: "Soneium",
+        chain_id: 1868,
+        l1_chain_id: 1,
+        bedrock_block: 0,
+        block_time: 2,
+        hardforks: OpHardforks::new()
+            .with_activation(OpHardfork::Regolith, 0)
+            .with_activation(OpHardfork::Canyon, 0)
+            .with_activation(OpHardfork::Delta, 0)
+            .with_activation(OpHardfork::Ecotone, 0)
+            .with_activation(OpHardfork::Fjord, 0)
+            .with_activation(OpHardfork::Granite, 0)
+            .with_activation(OpHardfork::Holocene, 1738573200)
+            .with_activation(OpHardfork::Isthmus, 1746806401)
+            .with_activation(OpHardfork::Jovian, 1764691201),
+        pectra_blob_schedule_time: None,
+        base_fee_params: BaseFeeParams::new(50, 6),
+        base_fee_params_canyon: BaseFeeParams::new(250, 6),
+        batch_inbox: address!("0x008dC74CecC9dedA8595B2Fe210cE5979F0BfA8e"),
+        batcher: address!("0x6776BE80dBAda6A02B5F2095cF13734ac303B8d1"),
+        unsafe_block_signer: address!("0x7c2Bd59ee2a2C7391c9A240132f26071e9546262"),
+        addresses: RegistryAddresses {
+            system_config: address!("0x7A8Ed66B319911A0F3E7288BDdAB30d9c0C875c3"),
+            optimism_portal: address!("0x88e529A6ccd302c948689Cd5156C83D4614FAE92"),
+            l1_cross_domain_messenger: address!("0x9CF951E3F74B644e621b36Ca9cea147a78D4c39f"),
+            l1_standard_bridge: address!("0xeb9bf100225c214Efc3E7C651ebbaDcF85177607"),
+            l1_erc721_bridge: address!("0x5933e323bE8896DfaCd1cD671442F27dAA10a053"),
+            optimism_mintable_erc20_factory: address!("0xc1047e30EFC9E172cFe7aa0219895B6a43fC415F"),
+            proxy_admin: address!("0x89889B569c3a505f3640ee1Bd0ac1D557f436D2a"),
+            l2_output_oracle: None,
+            dispute_game_factory: Some(address!("0x512A3d2c7a43BD9261d2B8E8C9c70D4bd4D503C0")),
+            protocol_versions: address!("0x8062AbC286f5e7D9428a0Ccb9AbD71e50d93b935"),
+            superchain_config: address!("0x95703e0982140D16f8ebA6d158FccEde42f04a4C"),
+        },
+    },
+    RegistryChain {
+        identifier: "mainnet/worldchain",
+        name: "World Chain",
+        chain_id: 480,
+        l1_chain_id: 1,
+        bedrock_block: 0,
+        block_time: 2,
+        hardforks: OpHardforks::new()
+            .with_activation(OpHardfork::Regolith, 0)
+            .with_activation(OpHardfork::Canyon, 0)
+            .with_activation(OpHardfork::Delta, 0)
+            .with_activation(OpHardfork::Ecotone, 0)
+            .with_activation(OpHardfork::Fjord, 1721826000)
+            .with_activation(OpHardfork::Granite, 1727780400)
+            .with_activation(OpHardfork::Holocene, 1738238400),
+        pectra_blob_schedule_time: None,
+        base_fee_params: BaseFeeParams::new(50, 10),
+        base_fee_params_canyon: BaseFeeParams::new(250, 10),
+        batch_inbox: address!("0xff00000000000000000000000000000000000480"),
+        batcher: address!("0xdBBE3D8c2d2b22A2611c5A94A9a12C2fCD49Eb29"),
+        unsafe_block_signer: address!("0x2270d6eC8E760daA317DD978cFB98C8f144B1f3A"),
+        addresses: RegistryAddresses {
+            system_config: address!("0x6ab0777fD0e609CE58F939a7F70Fe41F5Aa6300A"),
+            optimism_portal: address!("0xd5ec14a83B7d95BE1E2Ac12523e2dEE12Cbeea6C"),
+            l1_cross_domain_messenger: address!("0xf931a81D18B1766d15695ffc7c1920a62b7e710a"),
+            l1_standard_bridge: address!("0x470458C91978D2d929704489Ad730DC3E3001113"),
+            l1_erc721_bridge: address!("0x1Df436AfDb2fBB40F1fE8bEd4Fc89A0D0990a8E9"),
+            optimism_mintable_erc20_factory: address!("0x82Cb528466cF22412d89bdBE9bCF04856790dD0e"),
+            proxy_admin: address!("0xd7405BE7f3e63b094Af6C7C23D5eE33Fd82F872D"),
+            l2_output_oracle: Some(address!("0x19A6d1E9034596196295CF148509796978343c5D")),
+            dispute_game_factory: Some(address!("0x069c4c579671f8c120b1327a73217D01Ea2EC5ea")),
+            protocol_versions: address!("0x8062AbC286f5e7D9428a0Ccb9AbD71e50d93b935"),
+            superchain_config: address!("0x95703e0982140D16f8ebA6d158FccEde42f04a4C"),
+        },
+    },
+    RegistryChain {
+        identifier: "sepolia/op",
+        name: "OP Sepolia Testnet",
+        chain_id: 11155420,
+        l1_chain_id: 11155111,
+        bedrock_block: 0,
+        block_time: 2,
+        hardforks: OpHardforks::new()
+            .with_activation(OpHardfork::Regolith, 0)
+            .with_activation(OpHardfork::Canyon, 1699981200)
+            .with_activation(OpHardfork::Delta, 1703203200)
+            .with_activation(OpHardfork::Ecotone, 1708534800)
+            .with_activation(OpHardfork::Fjord, 1716998400)
+            .with_activation(OpHardfork::Granite, 1723478400)
+            .with_activation(OpHardfork::Holocene, 1732633200)
+            .with_activation(OpHardfork::Isthmus, 1744905600)
+            .with_activation(OpHardfork::Jovian, 1763568001),
+        pectra_blob_schedule_time: Some(1742486400),
+        base_fee_params: BaseFeeParams::new(50, 6),
+        base_fee_params_canyon: BaseFeeParams::new(250, 6),
+        batch_inbox: address!("0xff00000000000000000000000000000011155420"),
+        batcher: address!("0x8F23BB38F531600e5d8FDDaAEC41F13FaB46E98c"),
+        unsafe_block_signer: address!("0x57CACBB0d30b01eb2462e5dC940c161aff3230D3"),
+        addresses: RegistryAddresses {
+            system_config: address!("0x034edD2A225f7f429A63E0f1D2084B9E0A93b538"),
+            optimism_portal: address!("0x16Fc5058F25648194471939df75CF27A2fdC48BC"),
+            l1_cross_domain_messenger: address!("0x58Cc85b8D04EA49cC6DBd3CbFFd00B4B8D6cb3ef"),
+            l1_standard_bridge: address!("0xFBb0621E0B23b5478B630BD55a5f21f67730B0F1"),
+            l1_erc721_bridge: address!("0xd83e03D576d23C9AEab8cC44Fa98d058D2176D1f"),
+            optimism_mintable_erc20_factory: address!("0x868D59fF9710159C2B330Cc0fBDF57144dD7A13b"),
+            proxy_admin: address!("0x189aBAAaa82DfC015A588A7dbaD6F13b1D3485Bc"),
+            l2_output_oracle: None,
+            dispute_game_factory: Some(address!("0x05F9613aDB30026FFd634f38e5C4dFd30a197Fa1")),
+            protocol_versions: address!("0x79ADD5713B383DAa0a138d3C4780C7A1804a8090"),
+            superchain_config: address!("0xC2Be75506d5724086DEB7245bd260Cc9753911Be"),
+        },
+    },
+    RegistryChain {
+        identifier: "sepolia/base",
+        name: "Base Sepolia Testnet",
+        chain_id: 84532,
+        l1_chain_id: 11155111,
+        bedrock_block: 0,
+        block_time: 2,
+        hardforks: OpHardforks::new()
+            .with_activation(OpHardfork::Regolith, 0)
+            .with_activation(OpHardfork::Canyon, 1699981200)
+            .with_activation(OpHardfork::Delta, 1703203200)
+            .with_activation(OpHardfork::Ecotone, 1708534800)
+            .with_activation(OpHardfork::Fjord, 1716998400)
+            .with_activation(OpHardfork::Granite, 1723478400)
+            .with_activation(OpHardfork::Holocene, 1732633200)
+            .with_activation(OpHardfork::Isthmus, 1744905600)
+            .with_activation(OpHardfork::Jovian, 1763568001),
+        pectra_blob_schedule_time: Some(1742486400),
+        base_fee_params: BaseFeeParams::new(50, 10),
+        base_fee_params_canyon: BaseFeeParams::new(250, 10),
+        batch_inbox: address!("0xfF00000000000000000000000000000000084532"),
+        batcher: address!("0xfc56E7272EEBBBA5bC6c544e159483C4a38f8bA3"),
+        unsafe_block_signer: address!("0xb830b99c95Ea32300039624Cb567d324D4b1D83C"),
+        addresses: RegistryAddresses {
+            system_config: address!("0xf272670eb55e895584501d564AfEB048bEd26194"),
+            optimism_portal: address!("0x49f53e41452C74589E85cA1677426Ba426459e85"),
+            l1_cross_domain_messenger: address!("0xC34855F4De64F1840e5686e64278da901e261f20"),
+            l1_standard_bridge: address!("0xfd0Bf71F60660E2f608ed56e1659C450eB113120"),
+            l1_erc721_bridge: address!("0x21eFD066e581FA55Ef105170Cc04d74386a09190"),
+            optimism_mintable_erc20_factory: address!("0xb1efB9650aD6d0CC1ed3Ac4a0B7f1D5732696D37"),
+            proxy_admin: address!("0x0389E59Aa0a41E4A413Ae70f0008e76CAA34b1F3"),
+            l2_output_oracle: None,
+            dispute_game_factory: Some(address!("0xd6E6dBf4F7EA0ac412fD8b65ED297e64BB7a06E1")),
+            protocol_versions: address!("0x79ADD5713B383DAa0a138d3C4780C7A1804a8090"),
+            superchain_config: address!("0xC2Be75506d5724086DEB7245bd260Cc9753911Be"),
+        },
+    },
+    RegistryChain {
+        identifier: "sepolia/unichain",
+        name: "Unichain Sepolia Testnet",
+        chain_id: 1301,
+        l1_chain_id: 11155111,
+        bedrock_block: 0,
+        block_time: 1,
+        hardforks: OpHardforks::new()
+            .with_activation(OpHardfork::Regolith, 0)
+            .with_activation(OpHardfork::Canyon, 0)
+            .with_activation(OpHardfork::Delta, 0)
+            .with_activation(OpHardfork::Ecotone, 0)
+            .with_activation(OpHardfork::Fjord, 0)
+            .with_activation(OpHardfork::Granite, 0)
+            .with_activation(OpHardfork::Holocene, 1734559200)
+            .with_activation(OpHardfork::Isthmus, 1744905600)
+            .with_activation(OpHardfork::Jovian, 1763568001),
+        pectra_blob_schedule_time: Some(1742486400),
+        base_fee_params: BaseFeeParams::new(50, 6),
+        base_fee_params_canyon: BaseFeeParams::new(250, 6),
+        batch_inbox: address!("0xFf00000000000000000000000000000000001301"),
+        batcher: address!("0x4AB3387810eF500bfe05a49dc53A44C222cbab3e"),
+        unsafe_block_signer: address!("0x565B71025Ab4de80AcA33c62E51439af56301493"),
+        addresses: RegistryAddresses {
+            system_config: address!("0xaeE94b9aB7752D3F7704bDE212c0C6A0b701571D"),
+            optimism_portal: address!("0x0d83dab629f0e0F9d36c0Cbc89B69a489f0751bD"),
+            l1_cross_domain_messenger: address!("0x448A37330A60494E666F6DD60aD48d930AEbA381"),
+            l1_standard_bridge: address!("0xea58fcA6849d79EAd1f26608855c2D6407d54Ce2"),
+            l1_erc721_bridge: address!("0x4696b5e042755103fe558738Bcd1ecEe7A45eBfe"),
+            optimism_mintable_erc20_factory: address!("0xDf7977C3005730329A160637E8CB9f1675A4d9Be"),
+            proxy_admin: address!("0x2BF403E5353A7a082ef6bb3Ae2Be3B866D8D3ea4"),
+            l2_output_oracle: None,
+            dispute_game_factory: Some(address!("0xeff73e5aa3B9AEC32c659Aa3E00444d20a84394b")),
+            protocol_versions: address!("0x79ADD5713B383DAa0a138d3C4780C7A1804a8090"),
+            superchain_config: address!("0xC2Be75506d5724086DEB7245bd260Cc9753911Be"),
+        },
+    },
+    RegistryChain {
+        identifier: "sepolia/ink",
+        name: "Ink Sepolia",
+        chain_id: 763373,
+        l1_chain_id: 11155111,
+        bedrock_block: 0,
+        block_time: 1,
+        hardforks: OpHardforks::new()
+            .with_activation(OpHardfork::Regolith, 0)
+            .with_activation(OpHardfork::Canyon, 1699981200)
+            .with_activation(OpHardfork::Delta, 1703203200)
+            .with_activation(OpHardfork::Ecotone, 1708534800)
+            .with_activation(OpHardfork::Fjord, 1716998400)
+            .with_activation(OpHardfork::Granite, 1723478400)
+            .with_activation(OpHardfork::Holocene, 1732633200)
+            .with_activation(OpHardfork::Isthmus, 1744905600)
+            .with_activation(OpHardfork::Jovian, 1763568001),
+        pectra_blob_schedule_time: Some(1742486400),
+        base_fee_params: BaseFeeParams::new(50, 6),
+        base_fee_params_canyon: BaseFeeParams::new(250, 6),
+        batch_inbox: address!("0x004De1914F4f17aC234f5A5Bc8a4072a231d44BF"),
+        batcher: address!("0x21e57C21530Bc33F12Ba96C9dDC135488365002F"),
+        unsafe_block_signer: address!("0x43ec5732581d3FAE18AbB7CE34a796E111dBD1a0"),
+        addresses: RegistryAddresses {
+            system_config: address!("0x05C993e60179f28bF649a2Bb5b00b5F4283bD525"),
+            optimism_portal: address!("0x5c1d29C6c9C8b0800692acC95D700bcb4966A1d7"),
+            l1_cross_domain_messenger: address!("0x9fE1d3523F5342535E6E7770ED09ed85Dbc1Acc2"),
+            l1_standard_bridge: address!("0x33f60714BbD74d62b66D79213C348614DE51901C"),
+            l1_erc721_bridge: address!("0xd1C901BBD7796546A7bA2492e0E199911fAE68c7"),
+            optimism_mintable_erc20_factory: address!("0x686F782A749D1854f6Fa3F948450f4c65c6674f0"),
+            proxy_admin: address!("0xd7dB319a49362b2328cf417a934300cCcB442C8d"),
+            l2_output_oracle: None,
+            dispute_game_factory: Some(address!("0x860e626c700AF381133D9f4aF31412A2d1DB3D5d")),
+            protocol_versions: address!("0x79ADD5713B383DAa0a138d3C4780C7A1804a8090"),
+            superchain_config: address!("0xC2Be75506d5724086DEB7245bd260Cc9753911Be"),
+        },
+    },
+    RegistryChain {
+        identifier: "sepolia/zora",
+        name: "Zora Sepolia Testnet",
+        chain_id: 999999999,
+        l1_chain_id: 11155111,
+        bedrock_block: 0,
+        block_time: 2,
+        hardforks: OpHardforks::new()
+            .with_activation(OpHardfork::Regolith, 0)
+            .with_activation(OpHardfork::Canyon, 1699981200)
+            .with_activation(OpHardfork::Delta, 1703203200)
+            .with_activation(OpHardfork::Ecotone, 1708534800)
+            .with_activation(OpHardfork::Fjord, 1716998400)
+            .with_activation(OpHardfork::Granite, 1723478400)
+            .with_activation(OpHardfork::Holocene, 1732633200)
+            .with_activation(OpHardfork::Isthmus, 1744905600)
+            .with_activation(OpHardfork::Jovian, 1763568001),
+        pectra_blob_schedule_time: Some(1742486400),
+        base_fee_params: BaseFeeParams::new(50, 6),
+        base_fee_params_canyon: BaseFeeParams::new(250, 6),
+        batch_inbox: address!("0xCd734290E4bd0200dAC631c7D4b9E8a33234e91f"),
+        batcher: address!("0x3Cd868E221A3be64B161D596A7482257a99D857f"),
+        unsafe_block_signer: address!("0x3609513933100689bd1f84782529A99239842344"),
+        addresses: RegistryAddresses {
+            system_config: address!("0xB54c7BFC223058773CF9b739cC5bd4095184Fb08"),
+            optimism_portal: address!("0xeffE2C6cA9Ab797D418f0D91eA60807713f3536f"),
+            l1_cross_domain_messenger: address!("0x1bDBC0ae22bEc0c2f08B4dd836944b3E28fe9b7A"),
+            l1_standard_bridge: address!("0x5376f1D543dcbB5BD416c56C189e4cB7399fCcCB"),
+            l1_erc721_bridge: address!("0x16B0a4f451c4CB567703367e587E15Ac108e4311"),
+            optimism_mintable_erc20_factory: address!("0x5F3bdd57f01e88cE2F88f00685D30D6eb51A187c"),
+            proxy_admin: address!("0xE17071F4C216Eb189437fbDBCc16Bb79c4efD9c2"),
+            l2_output_oracle: Some(address!("0x2615B481Bd3E5A1C0C7Ca3Da1bdc663E8615Ade9")),
+            dispute_game_factory: None,
+            protocol_versions: address!("0x79ADD5713B383DAa0a138d3C4780C7A1804a8090"),
+            superchain_config: address!("0xC2Be75506d5724086DEB7245bd260Cc9753911Be"),
+        },
+    },
+];
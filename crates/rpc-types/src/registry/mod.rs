@@ -0,0 +1,228 @@
+//! An embedded registry of the major OP Stack chains.
+//!
+//! The configurations are taken from the [superchain registry], so that tools can look up a chain
+//! by its chain ID or name without downloading the registry at runtime. Hardforks scheduled after
+//! the embedded snapshot are not known.
+//!
+//! The snapshot is the `superchain/configs` directory of the superchain registry vendored in
+//! `kona-registry` 0.4.5, i.e. the `crates/protocol/registry/superchain-registry` submodule of
+//! kona at commit `be591a885848d246087346ed95ed4aa94e7a4486`. It predates the scheduling of
+//! Jovian, whose activation times are taken as follows:
+//!
+//! - OP Mainnet, Base, OP Sepolia and Base Sepolia: the chain hardfork schedules of
+//!   `alloy-op-hardforks` 0.5.0 (commit `0f5217735d76e088256430b92fe99a872b1238ed`).
+//! - Unichain, Ink, Zora and Soneium on mainnet, and Unichain, Ink and Zora on Sepolia: these
+//!   chains set a `superchain_time` in the snapshot and follow the superchain-wide schedule, so
+//!   they get the superchain-level Jovian time, which is the one of OP Mainnet on mainnet and of OP
+//!   Sepolia on Sepolia.
+//! - World Chain: it does not set a `superchain_time` and schedules its hardforks itself, so it has
+//!   no Jovian activation.
+//!
+//! [superchain registry]: https://github.com/ethereum-optimism/superchain-registry
+
+use crate::{OpBaseFeeInfo, OpChainInfo, OpGenesisInfo};
+use alloy_eips::eip1559::BaseFeeParams;
+use alloy_primitives::Address;
+use op_alloy_consensus::{OpHardfork, OpHardforks};
+
+mod chains;
+
+/// The configuration of an OP Stack chain of the superchain registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegistryChain {
+    /// The identifier of the chain in the registry, `<superchain>/<chain>`, e.g. `mainnet/op`.
+    pub identifier: &'static str,
+    /// The name of the chain, e.g. `OP Mainnet`.
+    pub name: &'static str,
+    /// The L2 chain ID.
+    pub chain_id: u64,
+    /// The L1 chain ID.
+    pub l1_chain_id: u64,
+    /// The number of the L2 genesis block, the first Bedrock block.
+    pub bedrock_block: u64,
+    /// The L2 block time, in seconds.
+    pub block_time: u64,
+    /// The hardfork activation schedule.
+    pub hardforks: OpHardforks,
+    /// Timestamp of the switch to the Pectra blob base fee schedule on L1, if any.
+    pub pectra_blob_schedule_time: Option<u64>,
+    /// The EIP-1559 base fee params before Canyon.
+    pub base_fee_params: BaseFeeParams,
+    /// The EIP-1559 base fee params from Canyon onwards, until Holocene moves them to the system
+    /// config.
+    pub base_fee_params_canyon: BaseFeeParams,
+    /// The L1 address batches are sent to.
+    pub batch_inbox: Address,
+    /// The batcher address.
+    pub batcher: Address,
+    /// The address signing the unsafe blocks gossiped by the sequencer.
+    pub unsafe_block_signer: Address,
+    /// The L1 contract addresses.
+    pub addresses: RegistryAddresses,
+}
+
+impl RegistryChain {
+    /// Returns the EIP-1559 base fee params at the L2 `timestamp`.
+    pub fn base_fee_params_at(&self, timestamp: u64) -> BaseFeeParams {
+        if self.hardforks.is_active_at(OpHardfork::Canyon, timestamp) {
+            self.base_fee_params_canyon
+        } else {
+            self.base_fee_params
+        }
+    }
+
+    /// Returns the Optimism specific genesis fields of the chain, as found in its genesis file.
+    pub fn chain_info(&self) -> OpChainInfo {
+        let activation = |fork| self.hardforks.activation(fork);
+        OpChainInfo {
+            genesis_info: Some(OpGenesisInfo {
+                bedrock_block: Some(self.bedrock_block),
+                regolith_time: activation(OpHardfork::Regolith),
+                canyon_time: activation(OpHardfork::Canyon),
+                ecotone_time: activation(OpHardfork::Ecotone),
+                fjord_time: activation(OpHardfork::Fjord),
+                granite_time: activation(OpHardfork::Granite),
+                holocene_time: activation(OpHardfork::Holocene),
+                isthmus_time: activation(OpHardfork::Isthmus),
+                interop_time: activation(OpHardfork::Interop),
+                jovian_time: activation(OpHardfork::Jovian),
+            }),
+            base_fee_info: Some(OpBaseFeeInfo {
+                eip1559_elasticity: u64::try_from(self.base_fee_params.elasticity_multiplier).ok(),
+                eip1559_denominator: u64::try_from(self.base_fee_params.max_change_denominator)
+                    .ok(),
+                eip1559_denominator_canyon: u64::try_from(
+                    self.base_fee_params_canyon.max_change_denominator,
+                )
+                .ok(),
+            }),
+        }
+    }
+}
+
+/// The L1 contract addresses of a chain of the superchain registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegistryAddresses {
+    /// The `SystemConfig` proxy.
+    pub system_config: Address,
+    /// The `OptimismPortal` proxy, which emits the deposits.
+    pub optimism_portal: Address,
+    /// The `L1CrossDomainMessenger` proxy.
+    pub l1_cross_domain_messenger: Address,
+    /// The `L1StandardBridge` proxy.
+    pub l1_standard_bridge: Address,
+    /// The `L1ERC721Bridge` proxy.
+    pub l1_erc721_bridge: Address,
+    /// The `OptimismMintableERC20Factory` proxy.
+    pub optimism_mintable_erc20_factory: Address,
+    /// The `ProxyAdmin` of the proxies.
+    pub proxy_admin: Address,
+    /// The `L2OutputOracle` proxy, for chains that still have one.
+    pub l2_output_oracle: Option<Address>,
+    /// The `DisputeGameFactory` proxy, for chains with fault proofs.
+    pub dispute_game_factory: Option<Address>,
+    /// The `ProtocolVersions` of the superchain.
+    pub protocol_versions: Address,
+    /// The `SuperchainConfig` of the chain.
+    pub superchain_config: Address,
+}
+
+/// Returns all the chains of the registry.
+pub fn chains() -> &'static [RegistryChain] {
+    chains::CHAINS
+}
+
+/// Returns the chain with the given chain ID.
+pub fn chain_by_id(chain_id: u64) -> Option<&'static RegistryChain> {
+    chains::CHAINS.iter().find(|chain| chain.chain_id == chain_id)
+}
+
+/// Returns the chain with the given registry identifier or name, ignoring ASCII case.
+pub fn chain_by_name(name: &str) -> Option<&'static RegistryChain> {
+    chains::CHAINS.iter().find(|chain| {
+        chain.identifier.eq_ignore_ascii_case(name) || chain.name.eq_ignore_ascii_case(name)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    #[test]
+    fn test_registry_lookup() {
+        let op = chain_by_id(10).unwrap();
+        assert_eq!(op.identifier, "mainnet/op");
+        assert_eq!(chain_by_name("OP Mainnet"), Some(op));
+        assert_eq!(chain_by_name("MAINNET/OP"), Some(op));
+        assert_eq!(
+            op.addresses.system_config,
+            address!("0x229047fed2591dbec1eF1118d64F7aF3dB9EB290")
+        );
+        assert_eq!(op.unsafe_block_signer, address!("0xAAAA45d9549EDA09E70937013520214382Ffc4A2"));
+
+        let base_sepolia = chain_by_name("sepolia/base").unwrap();
+        assert_eq!((base_sepolia.chain_id, base_sepolia.l1_chain_id), (84532, 11155111));
+
+        assert_eq!(chain_by_id(1), None);
+        assert_eq!(chain_by_name("op"), None);
+    }
+
+    #[test]
+    fn test_registry_chains_consistent() {
+        for (i, chain) in chains().iter().enumerate() {
+            assert!(chain.hardforks.validate().is_ok(), "{}", chain.identifier);
+            assert!(
+                chains()[..i].iter().all(|other| other.chain_id != chain.chain_id
+                    && other.identifier != chain.identifier),
+                "{}",
+                chain.identifier
+            );
+        }
+    }
+
+    #[test]
+    fn test_registry_base_fee_params() {
+        let op = chain_by_id(10).unwrap();
+        assert_eq!(op.base_fee_params_at(1704992400), BaseFeeParams::optimism());
+        assert_eq!(op.base_fee_params_at(1704992401), BaseFeeParams::optimism_canyon());
+
+        let base_sepolia = chain_by_id(84532).unwrap();
+        assert_eq!(base_sepolia.base_fee_params_at(0), BaseFeeParams::new(50, 10));
+    }
+
+    #[test]
+    fn test_registry_superchain_jovian() {
+        for chain in chains() {
+            let expected = match (chain.l1_chain_id, chain.chain_id) {
+                (_, 480) => None,
+                (1, _) => Some(1764691201),
+                _ => Some(1763568001),
+            };
+            assert_eq!(
+                chain.hardforks.activation(OpHardfork::Jovian),
+                expected,
+                "{}",
+                chain.identifier
+            );
+        }
+    }
+
+    #[test]
+    fn test_registry_chain_info() {
+        let info = chain_by_id(10).unwrap().chain_info();
+        let genesis_info = info.genesis_info.unwrap();
+        assert_eq!(genesis_info.bedrock_block, Some(105235063));
+        assert_eq!(genesis_info.regolith_time, Some(0));
+        assert_eq!(genesis_info.ecotone_time, Some(1710374401));
+        assert_eq!(genesis_info.jovian_time, Some(1764691201));
+        assert_eq!(
+            info.base_fee_info,
+            Some(OpBaseFeeInfo {
+                eip1559_elasticity: Some(6),
+                eip1559_denominator: Some(50),
+                eip1559_denominator_canyon: Some(250),
+            })
+        );
+    }
+}
@@ -0,0 +1,283 @@
+//! OP rollup configuration, as loaded from op-node's `rollup.json`.
+
+use crate::{OpBaseFeeInfo, genesis::hardfork_schedule};
+use alloc::string::String;
+use alloy_eips::{BlockNumHash, eip1559::BaseFeeParams};
+use alloy_primitives::Address;
+use op_alloy_consensus::{OpHardfork, OpHardforks, OpHardforksError, SystemConfig};
+
+/// The rollup configuration of an OP Stack chain, in the format of op-node's `rollup.json`.
+///
+/// Unknown fields are ignored, so that configurations of newer op-node versions can be loaded.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RollupConfig {
+    /// The L1 and L2 genesis anchors of the rollup.
+    pub genesis: RollupGenesis,
+    /// The L2 block time, in seconds.
+    pub block_time: u64,
+    /// The maximum number of seconds an L2 block timestamp can be ahead of its L1 origin, before
+    /// Fjord.
+    pub max_sequencer_drift: u64,
+    /// The number of L1 blocks in a sequencing window.
+    pub seq_window_size: u64,
+    /// The number of L1 blocks after which a channel times out, before Granite.
+    pub channel_timeout: u64,
+    /// The L1 chain ID.
+    pub l1_chain_id: u64,
+    /// The L2 chain ID.
+    pub l2_chain_id: u64,
+    /// regolith hardfork timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub regolith_time: Option<u64>,
+    /// canyon hardfork timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canyon_time: Option<u64>,
+    /// delta hardfork timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delta_time: Option<u64>,
+    /// ecotone hardfork timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ecotone_time: Option<u64>,
+    /// fjord hardfork timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fjord_time: Option<u64>,
+    /// granite hardfork timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub granite_time: Option<u64>,
+    /// holocene hardfork timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub holocene_time: Option<u64>,
+    /// Timestamp of the switch to the Pectra blob base fee schedule on L1, for chains that
+    /// activated Pectra on L1 before Isthmus on L2.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pectra_blob_schedule_time: Option<u64>,
+    /// isthmus hardfork timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub isthmus_time: Option<u64>,
+    /// jovian hardfork timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jovian_time: Option<u64>,
+    /// interop hardfork timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interop_time: Option<u64>,
+    /// The L1 address batches are sent to.
+    pub batch_inbox_address: Address,
+    /// The L1 address of the `OptimismPortal`, which emits the deposits.
+    pub deposit_contract_address: Address,
+    /// The L1 address of the `SystemConfig`, which emits the config updates.
+    pub l1_system_config_address: Address,
+    /// The L1 address of the `ProtocolVersions`.
+    pub protocol_versions_address: Address,
+    /// The L1 address of the `SuperchainConfig`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub superchain_config_address: Option<Address>,
+    /// The Alt-DA configuration, for chains that do not post their data to L1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alt_da: Option<AltDaConfig>,
+    /// The EIP-1559 configuration of the chain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain_op_config: Option<OpBaseFeeInfo>,
+}
+
+impl RollupConfig {
+    /// Returns the EIP-1559 base fee params at the L2 `timestamp`.
+    ///
+    /// Parameters missing from [`RollupConfig::chain_op_config`] default to the OP Mainnet ones.
+    /// From Holocene onwards, the params are set by the system config instead, see
+    /// [`SystemConfig::eip1559_params`].
+    pub fn base_fee_params_at(&self, timestamp: u64) -> BaseFeeParams {
+        let canyon_active = self.schedule().is_active_at(OpHardfork::Canyon, timestamp);
+        let default = if canyon_active {
+            BaseFeeParams::optimism_canyon()
+        } else {
+            BaseFeeParams::optimism()
+        };
+        let info = self.chain_op_config.unwrap_or_default();
+        let denominator =
+            if canyon_active { info.eip1559_denominator_canyon } else { info.eip1559_denominator };
+        BaseFeeParams::new(
+            denominator.map_or(default.max_change_denominator, u128::from),
+            info.eip1559_elasticity.map_or(default.elasticity_multiplier, u128::from),
+        )
+    }
+
+    /// Builds the hardfork schedule from the activation timestamps, without checking their order.
+    fn schedule(&self) -> OpHardforks {
+        hardfork_schedule([
+            (OpHardfork::Regolith, self.regolith_time),
+            (OpHardfork::Canyon, self.canyon_time),
+            (OpHardfork::Delta, self.delta_time),
+            (OpHardfork::Ecotone, self.ecotone_time),
+            (OpHardfork::Fjord, self.fjord_time),
+            (OpHardfork::Granite, self.granite_time),
+            (OpHardfork::Holocene, self.holocene_time),
+            (OpHardfork::Isthmus, self.isthmus_time),
+            (OpHardfork::Jovian, self.jovian_time),
+            (OpHardfork::Interop, self.interop_time),
+        ])
+    }
+}
+
+impl TryFrom<&RollupConfig> for OpHardforks {
+    type Error = OpHardforksError;
+
+    /// Builds the hardfork schedule from the activation timestamps, and checks that they are
+    /// ordered.
+    ///
+    /// The Pectra blob schedule is an L1 fee change, not an OP Stack hardfork, and is not part of
+    /// the schedule.
+    fn try_from(config: &RollupConfig) -> Result<Self, Self::Error> {
+        let schedule = config.schedule();
+        schedule.validate()?;
+        Ok(schedule)
+    }
+}
+
+/// The genesis anchors of a rollup: the L2 genesis block, its L1 origin and its system config.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RollupGenesis {
+    /// The L1 origin of the L2 genesis block.
+    pub l1: BlockNumHash,
+    /// The L2 genesis block.
+    pub l2: BlockNumHash,
+    /// The timestamp of the L2 genesis block.
+    pub l2_time: u64,
+    /// The system config at the L2 genesis block.
+    pub system_config: Option<SystemConfig>,
+}
+
+/// The Alt-DA configuration of a rollup.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AltDaConfig {
+    /// The L1 address of the data availability challenge contract.
+    pub da_challenge_contract_address: Address,
+    /// The type of the DA commitments, either `KeccakCommitment` or `GenericCommitment`.
+    pub da_commitment_type: String,
+    /// The number of L1 blocks during which a commitment can be challenged.
+    pub da_challenge_window: u64,
+    /// The number of L1 blocks during which a challenge can be resolved.
+    pub da_resolve_window: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256};
+
+    const OP_MAINNET: &str = include_str!("testdata/op-mainnet.json");
+    const BASE_MAINNET: &str = include_str!("testdata/base-mainnet.json");
+    // A devnet `rollup.json` written by op-node, as used by the `superchain-primitives` 0.5.0
+    // tests.
+    const OP_NODE_DEVNET: &str = include_str!("testdata/op-node-devnet.json");
+
+    // The OP Mainnet and Base fixtures are assembled from their superchain registry entries, in
+    // the `rollup.json` format, and are not written by op-node.
+    #[test]
+    fn test_rollup_config_roundtrip() {
+        // Serializing the config gives back the exact JSON, so no field is dropped.
+        for raw in [OP_MAINNET, BASE_MAINNET] {
+            let config: RollupConfig = serde_json::from_str(raw).unwrap();
+            let expected: serde_json::Value = serde_json::from_str(raw).unwrap();
+            assert_eq!(serde_json::to_value(&config).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_op_node_rollup_config_roundtrip() {
+        // A file written by op-node loses no field and gains none.
+        let config: RollupConfig = serde_json::from_str(OP_NODE_DEVNET).unwrap();
+        let expected: serde_json::Value = serde_json::from_str(OP_NODE_DEVNET).unwrap();
+        assert_eq!(serde_json::to_value(&config).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_deserialize_op_node_rollup_config() {
+        let config: RollupConfig = serde_json::from_str(OP_NODE_DEVNET).unwrap();
+        assert_eq!(config.genesis.l2_time, 1725557164);
+        assert_eq!(
+            config.genesis.system_config.unwrap().batcher_address,
+            address!("0xc81f87a644b41e49b3221f41251f15c6cb00ce03")
+        );
+        assert_eq!((config.l1_chain_id, config.l2_chain_id), (3151908, 1337));
+        assert_eq!(config.chain_op_config, None);
+        assert_eq!(config.base_fee_params_at(0), BaseFeeParams::optimism_canyon());
+
+        let schedule = OpHardforks::try_from(&config).unwrap();
+        assert_eq!(schedule.active_fork_at(0), OpHardfork::Fjord);
+    }
+
+    #[test]
+    fn test_deserialize_op_mainnet_rollup_config() {
+        let config: RollupConfig = serde_json::from_str(OP_MAINNET).unwrap();
+        assert_eq!(
+            config.genesis.l1,
+            BlockNumHash::new(
+                17422590,
+                b256!("0x438335a20d98863a4c0c97999eb2481921ccd28553eac6f913af7c12aec04108")
+            )
+        );
+        assert_eq!(config.genesis.l2.number, 105235063);
+        assert_eq!(
+            config.genesis.system_config.unwrap().batcher_address,
+            address!("0x6887246668a3b87f54deb3b94ba47a6f63f32985")
+        );
+        assert_eq!(config.l2_chain_id, 10);
+        assert_eq!(
+            config.batch_inbox_address,
+            address!("0xff00000000000000000000000000000000000010")
+        );
+        assert_eq!(config.alt_da, None);
+
+        let schedule = OpHardforks::try_from(&config).unwrap();
+        assert_eq!(schedule.active_fork_at(config.genesis.l2_time), OpHardfork::Regolith);
+        assert_eq!(schedule.active_fork_at(1746806401), OpHardfork::Isthmus);
+        assert_eq!(schedule.next_fork_after(1704992400), Some((OpHardfork::Canyon, 1704992401)));
+    }
+
+    #[test]
+    fn test_rollup_config_base_fee_params() {
+        let mut config: RollupConfig = serde_json::from_str(OP_MAINNET).unwrap();
+        assert_eq!(config.base_fee_params_at(1704992400), BaseFeeParams::new(50, 6));
+        assert_eq!(config.base_fee_params_at(1704992401), BaseFeeParams::new(250, 6));
+
+        config.chain_op_config = Some(OpBaseFeeInfo {
+            eip1559_elasticity: Some(10),
+            eip1559_denominator: None,
+            eip1559_denominator_canyon: None,
+        });
+        assert_eq!(config.base_fee_params_at(0), BaseFeeParams::new(50, 10));
+        config.chain_op_config = None;
+        assert_eq!(config.base_fee_params_at(u64::MAX), BaseFeeParams::optimism_canyon());
+    }
+
+    #[test]
+    fn test_rollup_config_unknown_fields() {
+        let mut raw: serde_json::Value = serde_json::from_str(OP_MAINNET).unwrap();
+        raw["unknown_field"] = "unknown".into();
+        raw["genesis"]["unknown_field"] = "unknown".into();
+        let config: RollupConfig = serde_json::from_value(raw).unwrap();
+        assert_eq!(config, serde_json::from_str(OP_MAINNET).unwrap());
+
+        let mut raw: serde_json::Value = serde_json::from_str(OP_MAINNET).unwrap();
+        raw["ecotone_time"] = 0.into();
+        let config: RollupConfig = serde_json::from_value(raw).unwrap();
+        assert!(matches!(
+            OpHardforks::try_from(&config),
+            Err(OpHardforksError::OutOfOrder { fork: OpHardfork::Ecotone, .. })
+        ));
+    }
+
+    #[test]
+    fn test_rollup_config_alt_da() {
+        let mut raw: serde_json::Value = serde_json::from_str(OP_MAINNET).unwrap();
+        raw["alt_da"] = serde_json::json!({
+            "da_challenge_contract_address": "0x0000000000000000000000000000000000000001",
+            "da_commitment_type": "KeccakCommitment",
+            "da_challenge_window": 160,
+            "da_resolve_window": 160
+        });
+        let config: RollupConfig = serde_json::from_value(raw.clone()).unwrap();
+        assert_eq!(config.alt_da.as_ref().unwrap().da_challenge_window, 160);
+        assert_eq!(serde_json::to_value(&config).unwrap(), raw);
+    }
+}